@@ -0,0 +1,80 @@
+//! Derive macro for `rustable::frame::Record`, which converts structs to and
+//! from the rows of a `Frame`. Use it through the `derive` feature of
+//! `rustable` rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::parse_macro_input;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Error;
+use syn::Fields;
+
+/// Derives `Record` for a struct with named fields. Each field becomes a
+/// column of the same name, and its type must be storable in a `Column`.
+#[proc_macro_derive(Record)]
+pub fn derive_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "`Record` can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "`Record` can only be derived for structs")),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let idents = fields.iter().map(|field| field.ident.as_ref().unwrap()).collect::<Vec<_>>();
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let names = idents.iter().map(|ident| ident.unraw().to_string()).collect::<Vec<_>>();
+
+    // Prefixed so that the generated locals cannot collide with each other or
+    // with the parameters, whatever the field names are.
+    let columns = idents.iter().map(|ident| format_ident!("__rustable_{}", ident)).collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics ::rustable::frame::Record for #name #ty_generics #where_clause {
+            fn fields() -> ::std::vec::Vec<(&'static str, ::std::string::String)> {
+                ::std::vec![
+                    #( (#names, <#types as ::rustable::traits::Storable>::dtype_name()), )*
+                ]
+            }
+
+            fn into_columns<L: ::rustable::traits::Label>(
+                __records: ::std::vec::Vec<Self>,
+                __index: &::rustable::index::Index<L>,
+            ) -> ::std::vec::Vec<::rustable::types::Column<L>>
+            {
+                #( let mut #columns = ::std::vec::Vec::<#types>::with_capacity(__records.len()); )*
+
+                for __record in __records {
+                    #( #columns.push(__record.#idents); )*
+                }
+
+                ::std::vec![
+                    #(
+                        <#types as ::rustable::traits::ColumnType>::into_column(
+                            ::rustable::series::Series::from_values(__index.clone(), #columns).unwrap()
+                        ),
+                    )*
+                ]
+            }
+
+            fn from_row<L: ::rustable::traits::Label>(__row: &::rustable::frame::Row<'_, L>) -> ::std::option::Option<Self> {
+                ::std::option::Option::Some(Self {
+                    #( #idents: ::std::clone::Clone::clone(__row.get_as::<#types>(#names)?), )*
+                })
+            }
+        }
+    })
+}
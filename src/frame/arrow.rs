@@ -0,0 +1,348 @@
+//! Conversion of `Frame`s to and from Arrow record batches.
+//!
+//! Numeric, boolean, and string columns map onto their Arrow equivalents,
+//! with `ISize` and `USize` widened to 64 bits. With the `date-time` feature,
+//! `Date`, `Time`, and `DateTime` columns map onto `Date32`, `Time64` and
+//! `Timestamp` in microseconds. All other columns are stored as strings.
+//! Columns with `Option` values map onto nullable fields.
+
+use std::iter::FromIterator;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::Array;
+use arrow_array::ArrayRef;
+use arrow_array::BooleanArray;
+use arrow_array::PrimitiveArray;
+use arrow_array::RecordBatch;
+use arrow_array::RecordBatchOptions;
+use arrow_array::StringArray;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+#[cfg(feature = "date-time")]
+use arrow_schema::TimeUnit;
+
+use crate::index::Index;
+use crate::series::Series;
+use crate::traits::ColumnType;
+use crate::traits::Label;
+use crate::types::Column;
+#[cfg(feature = "date-time")]
+use crate::types::Date;
+#[cfg(feature = "date-time")]
+use crate::types::DateTime;
+#[cfg(feature = "date-time")]
+use crate::types::Time;
+
+use super::Frame;
+use super::UnsupportedArrowType;
+
+/// Converts each value of a `Series` with the given function into a new
+/// primitive Arrow array.
+fn map_array<L, V, T, F>(series: &Series<L, V>, convert: F) -> ArrayRef
+where
+    L: Label,
+    V: ColumnType,
+    T: ArrowPrimitiveType,
+    F: Fn(&V) -> T::Native,
+{
+    Arc::new(PrimitiveArray::<T>::from_iter_values(series.values().iter().map(convert)))
+}
+
+/// Converts each present value of a `Series` with the given function into a
+/// new nullable primitive Arrow array.
+fn map_opt_array<L, V, T, F>(series: &Series<L, Option<V>>, convert: F) -> ArrayRef
+where
+    L: Label,
+    V: ColumnType,
+    Option<V>: ColumnType,
+    T: ArrowPrimitiveType,
+    F: Fn(&V) -> T::Native,
+{
+    Arc::new(PrimitiveArray::<T>::from_iter(series.values().iter().map(|v| v.as_ref().map(&convert))))
+}
+
+#[cfg(feature = "date-time")]
+fn date_to_days(date: &Date) -> i32 {
+    (*date - Date::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32
+}
+
+#[cfg(feature = "date-time")]
+fn time_to_micros(time: &Time) -> i64 {
+    (*time - Time::MIN).num_microseconds().unwrap()
+}
+
+#[cfg(feature = "date-time")]
+fn datetime_to_micros(datetime: &DateTime) -> i64 {
+    datetime.and_utc().timestamp_micros()
+}
+
+/// Converts a `Column` into an Arrow array.
+fn to_array<L: Label>(column: &Column<L>) -> ArrayRef {
+    macro_rules! to_array_variants {
+        ( $( $name:ident, $opt_name:ident => $array:ty; )* ) => {
+            match column {
+                $(
+                    Column::$name(s) => Arc::new(<$array>::from(s.values().to_vec())),
+                    Column::$opt_name(s) => Arc::new(<$array>::from(s.values().to_vec())),
+                )*
+                Column::ISize(s) => map_array::<_, _, Int64Type, _>(s, |&v| v as i64),
+                Column::OptISize(s) => map_opt_array::<_, _, Int64Type, _>(s, |&v| v as i64),
+                Column::USize(s) => map_array::<_, _, UInt64Type, _>(s, |&v| v as u64),
+                Column::OptUSize(s) => map_opt_array::<_, _, UInt64Type, _>(s, |&v| v as u64),
+                #[cfg(feature = "date-time")]
+                Column::Date(s) => map_array::<_, _, Date32Type, _>(s, date_to_days),
+                #[cfg(feature = "date-time")]
+                Column::OptDate(s) => map_opt_array::<_, _, Date32Type, _>(s, date_to_days),
+                #[cfg(feature = "date-time")]
+                Column::Time(s) => map_array::<_, _, Time64MicrosecondType, _>(s, time_to_micros),
+                #[cfg(feature = "date-time")]
+                Column::OptTime(s) => map_opt_array::<_, _, Time64MicrosecondType, _>(s, time_to_micros),
+                #[cfg(feature = "date-time")]
+                Column::DateTime(s) => map_array::<_, _, TimestampMicrosecondType, _>(s, datetime_to_micros),
+                #[cfg(feature = "date-time")]
+                Column::OptDateTime(s) => map_opt_array::<_, _, TimestampMicrosecondType, _>(s, datetime_to_micros),
+                column => {
+                    let strings =
+                        (0..column.len())
+                        .map(|p| column.datum(p).unwrap())
+                        .map(|datum| if datum.is_none() { None } else { Some(datum.to_string()) })
+                        .collect::<Vec<_>>()
+                    ;
+
+                    Arc::new(StringArray::from(strings))
+                },
+            }
+        };
+    }
+
+    to_array_variants!(
+        I8, OptI8 => PrimitiveArray<Int8Type>;
+        I16, OptI16 => PrimitiveArray<Int16Type>;
+        I32, OptI32 => PrimitiveArray<Int32Type>;
+        I64, OptI64 => PrimitiveArray<Int64Type>;
+        U8, OptU8 => PrimitiveArray<UInt8Type>;
+        U16, OptU16 => PrimitiveArray<UInt16Type>;
+        U32, OptU32 => PrimitiveArray<UInt32Type>;
+        U64, OptU64 => PrimitiveArray<UInt64Type>;
+        F32, OptF32 => PrimitiveArray<Float32Type>;
+        F64, OptF64 => PrimitiveArray<Float64Type>;
+        Bool, OptBool => BooleanArray;
+        Str, OptStr => StringArray;
+    )
+}
+
+/// Reads each value of an Arrow array with the given function, creating a
+/// `Column` with `Option` values if `optional` is `true`, with nulls becoming
+/// `None`. Returns `None` if the function cannot read one of the non-null
+/// values.
+fn read_column<T, F>(array: &dyn Array, optional: bool, index: Index<usize>, read: F) -> Option<Column<usize>>
+where
+    T: ColumnType,
+    Option<T>: ColumnType,
+    F: Fn(usize) -> Option<T>,
+{
+    let values = (0..array.len()).map(|i| if array.is_valid(i) { read(i).map(Some) } else { Some(None) }).collect::<Option<_>>()?;
+    Some(Column::from_options(index, values, optional))
+}
+
+/// Converts an Arrow array into a `Column`. The `Column` has `Option` values
+/// if the field is nullable or the array contains nulls. If the data type is
+/// not supported, or a date or time value is outside the range of its column
+/// type, an `UnsupportedArrowType` error is returned.
+fn from_array(field: &Field, array: &dyn Array, index: Index<usize>) -> Result<Column<usize>, UnsupportedArrowType> {
+    let optional = field.is_nullable() || array.null_count() > 0;
+    let unsupported = |data_type| UnsupportedArrowType { name: field.name().clone(), data_type };
+
+    macro_rules! from_primitive_types {
+        ( $( $data_type:ident => $type:ty; )* ) => {
+            match array.data_type() {
+                $(
+                    DataType::$data_type => {
+                        let array = array.as_primitive::<$type>();
+                        read_column(array, optional, index, |i| Some(array.value(i)))
+                    },
+                )*
+                DataType::Boolean => {
+                    let array = array.as_boolean();
+                    read_column(array, optional, index, |i| Some(array.value(i)))
+                },
+                DataType::Utf8 => {
+                    let array = array.as_string::<i32>();
+                    read_column(array, optional, index, |i| Some(array.value(i).to_string()))
+                },
+                DataType::LargeUtf8 => {
+                    let array = array.as_string::<i64>();
+                    read_column(array, optional, index, |i| Some(array.value(i).to_string()))
+                },
+                #[cfg(feature = "date-time")]
+                DataType::Date32 => {
+                    let array = array.as_primitive::<Date32Type>();
+                    read_column(array, optional, index, |i| array.value_as_date(i))
+                },
+                #[cfg(feature = "date-time")]
+                DataType::Time64(TimeUnit::Microsecond) => {
+                    let array = array.as_primitive::<Time64MicrosecondType>();
+                    read_column(array, optional, index, |i| array.value_as_time(i))
+                },
+                #[cfg(feature = "date-time")]
+                DataType::Timestamp(TimeUnit::Microsecond, None) => {
+                    let array = array.as_primitive::<TimestampMicrosecondType>();
+                    read_column(array, optional, index, |i| array.value_as_datetime(i))
+                },
+                data_type => { return Err(unsupported(data_type.to_string())); },
+            }
+        };
+    }
+
+    let column = from_primitive_types!(
+        Int8 => Int8Type;
+        Int16 => Int16Type;
+        Int32 => Int32Type;
+        Int64 => Int64Type;
+        UInt8 => UInt8Type;
+        UInt16 => UInt16Type;
+        UInt32 => UInt32Type;
+        UInt64 => UInt64Type;
+        Float32 => Float32Type;
+        Float64 => Float64Type;
+    );
+
+    column.ok_or_else(|| unsupported(format!("{} with out-of-range values", array.data_type())))
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Converts the columns of this `Frame` into an Arrow record batch with
+    /// one field per column, in order. The row labels are not included.
+    pub fn to_record_batch(&self) -> RecordBatch {
+        let (fields, arrays): (Vec<_>, Vec<_>) =
+            self.1
+            .iter()
+            .map(|(name, column)| {
+                let array = to_array(column);
+                (Field::new(name.as_str(), array.data_type().clone(), column.is_optional()), array)
+            })
+            .unzip()
+        ;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(self.num_rows()));
+
+        RecordBatch::try_new_with_options(Arc::new(Schema::new(fields)), arrays, &options).unwrap()
+    }
+}
+
+impl Frame<usize> {
+    /// Creates a new `Frame` from an Arrow record batch, with one row per
+    /// record labeled from the given start, and one column per field.
+    pub(crate) fn from_record_batch_at(batch: &RecordBatch, start: usize) -> Result<Self, UnsupportedArrowType> {
+        let index = Index::from_iter(start..(start + batch.num_rows()));
+
+        let columns =
+            batch.schema()
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(|(field, array)| {
+                from_array(field, array.as_ref(), index.clone()).map(|column| (field.name().clone(), column))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+        ;
+
+        Ok(Self::from_columns(index, columns).unwrap())
+    }
+
+    /// Creates a new `Frame` from an Arrow record batch, with one row per
+    /// record, labeled `0..n`, and one column per field. Columns have `Option`
+    /// values if their field is nullable or contains nulls. If a field has a
+    /// data type that has no matching column type, or has date or time values
+    /// outside the range of its column type, an `UnsupportedArrowType` error is
+    /// returned.
+    pub fn from_record_batch(batch: &RecordBatch) -> Result<Self, UnsupportedArrowType> {
+        Self::from_record_batch_at(batch, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::types::DType;
+
+    #[test]
+    fn round_trip() {
+        let index = Index::from_iter("abc".chars());
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("i8", Column::from(Series::from_values(index.clone(), vec![1i8, -2, 3]).unwrap())),
+            ("usize", Column::from(Series::from_values(index.clone(), vec![1usize, 2, 3]).unwrap())),
+            ("f64", Column::from(Series::from_values(index.clone(), vec![Some(0.5f64), None, Some(1.5)]).unwrap())),
+            ("bool", Column::from(Series::from_values(index.clone(), vec![true, false, true]).unwrap())),
+            ("str", Column::from(Series::from_values(index.clone(), vec![None, Some(String::from("x")), None]).unwrap())),
+            ("char", Column::from(Series::from_values(index, vec!['p', 'q', 'r']).unwrap())),
+        ]).unwrap();
+
+        let batch = frame.to_record_batch();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.schema().field(1).data_type(), &DataType::UInt64);
+        assert!(batch.schema().field(2).is_nullable());
+        assert_eq!(batch.schema().field(5).data_type(), &DataType::Utf8);
+
+        let read = Frame::from_record_batch(&batch).unwrap();
+        assert_eq!(read.index(), &Index::from_iter(0..3));
+        assert_eq!(
+            read.dtypes().into_iter().map(|(_, dtype)| dtype).collect::<Vec<_>>(),
+            vec![DType::I8, DType::U64, DType::F64, DType::Bool, DType::Str, DType::Str],
+        );
+        assert_eq!(read.column_as::<i8>("i8").unwrap().values(), &[1, -2, 3]);
+        assert_eq!(read.column_as::<Option<f64>>("f64").unwrap().values(), &[Some(0.5), None, Some(1.5)]);
+        assert_eq!(read.column_as::<Option<String>>("str").unwrap().values(), &[None, Some(String::from("x")), None]);
+        assert_eq!(read.column_as::<String>("char").unwrap().values()[1], "q");
+
+        let empty = Frame::<char>::with_index(Index::from_iter("ab".chars())).to_record_batch();
+        assert_eq!((empty.num_rows(), empty.num_columns()), (2, 0));
+    }
+
+    #[cfg(feature = "date-time")]
+    #[test]
+    fn round_trip_date_time() {
+        let index = Index::from_iter(0..2);
+        let date = Date::from_ymd_opt(2021, 3, 14).unwrap();
+        let time = Time::from_hms_micro_opt(15, 9, 26, 535_897).unwrap();
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("date", Column::from(Series::from_values(index.clone(), vec![date, Date::from_ymd_opt(1969, 12, 31).unwrap()]).unwrap())),
+            ("time", Column::from(Series::from_values(index.clone(), vec![Some(time), None]).unwrap())),
+            ("datetime", Column::from(Series::from_values(index, vec![date.and_time(time), date.and_time(Time::MIN)]).unwrap())),
+        ]).unwrap();
+
+        let read = Frame::from_record_batch(&frame.to_record_batch()).unwrap();
+        assert_eq!(read.schema(), frame.schema());
+        assert_eq!(read.column_as::<Date>("date").unwrap().values(), frame.column_as::<Date>("date").unwrap().values());
+        assert_eq!(read.column_as::<Option<Time>>("time").unwrap().values(), &[Some(time), None]);
+        assert_eq!(read.column_as::<DateTime>("datetime").unwrap().values()[0], date.and_time(time));
+
+        let schema = Schema::new(vec![Field::new("date", DataType::Date32, true)]);
+        let array: ArrayRef = Arc::new(PrimitiveArray::<Date32Type>::from(vec![Some(0), None, Some(i32::MAX)]));
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![array]).unwrap();
+
+        let err = Frame::from_record_batch(&batch).unwrap_err();
+        assert_eq!(err.name, "date");
+        assert_eq!(err.data_type, "Date32 with out-of-range values");
+    }
+
+    #[test]
+    fn unsupported() {
+        let schema = Schema::new(vec![Field::new("half", DataType::Float16, false)]);
+        let array: ArrayRef = Arc::new(PrimitiveArray::<Float16Type>::from_iter_values(std::iter::empty()));
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![array]).unwrap();
+
+        let err = Frame::from_record_batch(&batch).unwrap_err();
+        assert_eq!(err.name, "half");
+        assert_eq!(err.data_type, "Float16");
+    }
+}
@@ -0,0 +1,208 @@
+//! Concatenation of `Frame`s, either by stacking rows or by combining columns.
+
+use indexmap::IndexSet;
+
+use crate::index::Index;
+use crate::series::MisalignedIndex;
+use crate::series::OverlappingIndex;
+use crate::traits::Label;
+use crate::types::Column;
+
+use super::DTypeMismatch;
+use super::DuplicateColumnName;
+use super::Frame;
+use super::InvalidConcat;
+use super::MissingColumn;
+
+/// How to handle `Frame`s that do not all have the same columns when stacking
+/// their rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MismatchPolicy {
+    /// Return a `MissingColumn` error.
+    Error,
+    /// Fill the rows of a `Frame` without the column with `None`.
+    FillNone,
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Stacks the rows of the given `Frame`s end to end, in order. The new
+    /// `Frame` has every column name in order of first appearance. If any
+    /// labels are contained in more than one `Frame`, an `OverlappingIndex`
+    /// error listing them is returned. If a `Frame` is missing a column, the
+    /// given policy decides whether a `MissingColumn` error is returned or its
+    /// rows are filled with `None`. Columns with the same name must have the
+    /// same base type, or else a `DTypeMismatch` error is returned; they have
+    /// `Option` values if any of the stacked columns do.
+    pub fn concat_rows<I>(frames: I, policy: MismatchPolicy) -> Result<Self, InvalidConcat<L>>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let frames = frames.into_iter().collect::<Vec<_>>();
+
+        let mut index = Index::with_capacity(frames.iter().map(Self::num_rows).sum());
+        let mut overlapping = Vec::new();
+
+        for label in frames.iter().flat_map(|frame| frame.0.iter()) {
+            if !index.push(label.clone()) {
+                overlapping.push(label.clone());
+            }
+        }
+
+        if !overlapping.is_empty() {
+            return Err(OverlappingIndex { labels: overlapping }.into());
+        }
+
+        let names =
+            frames
+            .iter()
+            .flat_map(|frame| frame.column_names())
+            .collect::<IndexSet<_>>()
+        ;
+
+        let mut columns = Vec::with_capacity(names.len());
+
+        for &name in &names {
+            let parts =
+                frames
+                .iter()
+                .map(|frame| (frame.column(name), frame.num_rows()))
+                .collect::<Vec<_>>()
+            ;
+
+            if policy == MismatchPolicy::Error && parts.iter().any(|(column, _)| column.is_none()) {
+                return Err(MissingColumn { name: name.to_string() }.into());
+            }
+
+            let column = Column::concat(&parts, index.clone(), name).ok_or_else(|| {
+                let mut present = parts.iter().filter_map(|&(column, _)| column);
+                let first = present.next().unwrap();
+                let found = present.find(|column| column.dtype() != first.dtype()).unwrap();
+
+                DTypeMismatch { name: name.to_string(), expected: first.dtype_name(), found: found.dtype_name() }
+            })?;
+
+            columns.push((name.to_string(), column));
+        }
+
+        Ok(Self::new_inner(index, columns.into_iter().collect()))
+    }
+
+    /// Combines the columns of the given `Frame`s side by side, in order. The
+    /// new `Frame` has the row `Index` of the first `Frame`, and the rows of
+    /// the others are reordered to match it by label. If a `Frame` does not
+    /// have the same set of labels as the first, a `MisalignedIndex` error is
+    /// returned, and if a column name is repeated, a `DuplicateColumnName`
+    /// error is returned.
+    pub fn concat_columns<I>(frames: I) -> Result<Self, InvalidConcat<L>>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut frames = frames.into_iter();
+
+        let mut combined = match frames.next() {
+            Some(frame) => frame,
+            None => return Ok(Self::new()),
+        };
+
+        for frame in frames {
+            let missing = combined.0.difference(&frame.0).cloned().collect::<Vec<_>>();
+            let extra = frame.0.difference(&combined.0).cloned().collect::<Vec<_>>();
+
+            if !missing.is_empty() || !extra.is_empty() {
+                return Err(MisalignedIndex { missing, extra }.into());
+            }
+
+            // Only reorder if the labels are not already in row order.
+            let frame =
+                if frame.0 == combined.0 { frame }
+                else { frame.loc_multi(combined.0.iter()).unwrap() }
+            ;
+
+            for (name, column) in frame.1 {
+                if combined.1.contains_key(&name) {
+                    return Err(DuplicateColumnName { name }.into());
+                }
+
+                let column = column.relabeled(combined.0.clone(), &name);
+                combined.1.insert(name, column);
+            }
+        }
+
+        Ok(combined)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::series::Series;
+    use crate::traits::ColumnType;
+
+    fn frame(labels: &str, columns: Vec<(&str, Column<char>)>) -> Frame<char> {
+        Frame::from_columns(Index::from_iter(labels.chars()), columns).unwrap()
+    }
+
+    fn column<V: ColumnType>(values: Vec<V>) -> Column<char> {
+        let index = Index::from_iter(('a'..).take(values.len()));
+        V::into_column(Series::from_values(index, values).unwrap())
+    }
+
+    #[test]
+    fn concat_rows() {
+        let top = frame("ab", vec![("x", column(vec![1, 2])), ("y", column(vec!['p', 'q']))]);
+        let bottom = frame("cd", vec![("y", column(vec!['r', 's'])), ("x", column(vec![None, Some(4)]))]);
+
+        let stacked = Frame::concat_rows(vec![top.clone(), bottom.clone()], MismatchPolicy::Error).unwrap();
+        assert_eq!(stacked.index(), &Index::from_iter("abcd".chars()));
+        assert_eq!(stacked.column_names().collect::<Vec<_>>(), vec!["x", "y"]);
+        assert_eq!(stacked.column_as::<Option<i32>>("x").unwrap().values(), &[Some(1), Some(2), None, Some(4)]);
+        assert_eq!(stacked.column_as::<char>("y").unwrap().values(), &['p', 'q', 'r', 's']);
+        assert_eq!(stacked.column_as::<char>("y").unwrap().name(), Some("y"));
+
+        let extra = frame("e", vec![("z", column(vec!['t']))]);
+
+        let err = Frame::concat_rows(vec![top.clone(), extra.clone()], MismatchPolicy::Error).unwrap_err();
+        assert!(matches!(err, InvalidConcat::MissingColumn(MissingColumn { ref name }) if name == "x"));
+
+        let stacked = Frame::concat_rows(vec![top.clone(), extra], MismatchPolicy::FillNone).unwrap();
+        assert_eq!(stacked.column_names().collect::<Vec<_>>(), vec!["x", "y", "z"]);
+        assert_eq!(stacked.column_as::<Option<i32>>("x").unwrap().values(), &[Some(1), Some(2), None]);
+        assert_eq!(stacked.column_as::<Option<char>>("z").unwrap().values(), &[None, None, Some('t')]);
+
+        let err = Frame::concat_rows(vec![top.clone(), top.clone()], MismatchPolicy::Error).unwrap_err();
+        assert!(matches!(err, InvalidConcat::OverlappingIndex(OverlappingIndex { ref labels }) if labels == &['a', 'b']));
+
+        let wrong = frame("c", vec![("x", column(vec!['!'])), ("y", column(vec!['r']))]);
+        let err = Frame::concat_rows(vec![top, wrong], MismatchPolicy::Error).unwrap_err();
+        assert!(matches!(err, InvalidConcat::DTypeMismatch(DTypeMismatch { ref name, .. }) if name == "x"));
+
+        assert_eq!(Frame::<char>::concat_rows(vec![], MismatchPolicy::Error).unwrap().shape(), (0, 0));
+    }
+
+    #[test]
+    fn concat_columns() {
+        let left = frame("abc", vec![("x", column(vec![1, 2, 3]))]);
+        let right = frame("abc", vec![("y", column(vec!['p', 'q', 'r']))]).loc_multi(&['c', 'a', 'b']).unwrap();
+
+        let combined = Frame::concat_columns(vec![left.clone(), right]).unwrap();
+        assert_eq!(combined.index(), &Index::from_iter("abc".chars()));
+        assert_eq!(combined.column_names().collect::<Vec<_>>(), vec!["x", "y"]);
+        assert_eq!(combined.column_as::<char>("y").unwrap().values(), &['p', 'q', 'r']);
+        assert_eq!(combined.column_as::<char>("y").unwrap().index(), combined.index());
+
+        let err = Frame::concat_columns(vec![left.clone(), left.clone()]).unwrap_err();
+        assert!(matches!(err, InvalidConcat::DuplicateColumnName(DuplicateColumnName { ref name }) if name == "x"));
+
+        let short = frame("ab", vec![("z", column(vec![0, 0]))]);
+        let err = Frame::concat_columns(vec![left, short]).unwrap_err();
+        assert!(matches!(err, InvalidConcat::MisalignedIndex(MisalignedIndex { ref missing, .. }) if missing == &['c']));
+
+        assert_eq!(Frame::<char>::concat_columns(vec![]).unwrap().shape(), (0, 0));
+    }
+}
@@ -0,0 +1,440 @@
+//! Reading and writing of `Frame`s and `Series` as CSV data.
+
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::iter::FromIterator;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::index::Index;
+use crate::series::display::LabelDisplay;
+use crate::series::Series;
+use crate::traits::ColumnType;
+use crate::traits::Label;
+use crate::types::Column;
+use crate::types::Datum;
+
+use super::DuplicateColumnName;
+use super::Frame;
+use super::InvalidCsv;
+
+/// Options for reading CSV data into a `Frame`.
+#[derive(Debug, Clone)]
+pub struct ReadCsvOptions {
+    has_headers: bool,
+    delimiter: u8,
+    quote: u8,
+    null_tokens: Vec<String>,
+}
+
+impl Default for ReadCsvOptions {
+    fn default() -> Self {
+        Self {
+            has_headers: true,
+            delimiter: b',',
+            quote: b'"',
+            null_tokens: vec![String::new(), String::from("NA"), String::from("null")],
+        }
+    }
+}
+
+impl ReadCsvOptions {
+    /// Creates a new `ReadCsvOptions` for comma-delimited data with a header
+    /// row, where empty fields, `NA` and `null` are missing values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the first row contains the column names. If not, columns
+    /// are named by their position, starting from `0`.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Sets the byte that separates fields.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the byte that quotes fields.
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets the field values that are read as missing values.
+    pub fn null_tokens<I, S>(mut self, null_tokens: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.null_tokens = null_tokens.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// Options for writing a `Frame` or `Series` as CSV data.
+#[derive(Debug, Clone)]
+pub struct WriteCsvOptions {
+    has_headers: bool,
+    include_index: bool,
+    delimiter: u8,
+    float_precision: Option<usize>,
+    null_token: String,
+}
+
+impl Default for WriteCsvOptions {
+    fn default() -> Self {
+        Self {
+            has_headers: true,
+            include_index: false,
+            delimiter: b',',
+            float_precision: None,
+            null_token: String::new(),
+        }
+    }
+}
+
+impl WriteCsvOptions {
+    /// Creates a new `WriteCsvOptions` for comma-delimited data with a header
+    /// row and without the row labels, where missing values are empty fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to write a first row with the column names.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Sets whether to write the row labels as a first, unnamed column.
+    pub fn include_index(mut self, include_index: bool) -> Self {
+        self.include_index = include_index;
+        self
+    }
+
+    /// Sets the byte that separates fields.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the number of decimal places to write floating-point values with,
+    /// or `None` to write them in full.
+    pub fn float_precision(mut self, float_precision: Option<usize>) -> Self {
+        self.float_precision = float_precision;
+        self
+    }
+
+    /// Sets the field value that missing values are written as.
+    pub fn null_token<S>(mut self, null_token: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.null_token = null_token.into();
+        self
+    }
+
+    /// Formats a single value as a field.
+    fn format(&self, datum: Datum<'_>) -> String {
+        match (datum, self.float_precision) {
+            (datum, _) if datum.is_none() => self.null_token.clone(),
+            (Datum::F32(v), Some(p)) | (Datum::OptF32(Some(v)), Some(p)) => format!("{:.*}", p, v),
+            (Datum::F64(v), Some(p)) | (Datum::OptF64(Some(v)), Some(p)) => format!("{:.*}", p, v),
+            (datum, _) => datum.to_string(),
+        }
+    }
+}
+
+/// Returns `true` if every present field parses as a `T`.
+fn all_parse<T: FromStr>(fields: &[String], nulls: &[bool]) -> bool {
+    fields.iter().zip(nulls).all(|(field, &null)| null || field.parse::<T>().is_ok())
+}
+
+/// Parses every present field as a `T`, creating a `Column` with `Option`
+/// values if any fields are missing.
+fn parse_column<T>(fields: Vec<String>, nulls: &[bool], index: Index<usize>) -> Column<usize>
+where
+    T: ColumnType + FromStr,
+    Option<T>: ColumnType,
+{
+    let parse = |field: String| field.parse::<T>().ok().unwrap();
+
+    let values = fields.into_iter().zip(nulls).map(|(field, &null)| if null { None } else { Some(parse(field)) }).collect();
+    Column::from_options(index, values, false)
+}
+
+/// Creates a `Column` from the raw fields of a CSV column, using the first of
+/// `Bool`, `I64`, `F64`, or `Str` that every present field parses as.
+fn infer_column(fields: Vec<String>, null_tokens: &[String], index: Index<usize>) -> Column<usize> {
+    let nulls = fields.iter().map(|field| null_tokens.contains(field)).collect::<Vec<_>>();
+
+    if nulls.iter().all(|&null| null) {
+        parse_column::<String>(fields, &nulls, index)
+    } else if all_parse::<bool>(&fields, &nulls) {
+        parse_column::<bool>(fields, &nulls, index)
+    } else if all_parse::<i64>(&fields, &nulls) {
+        parse_column::<i64>(fields, &nulls, index)
+    } else if all_parse::<f64>(&fields, &nulls) {
+        parse_column::<f64>(fields, &nulls, index)
+    } else {
+        parse_column::<String>(fields, &nulls, index)
+    }
+}
+
+impl Frame<usize> {
+    /// Reads CSV data into a new `Frame` with one row per record, labeled
+    /// `0..n`. The type of each column is inferred from its values, and
+    /// columns with missing values have `Option` values. If the data is
+    /// malformed, e.g. records have differing lengths, a `Csv` error is
+    /// returned, and if a column name is repeated, a `DuplicateColumnName`
+    /// error is returned.
+    pub fn read_csv<R>(reader: R, options: &ReadCsvOptions) -> Result<Self, InvalidCsv>
+    where
+        R: Read,
+    {
+        let mut reader =
+            ::csv::ReaderBuilder::new()
+            .has_headers(options.has_headers)
+            .delimiter(options.delimiter)
+            .quote(options.quote)
+            .from_reader(reader)
+        ;
+
+        let mut names =
+            if options.has_headers { reader.headers()?.iter().map(String::from).collect() }
+            else { Vec::new() }
+        ;
+
+        let mut fields: Vec<Vec<String>> = vec![Vec::new(); names.len()];
+
+        for record in reader.records() {
+            let record = record?;
+
+            // Without headers, the first record decides the number of columns.
+            if fields.is_empty() && names.is_empty() {
+                names = (0..record.len()).map(|c| c.to_string()).collect();
+                fields = vec![Vec::new(); record.len()];
+            }
+
+            for (column, field) in fields.iter_mut().zip(record.iter()) {
+                column.push(field.to_string());
+            }
+        }
+
+        let num_rows = fields.first().map_or(0, Vec::len);
+        let index = Index::from_iter(0..num_rows);
+
+        let mut columns = Vec::with_capacity(names.len());
+
+        for (name, fields) in names.into_iter().zip(fields) {
+            if columns.iter().any(|(n, _)| n == &name) {
+                return Err(DuplicateColumnName { name }.into());
+            }
+
+            let column = infer_column(fields, &options.null_tokens, index.clone());
+            columns.push((name, column));
+        }
+
+        Ok(Self::from_columns(index, columns).unwrap())
+    }
+
+    /// Reads a CSV file at the given path into a new `Frame`, as with
+    /// `read_csv`.
+    pub fn read_csv_path<P>(path: P, options: &ReadCsvOptions) -> Result<Self, InvalidCsv>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).map_err(::csv::Error::from)?;
+        Self::read_csv(file, options)
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Writes this `Frame` as CSV data, with one record per row.
+    pub fn write_csv<W>(&self, writer: W, options: &WriteCsvOptions) -> Result<(), ::csv::Error>
+    where
+        W: Write,
+    {
+        let mut writer =
+            ::csv::WriterBuilder::new()
+            .delimiter(options.delimiter)
+            .from_writer(writer)
+        ;
+
+        if options.has_headers {
+            let index_header = if options.include_index { Some("") } else { None };
+            writer.write_record(index_header.into_iter().chain(self.column_names()))?;
+        }
+
+        for row in self.rows() {
+            let label = if options.include_index { Some(LabelDisplay(row.label()).to_string()) } else { None };
+            let fields = row.iter().map(|(_, datum)| options.format(datum));
+
+            writer.write_record(label.into_iter().chain(fields))?;
+        }
+
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes this `Frame` as CSV data to a file at the given path, as with
+    /// `write_csv`. The file is created if it does not exist, and truncated
+    /// if it does.
+    pub fn write_csv_path<P>(&self, path: P, options: &WriteCsvOptions) -> Result<(), ::csv::Error>
+    where
+        P: AsRef<Path>,
+    {
+        self.write_csv(File::create(path)?, options)
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: ColumnType,
+{
+    /// Writes this `Series` as CSV data, with one record per label/value pair.
+    /// The header is the name of this `Series`, or `0` if it has none.
+    pub fn write_csv<W>(&self, writer: W, options: &WriteCsvOptions) -> Result<(), ::csv::Error>
+    where
+        W: Write,
+    {
+        let name = self.name().unwrap_or("0");
+        let column = V::into_column(self.clone());
+
+        Frame::from_columns(self.index().clone(), vec![(name, column)]).unwrap().write_csv(writer, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::types::DType;
+
+    #[test]
+    fn read_csv() {
+        let data = "name,age,score,member\nann,31,2.5,true\nbob,NA,4,false\n\"c, d\",7,,true\n";
+        let frame = Frame::read_csv(data.as_bytes(), &ReadCsvOptions::new()).unwrap();
+
+        assert_eq!(frame.index(), &Index::from_iter(0..3));
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["name", "age", "score", "member"]);
+        assert_eq!(
+            frame.dtypes().into_iter().map(|(_, dtype)| dtype).collect::<Vec<_>>(),
+            vec![DType::Str, DType::I64, DType::F64, DType::Bool],
+        );
+
+        assert_eq!(frame.column_as::<String>("name").unwrap().values()[2], "c, d");
+        assert_eq!(frame.column_as::<Option<i64>>("age").unwrap().values(), &[Some(31), None, Some(7)]);
+        assert_eq!(frame.column_as::<Option<f64>>("score").unwrap().values(), &[Some(2.5), Some(4.0), None]);
+        assert_eq!(frame.column_as::<bool>("member").unwrap().values(), &[true, false, true]);
+    }
+
+    #[test]
+    fn read_csv_options() {
+        let data = "1;'a;b';-\n2;c;3\n";
+        let options = ReadCsvOptions::new().has_headers(false).delimiter(b';').quote(b'\'').null_tokens(vec!["-"]);
+        let frame = Frame::read_csv(data.as_bytes(), &options).unwrap();
+
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["0", "1", "2"]);
+        assert_eq!(frame.column_as::<i64>("0").unwrap().values(), &[1, 2]);
+        assert_eq!(frame.column_as::<String>("1").unwrap().values(), &[String::from("a;b"), String::from("c")]);
+        assert_eq!(frame.column_as::<Option<i64>>("2").unwrap().values(), &[None, Some(3)]);
+
+        let frame = Frame::read_csv("a,b\n".as_bytes(), &ReadCsvOptions::new()).unwrap();
+        assert_eq!(frame.shape(), (0, 2));
+
+        let frame = Frame::read_csv("a\nNA\n".as_bytes(), &ReadCsvOptions::new()).unwrap();
+        assert_eq!(frame.column_as::<Option<String>>("a").unwrap().values(), &[None]);
+    }
+
+    #[test]
+    fn read_csv_errors() {
+        let err = Frame::read_csv("a,b\n1,2\n3\n".as_bytes(), &ReadCsvOptions::new()).unwrap_err();
+        assert!(matches!(err, InvalidCsv::Csv(_)));
+
+        let err = Frame::read_csv("a,a\n1,2\n".as_bytes(), &ReadCsvOptions::new()).unwrap_err();
+        assert!(matches!(err, InvalidCsv::DuplicateColumnName(DuplicateColumnName { ref name }) if name == "a"));
+
+        let err = Frame::read_csv_path("/nonexistent/rustable.csv", &ReadCsvOptions::new()).unwrap_err();
+        assert!(matches!(err, InvalidCsv::Csv(_)));
+    }
+
+    #[test]
+    fn read_csv_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("read.csv");
+        std::fs::write(&path, "x,y\n1,a\n2,b\n").unwrap();
+
+        let frame = Frame::read_csv_path(&path, &ReadCsvOptions::new()).unwrap();
+
+        assert_eq!(frame.shape(), (2, 2));
+        assert_eq!(frame.column_as::<i64>("x").unwrap().values(), &[1, 2]);
+    }
+
+    #[test]
+    fn write_csv() {
+        let data = "name,age,score\nann,31,2.5\nbob,NA,0.125\n\"c, d\",7,\n";
+        let frame = Frame::read_csv(data.as_bytes(), &ReadCsvOptions::new()).unwrap();
+
+        let mut out = Vec::new();
+        frame.write_csv(&mut out, &WriteCsvOptions::new()).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "name,age,score\nann,31,2.5\nbob,,0.125\n\"c, d\",7,\n");
+
+        let options =
+            WriteCsvOptions::new()
+            .has_headers(false)
+            .include_index(true)
+            .delimiter(b'\t')
+            .float_precision(Some(2))
+            .null_token("NA")
+        ;
+
+        let mut out = Vec::new();
+        frame.write_csv(&mut out, &options).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "0\tann\t31\t2.50\n1\tbob\tNA\t0.12\n2\tc, d\t7\tNA\n");
+
+        let round_trip = {
+            let mut out = Vec::new();
+            frame.write_csv(&mut out, &WriteCsvOptions::new()).unwrap();
+            Frame::read_csv(&out[..], &ReadCsvOptions::new()).unwrap()
+        };
+        assert_eq!(round_trip.schema(), frame.schema());
+    }
+
+    #[test]
+    fn write_csv_series() {
+        let s = Series::from_iter_checked(vec![('a', Some(1.0f64)), ('b', None)]).unwrap();
+
+        let mut out = Vec::new();
+        s.write_csv(&mut out, &WriteCsvOptions::new().include_index(true)).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), ",0\na,1\nb,\n");
+
+        let s = s.rename("x");
+
+        let mut out = Vec::new();
+        s.write_csv(&mut out, &WriteCsvOptions::new().float_precision(Some(1))).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "x\n1.0\n\"\"\n");
+    }
+
+    #[test]
+    fn write_csv_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("write.csv");
+        let frame = Frame::read_csv("x,y\n1,a\n2,b\n".as_bytes(), &ReadCsvOptions::new()).unwrap();
+
+        frame.write_csv_path(&path, &WriteCsvOptions::new()).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+
+        assert_eq!(written, "x,y\n1,a\n2,b\n");
+    }
+}
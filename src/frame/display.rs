@@ -0,0 +1,211 @@
+//! Pretty-printing for `Frame`.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use crate::series::display::shown_rows;
+use crate::series::display::LabelDisplay;
+use crate::traits::Label;
+
+use super::Frame;
+
+/// The maximum width of a line before eliding the middle columns.
+const MAX_WIDTH: usize = 100;
+
+/// The spacing between adjacent columns.
+const SEP: &str = "  ";
+
+/// The marker used for elided rows and columns.
+const ELLIPSIS: &str = "...";
+
+/// Returns the display width of the widest cell.
+fn cell_width(cells: &[String]) -> usize {
+    cells.iter().map(|c| c.chars().count()).max().unwrap_or(0)
+}
+
+/// Chooses which columns to display so that lines fit within `MAX_WIDTH`,
+/// taking columns alternately from each end. `None` marks the elided middle
+/// columns.
+fn shown_columns(label_width: usize, widths: &[usize]) -> Vec<Option<usize>> {
+    let total = label_width + widths.iter().map(|w| SEP.len() + w).sum::<usize>();
+
+    if total <= MAX_WIDTH { return widths.iter().enumerate().map(|(c, _)| Some(c)).collect(); }
+
+    let budget = MAX_WIDTH.saturating_sub(label_width + SEP.len() + ELLIPSIS.len());
+
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    let (mut lo, mut hi, mut used) = (0, widths.len(), 0);
+
+    while lo < hi {
+        let from_left = left.len() <= right.len();
+        let c = if from_left { lo } else { hi - 1 };
+
+        if used + SEP.len() + widths[c] > budget { break; }
+        used += SEP.len() + widths[c];
+
+        if from_left { left.push(c); lo += 1; } else { right.push(c); hi -= 1; }
+    }
+
+    left.into_iter().map(Some)
+        .chain(Some(None))
+        .chain(right.into_iter().rev().map(Some))
+        .collect()
+}
+
+/// Displays a `Frame` as an aligned table with a header of column names and
+/// dtypes, followed by a footer with the shape. Long `Frame`s only show their
+/// first and last few rows, and wide `Frame`s only show their first and last
+/// few columns.
+impl<L> Display for Frame<L>
+where
+    L: Label,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let len = self.num_rows();
+
+        let positions = shown_rows(len);
+
+        let labels =
+            positions
+            .iter()
+            .map(|opt_p| match opt_p {
+                Some(p) => LabelDisplay(self.0.iloc(*p).unwrap()).to_string(),
+                None => String::from(ELLIPSIS),
+            })
+            .collect::<Vec<_>>()
+        ;
+
+        // Each column of cells starts with its name and dtype name.
+        let columns =
+            self.1
+            .iter()
+            .map(|(name, column)| {
+                let values = positions.iter().map(|opt_p| match opt_p {
+                    Some(p) => column.datum(*p).unwrap().to_string(),
+                    None => String::from(ELLIPSIS),
+                });
+
+                vec![name.clone(), column.dtype_name()].into_iter().chain(values).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+        ;
+
+        let label_width = cell_width(&labels);
+        let widths = columns.iter().map(|cells| cell_width(cells)).collect::<Vec<_>>();
+        let shown = shown_columns(label_width, &widths);
+
+        let ellipses = vec![String::from(ELLIPSIS); positions.len() + 2];
+
+        let shown =
+            shown
+            .into_iter()
+            .map(|opt_c| match opt_c {
+                Some(c) => (&columns[c], widths[c]),
+                None => (&ellipses, ELLIPSIS.len()),
+            })
+            .collect::<Vec<_>>()
+        ;
+
+        let write_line = |f: &mut Formatter, label: &str, row: usize| -> FmtResult {
+            let mut line = format!("{:<w$}", label, w = label_width);
+
+            for (cells, width) in &shown {
+                line.push_str(SEP);
+                line.push_str(&format!("{:>w$}", cells[row], w = width));
+            }
+
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_line(f, "", 0)?;
+        write_line(f, "", 1)?;
+
+        let rule =
+            std::iter::once(label_width)
+            .chain(shown.iter().map(|&(_, width)| width))
+            .map(|width| "-".repeat(width))
+            .collect::<Vec<_>>()
+            .join(SEP)
+        ;
+        writeln!(f, "{}", rule.trim_start())?;
+
+        for (row, label) in labels.iter().enumerate() {
+            write_line(f, label, row + 2)?;
+        }
+
+        write!(f, "[{} rows x {} columns]", len, self.num_cols())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+    use crate::series::display::END_ROWS;
+    use crate::series::Series;
+    use crate::types::Column;
+
+    #[test]
+    fn display() {
+        let index = Index::from_iter(vec![String::from("a"), String::from("bb")]);
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("id", Column::from(Series::from_values(index.clone(), vec![1, 22]).unwrap())),
+            ("score", Column::from(Series::from_values(index, vec![Some(0.5), None]).unwrap())),
+        ]).unwrap();
+
+        assert_eq!(frame.to_string(), concat!(
+            "     id        score\n",
+            "    I32  Option<F64>\n",
+            "--  ---  -----------\n",
+            "a     1          0.5\n",
+            "bb   22         None\n",
+            "[2 rows x 2 columns]",
+        ));
+
+        let frame = Frame::with_index(Index::from_iter("ab".chars()));
+        assert_eq!(frame.to_string(), "\n\n-\na\nb\n[2 rows x 0 columns]");
+    }
+
+    #[test]
+    fn display_truncated() {
+        let index = Index::from_iter(0..100);
+        let column = Column::from(Series::from_values(index.clone(), (0..100).map(|i| i * 2).collect()).unwrap());
+
+        let frame = Frame::from_columns(index, vec![("x", column)]).unwrap();
+        let lines = frame.to_string().lines().map(String::from).collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3 + 2 * END_ROWS + 2);
+        assert_eq!(lines[3], "0      0");
+        assert_eq!(lines[3 + END_ROWS], "...  ...");
+        assert_eq!(lines[3 + 2 * END_ROWS], "99   198");
+        assert_eq!(lines[4 + 2 * END_ROWS], "[100 rows x 1 columns]");
+    }
+
+    #[test]
+    fn display_elided() {
+        let index = Index::from_iter("ab".chars());
+
+        let columns =
+            (0..30)
+            .map(|c| {
+                let series = Series::from_values(index.clone(), vec![c * 1000, c]).unwrap();
+                (format!("col{}", c), Column::from(series))
+            })
+            .collect::<Vec<_>>()
+        ;
+
+        let frame = Frame::from_columns(index, columns).unwrap();
+        let lines = frame.to_string().lines().map(String::from).collect::<Vec<_>>();
+
+        assert!(lines.iter().all(|line| line.chars().count() <= MAX_WIDTH));
+        assert!(lines[0].starts_with("   col0  col1"));
+        assert!(lines[0].contains("  ...  "));
+        assert!(lines[0].ends_with("col28  col29"));
+        assert_eq!(lines[5], "[2 rows x 30 columns]");
+    }
+}
@@ -0,0 +1,775 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::error::Error;
+
+use crate::series::MisalignedIndex;
+use crate::series::OverlappingIndex;
+use crate::traits::Label;
+
+use super::group::Agg;
+
+#[derive(Debug)]
+pub struct LengthMismatch {
+    pub name: String,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for LengthMismatch {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "length mismatch between index and column {:?}: {} != {}",
+            self.name, self.expected, self.found,
+        )
+    }
+}
+
+impl Error for LengthMismatch {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct DuplicateColumnName {
+    pub name: String,
+}
+
+impl Display for DuplicateColumnName {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "found duplicate column name: {:?}", self.name)
+    }
+}
+
+impl Error for DuplicateColumnName {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+/// The reasons a set of columns cannot form a `Frame`.
+#[derive(Debug)]
+pub enum InvalidColumns {
+    LengthMismatch(LengthMismatch),
+    DuplicateColumnName(DuplicateColumnName),
+}
+
+impl Display for InvalidColumns {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::LengthMismatch(err) => Display::fmt(err, f),
+            Self::DuplicateColumnName(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for InvalidColumns {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::LengthMismatch(err) => Some(err),
+            Self::DuplicateColumnName(err) => Some(err),
+        }
+    }
+}
+
+impl From<LengthMismatch> for InvalidColumns {
+    fn from(err: LengthMismatch) -> Self {
+        Self::LengthMismatch(err)
+    }
+}
+
+impl From<DuplicateColumnName> for InvalidColumns {
+    fn from(err: DuplicateColumnName) -> Self {
+        Self::DuplicateColumnName(err)
+    }
+}
+
+#[derive(Debug)]
+pub struct MissingColumn {
+    pub name: String,
+}
+
+impl Display for MissingColumn {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "column not found: {:?}", self.name)
+    }
+}
+
+impl Error for MissingColumn {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct UnsupportedAgg {
+    pub name: String,
+    pub agg: Agg,
+    pub dtype_name: String,
+}
+
+impl Display for UnsupportedAgg {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "aggregation {:?} not supported for column {:?} of type {}",
+            self.agg, self.name, self.dtype_name,
+        )
+    }
+}
+
+impl Error for UnsupportedAgg {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+/// The reasons a set of aggregations cannot be applied to a `GroupBy`.
+#[derive(Debug)]
+pub enum InvalidAgg {
+    MissingColumn(MissingColumn),
+    UnsupportedAgg(UnsupportedAgg),
+    DuplicateColumnName(DuplicateColumnName),
+}
+
+impl Display for InvalidAgg {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::MissingColumn(err) => Display::fmt(err, f),
+            Self::UnsupportedAgg(err) => Display::fmt(err, f),
+            Self::DuplicateColumnName(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for InvalidAgg {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::MissingColumn(err) => Some(err),
+            Self::UnsupportedAgg(err) => Some(err),
+            Self::DuplicateColumnName(err) => Some(err),
+        }
+    }
+}
+
+impl From<MissingColumn> for InvalidAgg {
+    fn from(err: MissingColumn) -> Self {
+        Self::MissingColumn(err)
+    }
+}
+
+impl From<UnsupportedAgg> for InvalidAgg {
+    fn from(err: UnsupportedAgg) -> Self {
+        Self::UnsupportedAgg(err)
+    }
+}
+
+impl From<DuplicateColumnName> for InvalidAgg {
+    fn from(err: DuplicateColumnName) -> Self {
+        Self::DuplicateColumnName(err)
+    }
+}
+
+#[derive(Debug)]
+pub struct KeyTypeMismatch {
+    pub name: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl Display for KeyTypeMismatch {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "key column {:?} has mismatched types: {} != {}", self.name, self.left, self.right)
+    }
+}
+
+impl Error for KeyTypeMismatch {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+/// The reasons two `Frame`s cannot be joined on key columns.
+#[derive(Debug)]
+pub enum InvalidJoin {
+    MissingColumn(MissingColumn),
+    KeyTypeMismatch(KeyTypeMismatch),
+    DuplicateColumnName(DuplicateColumnName),
+}
+
+impl Display for InvalidJoin {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::MissingColumn(err) => Display::fmt(err, f),
+            Self::KeyTypeMismatch(err) => Display::fmt(err, f),
+            Self::DuplicateColumnName(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for InvalidJoin {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::MissingColumn(err) => Some(err),
+            Self::KeyTypeMismatch(err) => Some(err),
+            Self::DuplicateColumnName(err) => Some(err),
+        }
+    }
+}
+
+impl From<MissingColumn> for InvalidJoin {
+    fn from(err: MissingColumn) -> Self {
+        Self::MissingColumn(err)
+    }
+}
+
+impl From<KeyTypeMismatch> for InvalidJoin {
+    fn from(err: KeyTypeMismatch) -> Self {
+        Self::KeyTypeMismatch(err)
+    }
+}
+
+impl From<DuplicateColumnName> for InvalidJoin {
+    fn from(err: DuplicateColumnName) -> Self {
+        Self::DuplicateColumnName(err)
+    }
+}
+
+#[derive(Debug)]
+pub struct DuplicateEntry {
+    pub index: String,
+    pub column: String,
+}
+
+impl Display for DuplicateEntry {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "found duplicate entry for index {:?} and column {:?}", self.index, self.column)
+    }
+}
+
+impl Error for DuplicateEntry {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+/// The reasons a `Frame` cannot be pivoted.
+#[derive(Debug)]
+pub enum InvalidPivot {
+    MissingColumn(MissingColumn),
+    DuplicateEntry(DuplicateEntry),
+    UnsupportedAgg(UnsupportedAgg),
+    DuplicateColumnName(DuplicateColumnName),
+}
+
+impl Display for InvalidPivot {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::MissingColumn(err) => Display::fmt(err, f),
+            Self::DuplicateEntry(err) => Display::fmt(err, f),
+            Self::UnsupportedAgg(err) => Display::fmt(err, f),
+            Self::DuplicateColumnName(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for InvalidPivot {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::MissingColumn(err) => Some(err),
+            Self::DuplicateEntry(err) => Some(err),
+            Self::UnsupportedAgg(err) => Some(err),
+            Self::DuplicateColumnName(err) => Some(err),
+        }
+    }
+}
+
+impl From<MissingColumn> for InvalidPivot {
+    fn from(err: MissingColumn) -> Self {
+        Self::MissingColumn(err)
+    }
+}
+
+impl From<DuplicateEntry> for InvalidPivot {
+    fn from(err: DuplicateEntry) -> Self {
+        Self::DuplicateEntry(err)
+    }
+}
+
+impl From<UnsupportedAgg> for InvalidPivot {
+    fn from(err: UnsupportedAgg) -> Self {
+        Self::UnsupportedAgg(err)
+    }
+}
+
+impl From<DuplicateColumnName> for InvalidPivot {
+    fn from(err: DuplicateColumnName) -> Self {
+        Self::DuplicateColumnName(err)
+    }
+}
+
+#[derive(Debug)]
+pub struct DTypeMismatch {
+    pub name: String,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for DTypeMismatch {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "column {:?} has mismatched types: expected {}, found {}", self.name, self.expected, self.found)
+    }
+}
+
+impl Error for DTypeMismatch {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+/// The reasons a sequence of `Frame`s cannot be concatenated.
+#[derive(Debug)]
+pub enum InvalidConcat<L: Label> {
+    OverlappingIndex(OverlappingIndex<L>),
+    MisalignedIndex(MisalignedIndex<L>),
+    MissingColumn(MissingColumn),
+    DTypeMismatch(DTypeMismatch),
+    DuplicateColumnName(DuplicateColumnName),
+}
+
+impl<L: Label> Display for InvalidConcat<L> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::OverlappingIndex(err) => Display::fmt(err, f),
+            Self::MisalignedIndex(err) => Display::fmt(err, f),
+            Self::MissingColumn(err) => Display::fmt(err, f),
+            Self::DTypeMismatch(err) => Display::fmt(err, f),
+            Self::DuplicateColumnName(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl<L: Label + 'static> Error for InvalidConcat<L> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::OverlappingIndex(err) => Some(err),
+            Self::MisalignedIndex(err) => Some(err),
+            Self::MissingColumn(err) => Some(err),
+            Self::DTypeMismatch(err) => Some(err),
+            Self::DuplicateColumnName(err) => Some(err),
+        }
+    }
+}
+
+impl<L: Label> From<OverlappingIndex<L>> for InvalidConcat<L> {
+    fn from(err: OverlappingIndex<L>) -> Self {
+        Self::OverlappingIndex(err)
+    }
+}
+
+impl<L: Label> From<MisalignedIndex<L>> for InvalidConcat<L> {
+    fn from(err: MisalignedIndex<L>) -> Self {
+        Self::MisalignedIndex(err)
+    }
+}
+
+impl<L: Label> From<MissingColumn> for InvalidConcat<L> {
+    fn from(err: MissingColumn) -> Self {
+        Self::MissingColumn(err)
+    }
+}
+
+impl<L: Label> From<DTypeMismatch> for InvalidConcat<L> {
+    fn from(err: DTypeMismatch) -> Self {
+        Self::DTypeMismatch(err)
+    }
+}
+
+impl<L: Label> From<DuplicateColumnName> for InvalidConcat<L> {
+    fn from(err: DuplicateColumnName) -> Self {
+        Self::DuplicateColumnName(err)
+    }
+}
+
+/// The reasons the rows of a `Frame` cannot be converted into records.
+#[derive(Debug)]
+pub enum InvalidRecord {
+    MissingColumn(MissingColumn),
+    DTypeMismatch(DTypeMismatch),
+}
+
+impl Display for InvalidRecord {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::MissingColumn(err) => Display::fmt(err, f),
+            Self::DTypeMismatch(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for InvalidRecord {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::MissingColumn(err) => Some(err),
+            Self::DTypeMismatch(err) => Some(err),
+        }
+    }
+}
+
+impl From<MissingColumn> for InvalidRecord {
+    fn from(err: MissingColumn) -> Self {
+        Self::MissingColumn(err)
+    }
+}
+
+impl From<DTypeMismatch> for InvalidRecord {
+    fn from(err: DTypeMismatch) -> Self {
+        Self::DTypeMismatch(err)
+    }
+}
+
+/// The reasons CSV data cannot be read into a `Frame`.
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub enum InvalidCsv {
+    Csv(::csv::Error),
+    DuplicateColumnName(DuplicateColumnName),
+}
+
+#[cfg(feature = "csv")]
+impl Display for InvalidCsv {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Csv(err) => Display::fmt(err, f),
+            Self::DuplicateColumnName(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Error for InvalidCsv {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Csv(err) => Some(err),
+            Self::DuplicateColumnName(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<::csv::Error> for InvalidCsv {
+    fn from(err: ::csv::Error) -> Self {
+        Self::Csv(err)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<DuplicateColumnName> for InvalidCsv {
+    fn from(err: DuplicateColumnName) -> Self {
+        Self::DuplicateColumnName(err)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Debug)]
+pub struct UnexpectedJson {
+    pub expected: String,
+}
+
+#[cfg(feature = "serde_json")]
+impl Display for UnexpectedJson {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "unexpected JSON layout: expected {}", self.expected)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl Error for UnexpectedJson {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+/// The reasons JSON data cannot be read into a `Frame`.
+#[cfg(feature = "serde_json")]
+#[derive(Debug)]
+pub enum InvalidJson {
+    Json(::serde_json::Error),
+    UnexpectedJson(UnexpectedJson),
+    LengthMismatch(LengthMismatch),
+}
+
+#[cfg(feature = "serde_json")]
+impl Display for InvalidJson {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Json(err) => Display::fmt(err, f),
+            Self::UnexpectedJson(err) => Display::fmt(err, f),
+            Self::LengthMismatch(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl Error for InvalidJson {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Json(err) => Some(err),
+            Self::UnexpectedJson(err) => Some(err),
+            Self::LengthMismatch(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<::serde_json::Error> for InvalidJson {
+    fn from(err: ::serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<UnexpectedJson> for InvalidJson {
+    fn from(err: UnexpectedJson) -> Self {
+        Self::UnexpectedJson(err)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<LengthMismatch> for InvalidJson {
+    fn from(err: LengthMismatch) -> Self {
+        Self::LengthMismatch(err)
+    }
+}
+
+#[cfg(feature = "arrow")]
+#[derive(Debug)]
+pub struct UnsupportedArrowType {
+    pub name: String,
+    pub data_type: String,
+}
+
+#[cfg(feature = "arrow")]
+impl Display for UnsupportedArrowType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "field {:?} has unsupported Arrow data type: {}", self.name, self.data_type)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl Error for UnsupportedArrowType {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+/// The reasons a Parquet file cannot be read into a `Frame`.
+#[cfg(feature = "parquet")]
+#[derive(Debug)]
+pub enum InvalidParquet {
+    Parquet(::parquet::errors::ParquetError),
+    UnsupportedArrowType(UnsupportedArrowType),
+    MissingColumn(MissingColumn),
+}
+
+#[cfg(feature = "parquet")]
+impl Display for InvalidParquet {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Parquet(err) => Display::fmt(err, f),
+            Self::UnsupportedArrowType(err) => Display::fmt(err, f),
+            Self::MissingColumn(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl Error for InvalidParquet {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parquet(err) => Some(err),
+            Self::UnsupportedArrowType(err) => Some(err),
+            Self::MissingColumn(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<::parquet::errors::ParquetError> for InvalidParquet {
+    fn from(err: ::parquet::errors::ParquetError) -> Self {
+        Self::Parquet(err)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<UnsupportedArrowType> for InvalidParquet {
+    fn from(err: UnsupportedArrowType) -> Self {
+        Self::UnsupportedArrowType(err)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<MissingColumn> for InvalidParquet {
+    fn from(err: MissingColumn) -> Self {
+        Self::MissingColumn(err)
+    }
+}
+
+/// The reasons Arrow IPC data cannot be read into a `Frame`.
+#[cfg(feature = "ipc")]
+#[derive(Debug)]
+pub enum InvalidIpc {
+    Arrow(::arrow_schema::ArrowError),
+    UnsupportedArrowType(UnsupportedArrowType),
+}
+
+#[cfg(feature = "ipc")]
+impl Display for InvalidIpc {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Arrow(err) => Display::fmt(err, f),
+            Self::UnsupportedArrowType(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "ipc")]
+impl Error for InvalidIpc {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Arrow(err) => Some(err),
+            Self::UnsupportedArrowType(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "ipc")]
+impl From<::arrow_schema::ArrowError> for InvalidIpc {
+    fn from(err: ::arrow_schema::ArrowError) -> Self {
+        Self::Arrow(err)
+    }
+}
+
+#[cfg(feature = "ipc")]
+impl From<UnsupportedArrowType> for InvalidIpc {
+    fn from(err: UnsupportedArrowType) -> Self {
+        Self::UnsupportedArrowType(err)
+    }
+}
+
+#[cfg(feature = "sql")]
+#[derive(Debug)]
+pub struct UnsupportedSqlType {
+    pub name: String,
+    pub sql_type: String,
+}
+
+#[cfg(feature = "sql")]
+impl Display for UnsupportedSqlType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "column {:?} has unsupported SQL type: {}", self.name, self.sql_type)
+    }
+}
+
+#[cfg(feature = "sql")]
+impl Error for UnsupportedSqlType {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+/// The reasons the results of an SQL query cannot be read into a `Frame`.
+#[cfg(feature = "sql")]
+#[derive(Debug)]
+pub enum InvalidSql {
+    Sql(::rusqlite::Error),
+    UnsupportedSqlType(UnsupportedSqlType),
+    DuplicateColumnName(DuplicateColumnName),
+}
+
+#[cfg(feature = "sql")]
+impl Display for InvalidSql {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Sql(err) => Display::fmt(err, f),
+            Self::UnsupportedSqlType(err) => Display::fmt(err, f),
+            Self::DuplicateColumnName(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "sql")]
+impl Error for InvalidSql {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Sql(err) => Some(err),
+            Self::UnsupportedSqlType(err) => Some(err),
+            Self::DuplicateColumnName(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "sql")]
+impl From<::rusqlite::Error> for InvalidSql {
+    fn from(err: ::rusqlite::Error) -> Self {
+        Self::Sql(err)
+    }
+}
+
+#[cfg(feature = "sql")]
+impl From<UnsupportedSqlType> for InvalidSql {
+    fn from(err: UnsupportedSqlType) -> Self {
+        Self::UnsupportedSqlType(err)
+    }
+}
+
+#[cfg(feature = "sql")]
+impl From<DuplicateColumnName> for InvalidSql {
+    fn from(err: DuplicateColumnName) -> Self {
+        Self::DuplicateColumnName(err)
+    }
+}
+
+/// The reasons a worksheet cannot be read into a `Frame`.
+#[cfg(feature = "excel")]
+#[derive(Debug)]
+pub enum InvalidExcel {
+    Excel(::calamine::Error),
+    DuplicateColumnName(DuplicateColumnName),
+}
+
+#[cfg(feature = "excel")]
+impl Display for InvalidExcel {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Excel(err) => Display::fmt(err, f),
+            Self::DuplicateColumnName(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "excel")]
+impl Error for InvalidExcel {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Excel(err) => Some(err),
+            Self::DuplicateColumnName(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "excel")]
+impl From<::calamine::Error> for InvalidExcel {
+    fn from(err: ::calamine::Error) -> Self {
+        Self::Excel(err)
+    }
+}
+
+#[cfg(feature = "excel")]
+impl From<DuplicateColumnName> for InvalidExcel {
+    fn from(err: DuplicateColumnName) -> Self {
+        Self::DuplicateColumnName(err)
+    }
+}
@@ -0,0 +1,282 @@
+//! Reading of `Frame`s from Excel and OpenDocument spreadsheets.
+
+use std::iter::FromIterator;
+use std::path::Path;
+
+use calamine::Data;
+use calamine::Reader;
+use indexmap::IndexSet;
+
+use crate::index::Index;
+use crate::traits::ColumnType;
+use crate::types::Column;
+
+use super::DuplicateColumnName;
+use super::Frame;
+use super::InvalidExcel;
+
+/// Options for reading a worksheet into a `Frame`.
+#[derive(Debug, Clone, Default)]
+pub struct ReadExcelOptions {
+    has_headers: Option<bool>,
+}
+
+impl ReadExcelOptions {
+    /// Creates a new `ReadExcelOptions` that detects whether the first row
+    /// contains the column names.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the first row contains the column names, instead of
+    /// detecting it. If not, columns are named by their position, starting
+    /// from `0`.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = Some(has_headers);
+        self
+    }
+}
+
+/// Returns `true` if a row looks like a header, with a distinct, non-empty
+/// string in every cell.
+fn is_header(row: &[Data]) -> bool {
+    let mut seen = IndexSet::new();
+
+    !row.is_empty() && row.iter().all(|cell| match cell {
+        Data::String(s) => !s.is_empty() && seen.insert(s),
+        _ => false,
+    })
+}
+
+/// Returns `true` if a cell has no value, either by being empty or holding an
+/// error such as `#N/A`.
+fn is_missing(cell: &Data) -> bool {
+    matches!(cell, Data::Empty | Data::Error(_))
+}
+
+/// Returns the value of an integer cell, or of a float cell with an integral
+/// value that fits in an `i64`.
+fn as_i64(cell: &Data) -> Option<i64> {
+    match cell {
+        Data::Int(i) => Some(*i),
+        Data::Float(f) if f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64 => Some(*f as i64),
+        _ => None,
+    }
+}
+
+/// Returns the value of a numeric cell, with dates and times as their serial
+/// numbers.
+fn as_f64(cell: &Data) -> Option<f64> {
+    match cell {
+        Data::Int(i) => Some(*i as f64),
+        Data::Float(f) => Some(*f),
+        Data::DateTime(dt) => Some(dt.as_f64()),
+        _ => None,
+    }
+}
+
+/// Returns the value of a date and time cell.
+#[cfg(feature = "date-time")]
+fn as_datetime(cell: &Data) -> Option<crate::types::DateTime> {
+    match cell {
+        Data::DateTime(dt) if dt.is_datetime() => dt.as_datetime(),
+        _ => None,
+    }
+}
+
+/// Converts every present cell with the given function, creating a `Column`
+/// with `Option` values if any cells are missing.
+fn convert_column<T, F>(cells: &[&Data], index: Index<usize>, convert: F) -> Column<usize>
+where
+    T: ColumnType,
+    Option<T>: ColumnType,
+    F: Fn(&Data) -> T,
+{
+    let values = cells.iter().map(|&cell| if is_missing(cell) { None } else { Some(convert(cell)) }).collect();
+    Column::from_options(index, values, false)
+}
+
+/// Creates a `Column` from the cells of a worksheet column, using the first of
+/// `DateTime`, `Bool`, `I64`, `F64`, or `Str` that every present cell fits.
+/// `DateTime` is only used with the `date-time` feature, otherwise dates and
+/// times are read as their serial numbers.
+fn read_column(cells: &[&Data], index: Index<usize>) -> Column<usize> {
+    let present = cells.iter().filter(|cell| !is_missing(cell)).collect::<Vec<_>>();
+
+    if present.is_empty() {
+        return convert_column(cells, index, Data::to_string);
+    }
+
+    #[cfg(feature = "date-time")]
+    {
+        if present.iter().all(|cell| as_datetime(cell).is_some()) {
+            return convert_column(cells, index, |cell| as_datetime(cell).unwrap());
+        }
+    }
+
+    if present.iter().all(|cell| matches!(cell, Data::Bool(_))) {
+        convert_column(cells, index, |cell| cell == &Data::Bool(true))
+    } else if present.iter().all(|cell| as_i64(cell).is_some()) {
+        convert_column(cells, index, |cell| as_i64(cell).unwrap())
+    } else if present.iter().all(|cell| as_f64(cell).is_some()) {
+        convert_column(cells, index, |cell| as_f64(cell).unwrap())
+    } else {
+        convert_column(cells, index, Data::to_string)
+    }
+}
+
+impl Frame<usize> {
+    /// Reads a worksheet of an `.xlsx`, `.xlsm`, `.xlsb`, `.xls`, or `.ods`
+    /// file into a new `Frame`, with one row per row of the used range of the
+    /// worksheet, labeled `0..n`. Unless set in the options, the first row is
+    /// used as the column names if every cell in it is a distinct, non-empty
+    /// string. The type of each column is inferred from the types of its cells,
+    /// and columns with empty or error cells have `Option` values. If the file
+    /// or worksheet cannot be read, an `Excel` error is returned, and if a
+    /// column name is repeated, a `DuplicateColumnName` error is returned.
+    pub fn read_excel<P>(path: P, sheet: &str, options: &ReadExcelOptions) -> Result<Self, InvalidExcel>
+    where
+        P: AsRef<Path>,
+    {
+        let mut workbook = calamine::open_workbook_auto(path)?;
+        let range = workbook.worksheet_range(sheet)?;
+
+        let mut rows = range.rows().peekable();
+
+        let has_headers = match options.has_headers {
+            Some(has_headers) => has_headers,
+            None => rows.peek().is_some_and(|row| is_header(row)),
+        };
+
+        let names =
+            if has_headers {
+                rows.next()
+                    .unwrap_or_default()
+                    .iter()
+                    .enumerate()
+                    .map(|(c, cell)| if cell == &Data::Empty { c.to_string() } else { cell.to_string() })
+                    .collect()
+            } else {
+                (0..range.width()).map(|c| c.to_string()).collect::<Vec<_>>()
+            }
+        ;
+
+        let rows = rows.collect::<Vec<_>>();
+        let index = Index::from_iter(0..rows.len());
+
+        let mut columns = Vec::with_capacity(names.len());
+
+        for (c, name) in names.into_iter().enumerate() {
+            if columns.iter().any(|(n, _)| n == &name) {
+                return Err(DuplicateColumnName { name }.into());
+            }
+
+            let cells = rows.iter().map(|row| &row[c]).collect::<Vec<_>>();
+            columns.push((name, read_column(&cells, index.clone())));
+        }
+
+        Ok(Self::from_columns(index, columns).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rust_xlsxwriter::Workbook;
+    use tempfile::TempPath;
+
+    use crate::types::DType;
+
+    /// Writes a workbook with a single worksheet named `Data` to a unique
+    /// temporary file, filling it with the given function. The file is removed
+    /// when the returned path is dropped.
+    fn write_workbook<F>(fill: F) -> TempPath
+    where
+        F: FnOnce(&mut rust_xlsxwriter::Worksheet),
+    {
+        let path = tempfile::Builder::new().suffix(".xlsx").tempfile().unwrap().into_temp_path();
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet().set_name("Data").unwrap();
+        fill(worksheet);
+        workbook.save(&path).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn read_excel() {
+        let path = write_workbook(|sheet| {
+            sheet.write_row(0, 0, ["id", "score", "flag", "name", "blank"]).unwrap();
+
+            for r in 1..4 {
+                sheet.write_number(r, 0, r as f64).unwrap();
+                sheet.write_boolean(r, 2, r % 2 == 1).unwrap();
+                sheet.write_string(r, 3, format!("n{}", r)).unwrap();
+            }
+
+            sheet.write_number(1, 1, 0.5).unwrap();
+            sheet.write_number(3, 1, 2.0).unwrap();
+            sheet.write_string(3, 4, "").unwrap();
+        });
+
+        let frame = Frame::read_excel(&path, "Data", &ReadExcelOptions::new()).unwrap();
+
+        assert_eq!(frame.index(), &Index::from_iter(0..3));
+        assert_eq!(
+            frame.schema().fields().iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["id: I64", "score: Option<F64>", "flag: Bool", "name: Str", "blank: Option<Str>"],
+        );
+        assert_eq!(frame.column_as::<i64>("id").unwrap().values(), &[1, 2, 3]);
+        assert_eq!(frame.column_as::<Option<f64>>("score").unwrap().values(), &[Some(0.5), None, Some(2.0)]);
+        assert_eq!(frame.column_as::<bool>("flag").unwrap().values(), &[true, false, true]);
+
+        let frame = Frame::read_excel(&path, "Data", &ReadExcelOptions::new().has_headers(false)).unwrap();
+
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["0", "1", "2", "3", "4"]);
+        assert_eq!(frame.num_rows(), 4);
+        assert_eq!(frame.column("0").unwrap().dtype(), DType::Str);
+        assert_eq!(frame.column_as::<Option<String>>("1").unwrap().values()[1], Some(String::from("0.5")));
+    }
+
+    #[test]
+    fn detect_headers() {
+        let path = write_workbook(|sheet| {
+            sheet.write_row(0, 0, ["a", "a"]).unwrap();
+            sheet.write_row(1, 0, ["b", "c"]).unwrap();
+        });
+
+        let frame = Frame::read_excel(&path, "Data", &ReadExcelOptions::new()).unwrap();
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["0", "1"]);
+        assert_eq!(frame.num_rows(), 2);
+
+        let err = Frame::read_excel(&path, "Data", &ReadExcelOptions::new().has_headers(true)).unwrap_err();
+        assert!(matches!(err, InvalidExcel::DuplicateColumnName(DuplicateColumnName { ref name }) if name == "a"));
+
+        let err = Frame::read_excel(&path, "Missing", &ReadExcelOptions::new()).unwrap_err();
+
+        assert!(matches!(err, InvalidExcel::Excel(_)));
+    }
+
+    #[cfg(feature = "date-time")]
+    #[test]
+    fn read_excel_date_time() {
+        use rust_xlsxwriter::ExcelDateTime;
+        use rust_xlsxwriter::Format;
+
+        use crate::types::Date;
+
+        let path = write_workbook(|sheet| {
+            let format = Format::new().set_num_format("yyyy-mm-dd");
+
+            sheet.write_string(0, 0, "when").unwrap();
+            sheet.write_datetime_with_format(1, 0, ExcelDateTime::from_ymd(2021, 3, 14).unwrap(), &format).unwrap();
+        });
+
+        let frame = Frame::read_excel(&path, "Data", &ReadExcelOptions::new()).unwrap();
+
+        let expected = Date::from_ymd_opt(2021, 3, 14).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(frame.column_as::<crate::types::DateTime>("when").unwrap().values(), &[expected]);
+    }
+}
@@ -0,0 +1,535 @@
+//! Grouping of the rows of a `Frame` by the values of key columns.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::iter::FromIterator;
+
+use indexmap::IndexMap;
+
+use crate::index::Index;
+use crate::series::Series;
+use crate::traits::ColumnType;
+use crate::traits::Integer;
+use crate::traits::Label;
+use crate::traits::Numeric;
+use crate::types::Column;
+use crate::types::Datum;
+
+use super::DuplicateColumnName;
+use super::Frame;
+use super::InvalidAgg;
+use super::MissingColumn;
+use super::UnsupportedAgg;
+
+/// An aggregation to apply to a column within each group of a `GroupBy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Agg {
+    /// The number of non-`None` values.
+    Count,
+    /// The sum of the non-`None` values. Only supported for numeric columns.
+    /// For integer columns, a group whose sum overflows has a sum of `None`,
+    /// and the column has `Option` values if any group overflows.
+    Sum,
+    /// The arithmetic mean of the non-`None` values, skipping `NaN`s, or
+    /// `None` if there are none. Only supported for numeric columns.
+    Mean,
+    /// The smallest non-`None` value, skipping `NaN`s. Only supported for
+    /// numeric columns.
+    Min,
+    /// The largest non-`None` value, skipping `NaN`s. Only supported for
+    /// numeric columns.
+    Max,
+    /// The first value.
+    First,
+    /// The last value.
+    Last,
+}
+
+impl Agg {
+    /// Returns the lowercase name of this aggregation, which `GroupBy::agg`
+    /// uses as a suffix for the names of aggregated columns.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::Sum => "sum",
+            Self::Mean => "mean",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::First => "first",
+            Self::Last => "last",
+        }
+    }
+}
+
+/// The values of the key columns of a single row, hashed and compared as a
+/// whole.
+pub(super) struct RowKey<'a>(pub(super) Vec<Datum<'a>>);
+
+impl<'a> Hash for RowKey<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for datum in &self.0 { datum.key_hash(state); }
+    }
+}
+
+impl<'a> PartialEq for RowKey<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| a.key_eq(b))
+    }
+}
+
+impl<'a> Eq for RowKey<'a> {}
+
+/// Aggregates the values produced by a lookup function for each group. Values
+/// that are not comparable with themselves (e.g. `NaN`) are skipped. Returns
+/// `None` for aggregations that are not numeric.
+fn aggregate_numeric<R, F>(groups: &[Vec<usize>], agg: Agg, get: F) -> Option<Column<usize>>
+where
+    R: Numeric + ColumnType,
+    Option<R>: ColumnType,
+    F: Fn(usize) -> Option<R>,
+{
+    let index = Index::from_iter(0..groups.len());
+    let present = |positions: &Vec<usize>| {
+        positions.iter().filter_map(|&p| get(p)).filter(|v| v.partial_cmp(v).is_some()).collect::<Vec<_>>()
+    };
+
+    let column = match agg {
+        Agg::Sum => {
+            let values =
+                groups
+                .iter()
+                .map(|ps| present(ps).into_iter().fold(R::zero(), |acc, v| acc + v))
+                .collect()
+            ;
+
+            R::into_column(Series::from_values(index, values).unwrap())
+        },
+        Agg::Mean => {
+            let values =
+                groups
+                .iter()
+                .map(|ps| {
+                    let values = present(ps);
+                    let total = values.iter().map(|v| v.to_f64()).sum::<f64>();
+
+                    if values.is_empty() { None } else { Some(total / values.len() as f64) }
+                })
+                .collect()
+            ;
+
+            Option::<f64>::into_column(Series::from_values(index, values).unwrap())
+        },
+        Agg::Min | Agg::Max => {
+            let wanted = if agg == Agg::Min { Ordering::Less } else { Ordering::Greater };
+
+            let values =
+                groups
+                .iter()
+                .map(|ps| {
+                    present(ps)
+                    .into_iter()
+                    .fold(None, |acc, v| match acc {
+                        Some(best) if v.partial_cmp(&best) != Some(wanted) => Some(best),
+                        _ => Some(v),
+                    })
+                })
+                .collect()
+            ;
+
+            Option::<R>::into_column(Series::from_values(index, values).unwrap())
+        },
+        Agg::Count | Agg::First | Agg::Last => return None,
+    };
+
+    Some(column)
+}
+
+/// Sums the integer values produced by a lookup function for each group, with
+/// `None` as the sum of a group that overflows. The new column has `Option`
+/// values only if any group overflows.
+fn sum_integer<R, F>(groups: &[Vec<usize>], get: F) -> Column<usize>
+where
+    R: Integer + ColumnType,
+    Option<R>: ColumnType,
+    F: Fn(usize) -> Option<R>,
+{
+    let index = Index::from_iter(0..groups.len());
+
+    let sums =
+        groups
+        .iter()
+        .map(|ps| ps.iter().filter_map(|&p| get(p)).try_fold(R::zero(), R::checked_add))
+        .collect::<Vec<_>>()
+    ;
+
+    if sums.iter().all(Option::is_some) {
+        R::into_column(Series::from_values(index, sums.into_iter().flatten().collect()).unwrap())
+    } else {
+        Option::<R>::into_column(Series::from_values(index, sums).unwrap())
+    }
+}
+
+/// Helper macro to dispatch a numeric aggregation on each numeric `Column`
+/// variant.
+macro_rules! aggregate_numeric_column {
+    ( $column:expr, $groups:expr, $agg:expr; $( ($name:ident $( , $cfg_flag:meta )?), )+ ) => {
+        paste::item! {
+            match $column {
+                $(
+                    $(#[$cfg_flag])?
+                    Column::$name(series) => aggregate_numeric($groups, $agg, |p| Some(series.values()[p])),
+                    $(#[$cfg_flag])?
+                    Column::[<Opt $name>](series) => aggregate_numeric($groups, $agg, |p| series.values()[p]),
+                )+
+                _ => None,
+            }
+        }
+    };
+}
+
+/// Helper macro to dispatch an integer sum on each integer `Column` variant.
+macro_rules! sum_integer_column {
+    ( $column:expr, $groups:expr; $( ($name:ident $( , $cfg_flag:meta )?), )+ ) => {
+        paste::item! {
+            match $column {
+                $(
+                    $(#[$cfg_flag])?
+                    Column::$name(series) => Some(sum_integer($groups, |p| Some(series.values()[p]))),
+                    $(#[$cfg_flag])?
+                    Column::[<Opt $name>](series) => Some(sum_integer($groups, |p| series.values()[p])),
+                )+
+                _ => None,
+            }
+        }
+    };
+}
+
+/// Applies an aggregation to the values of a column within each group, and
+/// produces a new column with one value per group. Returns `None` if the
+/// aggregation is not supported for the type of the column.
+pub(super) fn aggregate<L: Label>(column: &Column<L>, groups: &[Vec<usize>], agg: Agg, name: &str) -> Option<Column<usize>> {
+    let index = Index::from_iter(0..groups.len());
+
+    let mut aggregated = match agg {
+        Agg::Count => {
+            let values =
+                groups
+                .iter()
+                .map(|ps| ps.iter().filter(|&&p| !column.datum(p).unwrap().is_none()).count())
+                .collect()
+            ;
+
+            usize::into_column(Series::from_values(index, values).unwrap())
+        },
+        Agg::First | Agg::Last => {
+            let positions =
+                groups
+                .iter()
+                .map(|ps| if agg == Agg::First { ps[0] } else { ps[ps.len() - 1] })
+                .collect::<Vec<_>>()
+            ;
+
+            column.take(&positions).unwrap().relabeled(index, name)
+        },
+        Agg::Sum if column.dtype().is_integer() => sum_integer_column!(
+            column, groups;
+            (I8), (I16), (I32), (I64), (ISize), (I128, cfg(feature = "128")),
+            (U8), (U16), (U32), (U64), (USize), (U128, cfg(feature = "128")),
+        )?,
+        Agg::Sum | Agg::Mean | Agg::Min | Agg::Max => aggregate_numeric_column!(
+            column, groups, agg;
+            (I8), (I16), (I32), (I64), (ISize), (I128, cfg(feature = "128")),
+            (U8), (U16), (U32), (U64), (USize), (U128, cfg(feature = "128")),
+            (F32), (F64),
+        )?,
+    };
+
+    aggregated.set_name(name);
+
+    Some(aggregated)
+}
+
+/// A grouping of the rows of a `Frame` by the values of one or more key
+/// columns, created by `Frame::group_by`. Groups are ordered by the first
+/// occurrence of their key, and rows within each group keep their original
+/// order. Missing values in key columns are keys like any other.
+///
+/// Aggregated results are `Frame`s with one row per group, labeled by group
+/// number, that start with the key columns.
+pub struct GroupBy<'a, L: Label> {
+    frame: &'a Frame<L>,
+    keys: Vec<String>,
+    groups: Vec<Vec<usize>>,
+}
+
+impl<'a, L> GroupBy<'a, L>
+where
+    L: Label,
+{
+    /// The caller must ensure that all key columns exist.
+    fn new(frame: &'a Frame<L>, keys: Vec<String>) -> Self {
+        let key_columns = keys.iter().map(|k| &frame.1[k]).collect::<Vec<_>>();
+
+        let mut codes = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+
+        for pos in 0..frame.num_rows() {
+            let key = RowKey(key_columns.iter().map(|c| c.datum(pos).unwrap()).collect());
+
+            let code = *codes.entry(key).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+
+            groups[code].push(pos);
+        }
+
+        Self { frame, keys, groups }
+    }
+
+    /// Returns the names of the key columns of this `GroupBy`.
+    pub fn key_names(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Returns the number of groups.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if there are no groups.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns the positions in the original `Frame` that belong to each
+    /// group, in group order.
+    pub fn positions(&self) -> &[Vec<usize>] {
+        &self.groups
+    }
+
+    /// Returns the key columns with one value per group.
+    fn key_columns(&self) -> IndexMap<String, Column<usize>> {
+        self.keys
+            .iter()
+            .map(|k| (k.clone(), aggregate(&self.frame.1[k], &self.groups, Agg::First, k).unwrap()))
+            .collect()
+    }
+
+    /// Returns a `Frame` with the values of the key columns for each group.
+    pub fn keys(&self) -> Frame<usize> {
+        Frame::new_inner(Index::from_iter(0..self.len()), self.key_columns())
+    }
+
+    /// Returns an iterator that yields the values of the key columns of each
+    /// group along with its sub-`Frame`, in group order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec<Datum<'a>>, Frame<L>)> + '_ {
+        let frame = self.frame;
+
+        self.groups.iter().map(move |positions| {
+            let key = self.keys.iter().map(|k| frame.1[k].datum(positions[0]).unwrap()).collect();
+            (key, frame.select_positions(positions).unwrap())
+        })
+    }
+
+    /// Applies each aggregation to its column within each group. The result
+    /// contains the key columns, followed by one column per aggregation named
+    /// `"<column>_<aggregation>"`, e.g. `"price_sum"`. If a column is not
+    /// found or does not support its aggregation, or if the result would have
+    /// duplicate column names, an `InvalidAgg` error is returned.
+    pub fn agg<I, S>(&self, aggs: I) -> Result<Frame<usize>, InvalidAgg>
+    where
+        I: IntoIterator<Item = (S, Agg)>,
+        S: AsRef<str>,
+    {
+        let mut columns = self.key_columns();
+
+        for (name, agg) in aggs {
+            let name = name.as_ref();
+
+            let column = self.frame.1.get(name).ok_or_else(|| MissingColumn { name: name.to_string() })?;
+            let agg_name = format!("{}_{}", name, agg.name());
+
+            let aggregated = aggregate(column, &self.groups, agg, &agg_name).ok_or_else(|| {
+                UnsupportedAgg { name: name.to_string(), agg, dtype_name: column.dtype_name() }
+            })?;
+
+            if columns.contains_key(&agg_name) {
+                return Err(DuplicateColumnName { name: agg_name }.into());
+            }
+
+            columns.insert(agg_name, aggregated);
+        }
+
+        Ok(Frame::new_inner(Index::from_iter(0..self.len()), columns))
+    }
+
+    /// Applies an aggregation to every non-key column that supports it,
+    /// keeping the column names.
+    fn agg_all(&self, agg: Agg) -> Frame<usize> {
+        let mut columns = self.key_columns();
+
+        for (name, column) in &self.frame.1 {
+            if self.keys.contains(name) { continue; }
+
+            if let Some(aggregated) = aggregate(column, &self.groups, agg, name) {
+                columns.insert(name.clone(), aggregated);
+            }
+        }
+
+        Frame::new_inner(Index::from_iter(0..self.len()), columns)
+    }
+
+    /// Counts the number of non-`None` values of each non-key column in each
+    /// group.
+    pub fn count(&self) -> Frame<usize> {
+        self.agg_all(Agg::Count)
+    }
+
+    /// Computes the arithmetic mean of each numeric non-key column in each
+    /// group. Non-numeric columns are left out.
+    pub fn mean(&self) -> Frame<usize> {
+        self.agg_all(Agg::Mean)
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Groups the rows of this `Frame` by the values of the given key columns.
+    /// If a key column is not found, a `MissingColumn` error is returned.
+    pub fn group_by(&self, keys: &[&str]) -> Result<GroupBy<'_, L>, MissingColumn> {
+        if let Some(name) = keys.iter().find(|k| !self.contains_column(k)) {
+            return Err(MissingColumn { name: name.to_string() });
+        }
+
+        Ok(GroupBy::new(self, keys.iter().map(|k| k.to_string()).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::fixtures;
+
+    #[test]
+    fn group_by() {
+        let frame = fixtures::city_sales();
+
+        let g = frame.group_by(&["city"]).unwrap();
+        assert_eq!(g.len(), 3);
+        assert_eq!(g.key_names(), &[String::from("city")]);
+        assert_eq!(g.positions(), &[vec![0, 2, 5], vec![1, 4], vec![3]]);
+
+        let keys = g.keys();
+        assert_eq!(keys.index(), &Index::from_iter(0..3));
+        assert_eq!(keys.column_as::<String>("city").unwrap().values(), &["A", "B", "C"]);
+
+        let g = frame.group_by(&["city", "year"]).unwrap();
+        assert_eq!(g.positions(), &[vec![0, 5], vec![1, 4], vec![2], vec![3]]);
+
+        let (key, sub) = g.iter().nth(1).unwrap();
+        assert_eq!(key, vec![Datum::Str(&String::from("B")), Datum::I32(&1)]);
+        assert_eq!(sub.index(), &Index::from_iter(vec![1, 4]));
+        assert_eq!(sub.column_as::<i32>("sales").unwrap().values(), &[20, 50]);
+
+        assert_eq!(frame.group_by(&["town"]).err().unwrap().name, "town");
+        assert_eq!(frame.group_by(&[]).unwrap().len(), 1);
+        assert!(Frame::<usize>::new().group_by(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn agg() {
+        let frame = fixtures::city_sales();
+        let g = frame.group_by(&["city"]).unwrap();
+
+        let result = g.agg(vec![
+            ("sales", Agg::Sum),
+            ("sales", Agg::Mean),
+            ("price", Agg::Min),
+            ("price", Agg::Max),
+            ("price", Agg::Count),
+            ("flag", Agg::Last),
+        ]).unwrap();
+
+        assert_eq!(
+            result.column_names().collect::<Vec<_>>(),
+            vec!["city", "sales_sum", "sales_mean", "price_min", "price_max", "price_count", "flag_last"],
+        );
+        assert_eq!(result.column_as::<i32>("sales_sum").unwrap().values(), &[100, 70, 40]);
+        assert_eq!(result.column_as::<Option<f64>>("sales_mean").unwrap().values(), &[Some(100.0 / 3.0), Some(35.0), Some(40.0)]);
+        assert_eq!(result.column_as::<Option<f64>>("price_min").unwrap().values(), &[Some(1.0), Some(4.0), None]);
+        assert_eq!(result.column_as::<Option<f64>>("price_max").unwrap().values(), &[Some(3.0), Some(4.0), None]);
+        assert_eq!(result.column_as::<usize>("price_count").unwrap().values(), &[3, 1, 1]);
+        assert_eq!(result.column_as::<bool>("flag_last").unwrap().values(), &[false, false, true]);
+        assert_eq!(result.column_as::<bool>("flag_last").unwrap().name(), Some("flag_last"));
+
+        match g.agg(vec![("flag", Agg::Sum)]).unwrap_err() {
+            InvalidAgg::UnsupportedAgg(err) => {
+                assert_eq!(err.name, "flag");
+                assert_eq!(err.agg, Agg::Sum);
+                assert_eq!(err.dtype_name, "Bool");
+            },
+            _ => panic!("expected an unsupported aggregation"),
+        }
+
+        match g.agg(vec![("town", Agg::First)]).unwrap_err() {
+            InvalidAgg::MissingColumn(err) => assert_eq!(err.name, "town"),
+            _ => panic!("expected a missing column"),
+        }
+
+        match g.agg(vec![("sales", Agg::Sum), ("sales", Agg::Sum)]).unwrap_err() {
+            InvalidAgg::DuplicateColumnName(err) => assert_eq!(err.name, "sales_sum"),
+            _ => panic!("expected a duplicate column name"),
+        }
+    }
+
+    #[test]
+    fn count_mean() {
+        let frame = fixtures::city_sales();
+        let g = frame.group_by(&["city"]).unwrap();
+
+        let counts = g.count();
+        assert_eq!(counts.column_names().collect::<Vec<_>>(), vec!["city", "year", "sales", "price", "flag"]);
+        assert_eq!(counts.column_as::<usize>("sales").unwrap().values(), &[3, 2, 1]);
+        assert_eq!(counts.column_as::<usize>("price").unwrap().values(), &[3, 1, 1]);
+
+        let means = g.mean();
+        assert_eq!(means.column_names().collect::<Vec<_>>(), vec!["city", "year", "sales", "price"]);
+        assert_eq!(means.column_as::<Option<f64>>("year").unwrap().values(), &[Some(4.0 / 3.0), Some(1.0), Some(1.0)]);
+        assert_eq!(means.column_as::<Option<f64>>("price").unwrap().values(), &[Some(2.0), Some(4.0), None]);
+    }
+
+    #[test]
+    fn sum_overflow() {
+        let index = Index::from_iter(0..4);
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("key", Column::from(Series::from_values(index.clone(), vec!['a', 'a', 'b', 'b']).unwrap())),
+            ("small", Column::from(Series::from_values(index.clone(), vec![100i8, 27, 100, 28]).unwrap())),
+            ("total", Column::from(Series::from_values(index, vec![Some(100i8), None, Some(-100), Some(-28)]).unwrap())),
+        ]).unwrap();
+
+        let result = frame.group_by(&["key"]).unwrap().agg(vec![("small", Agg::Sum), ("total", Agg::Sum)]).unwrap();
+
+        assert_eq!(result.column_as::<Option<i8>>("small_sum").unwrap().values(), &[Some(127), None]);
+        assert_eq!(result.column_as::<i8>("total_sum").unwrap().values(), &[100, -128]);
+    }
+
+    #[test]
+    fn float_keys() {
+        let index = Index::from_iter(0..5);
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("key", Column::from(Series::from_values(index.clone(), vec![0.0, -0.0, f64::NAN, -f64::NAN, 1.0]).unwrap())),
+            ("value", Column::from(Series::from_values(index, vec![1, 2, 3, 4, 5]).unwrap())),
+        ]).unwrap();
+
+        let g = frame.group_by(&["key"]).unwrap();
+        assert_eq!(g.positions(), &[vec![0, 1], vec![2, 3], vec![4]]);
+    }
+}
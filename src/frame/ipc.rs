@@ -0,0 +1,195 @@
+//! Reading and writing of `Frame`s in the Arrow IPC formats, through their
+//! conversion to and from Arrow record batches.
+
+use std::fs::File;
+use std::io::Read;
+use std::io::Seek;
+use std::io::Write;
+use std::path::Path;
+
+use arrow_array::RecordBatch;
+use arrow_array::RecordBatchReader;
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::reader::StreamReader;
+use arrow_ipc::writer::FileWriter;
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::ArrowError;
+
+use crate::traits::Label;
+
+use super::Frame;
+use super::InvalidIpc;
+use super::MismatchPolicy;
+
+/// The layout of a `Frame` as Arrow IPC data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpcFormat {
+    /// The random-access file format, also known as Feather (version 2).
+    File,
+    /// The streaming format, which has no footer and is read sequentially.
+    Stream,
+}
+
+/// Reads all record batches into a new `Frame`, with rows labeled `0..n`.
+fn read_batches<R>(reader: R) -> Result<Frame<usize>, InvalidIpc>
+where
+    R: RecordBatchReader,
+{
+    let schema = reader.schema();
+
+    let mut frames = Vec::new();
+    let mut offset = 0;
+
+    for batch in reader {
+        let batch = batch?;
+        frames.push(Frame::from_record_batch_at(&batch, offset)?);
+        offset += batch.num_rows();
+    }
+
+    if frames.is_empty() {
+        return Ok(Frame::from_record_batch(&RecordBatch::new_empty(schema))?);
+    }
+
+    Ok(Frame::concat_rows(frames, MismatchPolicy::Error).unwrap())
+}
+
+impl Frame<usize> {
+    /// Reads Arrow IPC data in the given format into a new `Frame`, with one
+    /// row per record, labeled `0..n`, and one column per field. Column types
+    /// are converted as in `Frame::from_record_batch`. The file format needs
+    /// to seek to its footer; use `Frame::read_ipc_stream` to read the stream
+    /// format from a reader that cannot seek.
+    pub fn read_ipc<R>(reader: R, format: IpcFormat) -> Result<Self, InvalidIpc>
+    where
+        R: Read + Seek,
+    {
+        match format {
+            IpcFormat::File => read_batches(FileReader::try_new(reader, None)?),
+            IpcFormat::Stream => Self::read_ipc_stream(reader),
+        }
+    }
+
+    /// Reads Arrow IPC data in the stream format into a new `Frame`, as in
+    /// `Frame::read_ipc`, from a reader that does not need to seek.
+    pub fn read_ipc_stream<R>(reader: R) -> Result<Self, InvalidIpc>
+    where
+        R: Read,
+    {
+        read_batches(StreamReader::try_new(reader, None)?)
+    }
+
+    /// Reads the Arrow IPC file at the given path into a new `Frame`.
+    pub fn read_ipc_path<P>(path: P, format: IpcFormat) -> Result<Self, InvalidIpc>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).map_err(ArrowError::from)?;
+        Self::read_ipc(file, format)
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Writes this `Frame` as Arrow IPC data in the given format, with one
+    /// field per column. The row labels are not written. Column types are
+    /// converted as in `Frame::to_record_batch`.
+    pub fn write_ipc<W>(&self, writer: W, format: IpcFormat) -> Result<(), ArrowError>
+    where
+        W: Write,
+    {
+        let batch = self.to_record_batch();
+
+        match format {
+            IpcFormat::File => {
+                let mut writer = FileWriter::try_new(writer, &batch.schema())?;
+                writer.write(&batch)?;
+                writer.finish()
+            },
+            IpcFormat::Stream => {
+                let mut writer = StreamWriter::try_new(writer, &batch.schema())?;
+                writer.write(&batch)?;
+                writer.finish()
+            },
+        }
+    }
+
+    /// Writes this `Frame` as Arrow IPC data at the given path, creating or
+    /// truncating it.
+    pub fn write_ipc_path<P>(&self, path: P, format: IpcFormat) -> Result<(), ArrowError>
+    where
+        P: AsRef<Path>,
+    {
+        self.write_ipc(File::create(path)?, format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+    use crate::testing::fixtures;
+
+    #[test]
+    fn round_trip() {
+        let frame = fixtures::id_score_flag();
+
+        for format in [IpcFormat::File, IpcFormat::Stream] {
+            let mut bytes = Vec::new();
+            frame.write_ipc(&mut bytes, format).unwrap();
+
+            let read = Frame::read_ipc(Cursor::new(bytes), format).unwrap();
+
+            assert_eq!(read.index(), &Index::from_iter(0..3));
+            assert_eq!(read.schema(), frame.schema());
+            assert_eq!(read.column_as::<u32>("id").unwrap().values(), &[1, 2, 3]);
+            assert_eq!(read.column_as::<Option<f32>>("score").unwrap().values(), &[Some(0.5), None, Some(1.5)]);
+            assert_eq!(read.column_as::<bool>("flag").unwrap().values(), &[true, false, true]);
+        }
+    }
+
+    #[test]
+    fn read_stream() {
+        let frame = fixtures::id_score_flag();
+
+        let mut bytes = Vec::new();
+        frame.write_ipc(&mut bytes, IpcFormat::Stream).unwrap();
+
+        // A byte slice can be read, but not seeked.
+        let read = Frame::read_ipc_stream(&bytes[..]).unwrap();
+
+        assert_eq!(read.index(), &Index::from_iter(0..3));
+        assert_eq!(read.schema(), frame.schema());
+        assert_eq!(read.column_as::<u32>("id").unwrap().values(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn round_trip_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("round_trip.feather");
+        let frame = fixtures::id_score_flag().iloc_range(0..0).unwrap();
+
+        frame.write_ipc_path(&path, IpcFormat::File).unwrap();
+        let read = Frame::read_ipc_path(&path, IpcFormat::File).unwrap();
+
+        assert_eq!(read.shape(), (0, 3));
+        assert_eq!(read.schema(), frame.schema());
+    }
+
+    #[test]
+    fn read_invalid() {
+        let mut bytes = Vec::new();
+        fixtures::id_score_flag().write_ipc(&mut bytes, IpcFormat::Stream).unwrap();
+
+        let err = Frame::read_ipc(Cursor::new(bytes), IpcFormat::File).unwrap_err();
+        assert!(matches!(err, InvalidIpc::Arrow(_)));
+
+        let err = Frame::read_ipc_path("/nonexistent/rustable.feather", IpcFormat::File).unwrap_err();
+        assert!(matches!(err, InvalidIpc::Arrow(_)));
+    }
+}
@@ -0,0 +1,101 @@
+use std::ops::Range;
+
+use crate::traits::Label;
+use crate::types::Datum;
+
+use super::Frame;
+use super::Row;
+
+/// An iterator over the rows of a `Frame`, as `Row` views.
+pub struct Rows<'a, L: Label> {
+    frame: &'a Frame<L>,
+    positions: Range<usize>,
+}
+
+impl<'a, L> Rows<'a, L>
+where
+    L: Label,
+{
+    pub(crate) fn new(frame: &'a Frame<L>) -> Self {
+        Self { frame, positions: 0..frame.num_rows() }
+    }
+}
+
+impl<'a, L> Iterator for Rows<'a, L>
+where
+    L: Label,
+{
+    type Item = Row<'a, L>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions.next().map(|pos| Row::new(self.frame, pos))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<'a, L> DoubleEndedIterator for Rows<'a, L>
+where
+    L: Label,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.positions.next_back().map(|pos| Row::new(self.frame, pos))
+    }
+}
+
+impl<'a, L> ExactSizeIterator for Rows<'a, L>
+where
+    L: Label,
+{
+    fn len(&self) -> usize {
+        self.positions.len()
+    }
+}
+
+/// An iterator over the rows of a `Frame`, as pairs of a label and the values
+/// of that row in column order.
+pub struct IterRows<'a, L: Label>(Rows<'a, L>);
+
+impl<'a, L> IterRows<'a, L>
+where
+    L: Label,
+{
+    pub(crate) fn new(frame: &'a Frame<L>) -> Self {
+        Self(Rows::new(frame))
+    }
+}
+
+impl<'a, L> Iterator for IterRows<'a, L>
+where
+    L: Label,
+{
+    type Item = (&'a L, Vec<Datum<'a>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|row| (row.label(), row.to_vec()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, L> DoubleEndedIterator for IterRows<'a, L>
+where
+    L: Label,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|row| (row.label(), row.to_vec()))
+    }
+}
+
+impl<'a, L> ExactSizeIterator for IterRows<'a, L>
+where
+    L: Label,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
@@ -0,0 +1,312 @@
+//! Joining of the rows of two `Frame`s on their row labels or on key columns.
+
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+use indexmap::IndexMap;
+
+use crate::index::Index;
+use crate::index::Join;
+use crate::traits::Label;
+use crate::types::Column;
+
+use super::group::RowKey;
+use super::DuplicateColumnName;
+use super::Frame;
+use super::InvalidJoin;
+use super::KeyTypeMismatch;
+use super::MissingColumn;
+
+/// Combines the joined key, left and right columns in that order, adding the
+/// matching suffix to left and right column names contained in both. Each
+/// column is renamed to its final column name.
+fn combine_columns<K: Label>(
+    keys: Vec<(String, Column<K>)>,
+    left: Vec<(String, Column<K>)>,
+    right: Vec<(String, Column<K>)>,
+    suffixes: (&str, &str),
+) -> Result<IndexMap<String, Column<K>>, DuplicateColumnName>
+{
+    let clashes = |name: &String, others: &[(String, Column<K>)]| others.iter().any(|(n, _)| n == name);
+
+    let left_names =
+        left
+        .iter()
+        .map(|(n, _)| if clashes(n, &right) { format!("{}{}", n, suffixes.0) } else { n.clone() })
+        .collect::<Vec<_>>()
+    ;
+    let right_names =
+        right
+        .iter()
+        .map(|(n, _)| if clashes(n, &left) { format!("{}{}", n, suffixes.1) } else { n.clone() })
+        .collect::<Vec<_>>()
+    ;
+
+    let named =
+        keys
+        .into_iter()
+        .chain(left_names.into_iter().zip(left.into_iter().map(|(_, c)| c)))
+        .chain(right_names.into_iter().zip(right.into_iter().map(|(_, c)| c)))
+    ;
+
+    let mut columns = IndexMap::new();
+
+    for (name, mut column) in named {
+        if columns.contains_key(&name) {
+            return Err(DuplicateColumnName { name });
+        }
+
+        column.set_name(&name);
+        columns.insert(name, column);
+    }
+
+    Ok(columns)
+}
+
+/// Returns the key of the row at the given position in the key columns.
+fn row_key<'a, L: Label>(key_columns: &[&'a Column<L>], pos: usize) -> RowKey<'a> {
+    RowKey(key_columns.iter().map(|c| c.datum(pos).unwrap()).collect())
+}
+
+/// Returns the key columns of a `Frame`, along with the positions of the rows
+/// with each key.
+fn key_positions<'a, L: Label>(frame: &'a Frame<L>, on: &[&str]) -> (Vec<&'a Column<L>>, HashMap<RowKey<'a>, Vec<usize>>) {
+    let key_columns = on.iter().map(|&k| &frame.1[k]).collect::<Vec<_>>();
+
+    let mut positions: HashMap<_, Vec<usize>> = HashMap::new();
+    for pos in 0..frame.num_rows() {
+        positions.entry(row_key(&key_columns, pos)).or_default().push(pos);
+    }
+
+    (key_columns, positions)
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Joins the columns of two `Frame`s on their row labels, which are
+    /// aligned as in `Index::align`. Columns of a side that may be missing
+    /// labels in the result (e.g. the right side of a left join) get `Option`
+    /// values. Column names contained in both `Frame`s get the left or right
+    /// suffix added. If the result would still have duplicate column names, a
+    /// `DuplicateColumnName` error is returned.
+    pub fn join_index(&self, other: &Self, how: Join, suffixes: (&str, &str)) -> Result<Self, DuplicateColumnName> {
+        let (index, left_pos, right_pos) = self.0.align(&other.0, how);
+
+        let left_opt = how == Join::Right || how == Join::Outer;
+        let right_opt = how == Join::Left || how == Join::Outer;
+
+        let left =
+            self.1
+            .iter()
+            .map(|(n, c)| (n.clone(), c.gather(&left_pos, index.clone(), left_opt)))
+            .collect()
+        ;
+        let right =
+            other.1
+            .iter()
+            .map(|(n, c)| (n.clone(), c.gather(&right_pos, index.clone(), right_opt)))
+            .collect()
+        ;
+
+        let columns = combine_columns(Vec::new(), left, right, suffixes)?;
+
+        Ok(Self::new_inner(index, columns))
+    }
+
+    /// Joins the rows of two `Frame`s that have equal values in the given key
+    /// columns, matching keys by hashing. Every matching pair of rows produces
+    /// a row in the result, which is labeled by position. Left rows come first
+    /// in their original order, except for right joins, which follow the right
+    /// order; outer joins end with the unmatched right rows.
+    ///
+    /// The result starts with the key columns, followed by the other left and
+    /// right columns. Columns of a side that may be missing rows in the result
+    /// get `Option` values, and column names contained in both `Frame`s get the
+    /// left or right suffix added. Key columns may differ in whether they
+    /// have `Option` values, in which case the result key column does. If a
+    /// key column is not found in either `Frame`, has different base types in
+    /// each, or if the result would have duplicate column names, an
+    /// `InvalidJoin` error is returned.
+    pub fn join(&self, other: &Self, on: &[&str], how: Join, suffixes: (&str, &str)) -> Result<Frame<usize>, InvalidJoin> {
+        for &name in on {
+            let missing = || MissingColumn { name: name.to_string() };
+            let (left, right) = (self.column(name).ok_or_else(missing)?, other.column(name).ok_or_else(missing)?);
+
+            if left.dtype() != right.dtype() {
+                let (left, right) = (left.dtype_name(), right.dtype_name());
+                return Err(KeyTypeMismatch { name: name.to_string(), left, right }.into());
+            }
+        }
+
+        let (left_keys, left_map) = key_positions(self, on);
+        let (right_keys, right_map) = key_positions(other, on);
+
+        let mut pairs = Vec::new();
+
+        if how == Join::Right {
+            for r in 0..other.num_rows() {
+                let found = left_map.get(&row_key(&right_keys, r)).map_or(&[][..], Vec::as_slice);
+
+                if found.is_empty() { pairs.push((None, Some(r))); }
+                pairs.extend(found.iter().map(|&l| (Some(l), Some(r))));
+            }
+        } else {
+            let mut matched = vec![false; other.num_rows()];
+
+            for l in 0..self.num_rows() {
+                let found = right_map.get(&row_key(&left_keys, l)).map_or(&[][..], Vec::as_slice);
+
+                if found.is_empty() && how != Join::Inner { pairs.push((Some(l), None)); }
+                for &r in found { matched[r] = true; }
+                pairs.extend(found.iter().map(|&r| (Some(l), Some(r))));
+            }
+
+            if how == Join::Outer {
+                pairs.extend((0..other.num_rows()).filter(|&r| !matched[r]).map(|r| (None, Some(r))));
+            }
+        }
+
+        let index = Index::from_iter(0..pairs.len());
+        let left_pos = pairs.iter().map(|&(l, _)| l).collect::<Vec<_>>();
+        let right_pos = pairs.iter().map(|&(_, r)| r).collect::<Vec<_>>();
+
+        let left_opt = how == Join::Right || how == Join::Outer;
+        let right_opt = how == Join::Left || how == Join::Outer;
+
+        let keys =
+            on
+            .iter()
+            .map(|&k| (k.to_string(), self.1[k].coalesce_gather(&other.1[k], &pairs, index.clone()).unwrap()))
+            .collect()
+        ;
+        let left =
+            self.1
+            .iter()
+            .filter(|(n, _)| !on.contains(&n.as_str()))
+            .map(|(n, c)| (n.clone(), c.gather(&left_pos, index.clone(), left_opt)))
+            .collect()
+        ;
+        let right =
+            other.1
+            .iter()
+            .filter(|(n, _)| !on.contains(&n.as_str()))
+            .map(|(n, c)| (n.clone(), c.gather(&right_pos, index.clone(), right_opt)))
+            .collect()
+        ;
+
+        let columns = combine_columns(keys, left, right, suffixes)?;
+
+        Ok(Frame::new_inner(index, columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::series::Series;
+    use crate::testing::fixtures;
+
+    #[test]
+    fn join() {
+        let (left, right) = (fixtures::people(), fixtures::teams());
+
+        let inner = left.join(&right, &["id"], Join::Inner, ("_l", "_r")).unwrap();
+        assert_eq!(inner.column_names().collect::<Vec<_>>(), vec!["id", "score_l", "score_r"]);
+        assert_eq!(inner.index(), &Index::from_iter(0..3));
+        assert_eq!(inner.column_as::<i32>("id").unwrap().values(), &[1, 2, 2]);
+        assert_eq!(inner.column_as::<i32>("score_l").unwrap().values(), &[10, 20, 40]);
+        assert_eq!(inner.column_as::<i32>("score_l").unwrap().name(), Some("score_l"));
+        assert_eq!(inner.column_as::<Option<f64>>("score_r").unwrap().values(), &[Some(1.5), Some(0.5), Some(0.5)]);
+
+        let left_join = left.join(&right, &["id"], Join::Left, ("_l", "_r")).unwrap();
+        assert_eq!(left_join.column_as::<i32>("id").unwrap().values(), &[1, 2, 3, 2]);
+        assert_eq!(left_join.column_as::<i32>("score_l").unwrap().values(), &[10, 20, 30, 40]);
+        assert_eq!(
+            left_join.column_as::<Option<f64>>("score_r").unwrap().values(),
+            &[Some(1.5), Some(0.5), None, Some(0.5)],
+        );
+
+        let right_join = left.join(&right, &["id"], Join::Right, ("_l", "_r")).unwrap();
+        assert_eq!(right_join.column_as::<i32>("id").unwrap().values(), &[2, 2, 4, 1]);
+        assert_eq!(right_join.column_as::<Option<i32>>("score_l").unwrap().values(), &[Some(20), Some(40), None, Some(10)]);
+        assert_eq!(
+            right_join.column_as::<Option<f64>>("score_r").unwrap().values(),
+            &[Some(0.5), Some(0.5), None, Some(1.5)],
+        );
+
+        let outer = left.join(&right, &["id"], Join::Outer, ("_l", "_r")).unwrap();
+        assert_eq!(outer.column_as::<i32>("id").unwrap().values(), &[1, 2, 3, 2, 4]);
+        assert_eq!(
+            outer.column_as::<Option<i32>>("score_l").unwrap().values(),
+            &[Some(10), Some(20), Some(30), Some(40), None],
+        );
+    }
+
+    #[test]
+    fn join_optional_keys() {
+        let (left, mut right) = (fixtures::people(), fixtures::teams());
+        let index = right.index().clone();
+        right.insert_column("id", Series::from_values(index, vec![Some(2), None, Some(1)]).unwrap()).unwrap();
+
+        let inner = left.join(&right, &["id"], Join::Inner, ("_l", "_r")).unwrap();
+        assert_eq!(inner.column_as::<Option<i32>>("id").unwrap().values(), &[Some(1), Some(2), Some(2)]);
+        assert_eq!(inner.column_as::<i32>("score_l").unwrap().values(), &[10, 20, 40]);
+
+        let right_join = left.join(&right, &["id"], Join::Right, ("_l", "_r")).unwrap();
+        assert_eq!(right_join.column_as::<Option<i32>>("id").unwrap().values(), &[Some(2), Some(2), None, Some(1)]);
+        assert_eq!(right_join.column_as::<Option<i32>>("score_l").unwrap().values(), &[Some(20), Some(40), None, Some(10)]);
+    }
+
+    #[test]
+    fn join_errors() {
+        let (left, right) = (fixtures::people(), fixtures::teams());
+
+        match left.join(&right, &["team"], Join::Inner, ("_l", "_r")).unwrap_err() {
+            InvalidJoin::MissingColumn(err) => assert_eq!(err.name, "team"),
+            _ => panic!("expected a missing column"),
+        }
+
+        match left.join(&right, &["score"], Join::Inner, ("_l", "_r")).unwrap_err() {
+            InvalidJoin::KeyTypeMismatch(err) => {
+                assert_eq!(err.name, "score");
+                assert_eq!((err.left.as_str(), err.right.as_str()), ("I32", "Option<F64>"));
+            },
+            _ => panic!("expected a key type mismatch"),
+        }
+
+        match left.join(&right, &["id"], Join::Inner, ("", "")).unwrap_err() {
+            InvalidJoin::DuplicateColumnName(err) => assert_eq!(err.name, "score"),
+            _ => panic!("expected a duplicate column name"),
+        }
+    }
+
+    #[test]
+    fn join_index() {
+        let (left, right) = (fixtures::people(), fixtures::teams());
+        let right = Frame::from_columns(
+            Index::from_iter("cxa".chars()),
+            right.1.into_iter().collect::<Vec<_>>(),
+        ).unwrap();
+
+        let joined = left.join_index(&right, Join::Left, ("_l", "_r")).unwrap();
+        assert_eq!(joined.index(), &Index::from_iter("abcd".chars()));
+        assert_eq!(joined.column_names().collect::<Vec<_>>(), vec!["id_l", "score_l", "id_r", "score_r"]);
+        assert_eq!(joined.column_as::<i32>("score_l").unwrap().values(), &[10, 20, 30, 40]);
+        assert_eq!(joined.column_as::<Option<i32>>("id_r").unwrap().values(), &[Some(1), None, Some(2), None]);
+        assert_eq!(
+            joined.column_as::<Option<f64>>("score_r").unwrap().values(),
+            &[Some(1.5), None, Some(0.5), None],
+        );
+
+        let joined = left.join_index(&right, Join::Inner, ("_l", "_r")).unwrap();
+        assert_eq!(joined.index(), &Index::from_iter("ac".chars()));
+        assert_eq!(joined.column_as::<i32>("id_r").unwrap().values(), &[1, 2]);
+
+        let joined = left.join_index(&right, Join::Outer, ("_l", "_r")).unwrap();
+        assert_eq!(joined.index(), &Index::from_iter("abcdx".chars()));
+        assert_eq!(joined.column_as::<Option<i32>>("id_l").unwrap().values()[4], None);
+    }
+}
@@ -0,0 +1,324 @@
+//! Reading and writing of `Frame`s as JSON and newline-delimited JSON data.
+
+use std::io::Read;
+use std::io::Write;
+use std::iter::FromIterator;
+
+use indexmap::IndexSet;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::index::Index;
+use crate::traits::ColumnType;
+use crate::traits::Label;
+use crate::types::Column;
+use crate::types::Datum;
+
+use super::Frame;
+use super::InvalidJson;
+use super::LengthMismatch;
+use super::UnexpectedJson;
+
+/// The layout of a `Frame` as JSON data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonOrient {
+    /// An array with one object per row, mapping column names to values.
+    Records,
+    /// One object per row as with `Records`, each on its own line (NDJSON).
+    Lines,
+    /// An object mapping column names to arrays of values.
+    Columns,
+}
+
+/// Converts a value to JSON. Numbers, booleans, and strings map onto their
+/// JSON equivalents, missing values and non-finite floats onto `null`, and
+/// all other values onto their display strings.
+fn to_json(datum: Datum<'_>) -> Value {
+    macro_rules! to_json_variants {
+        ( $( $name:ident, $opt_name:ident; )* ) => {
+            match datum {
+                $( Datum::$name(v) | Datum::$opt_name(Some(v)) => Value::from(v.clone()), )*
+                datum if datum.is_none() => Value::Null,
+                datum => Value::String(datum.to_string()),
+            }
+        };
+    }
+
+    to_json_variants!(
+        I8, OptI8; I16, OptI16; I32, OptI32; I64, OptI64; ISize, OptISize;
+        U8, OptU8; U16, OptU16; U32, OptU32; U64, OptU64; USize, OptUSize;
+        F32, OptF32; F64, OptF64;
+        Bool, OptBool;
+        Str, OptStr;
+    )
+}
+
+/// Converts every present value with the given function, creating a `Column`
+/// with `Option` values if any values are `null`.
+fn convert_column<T, F>(values: &[Value], index: Index<usize>, convert: F) -> Column<usize>
+where
+    T: ColumnType,
+    Option<T>: ColumnType,
+    F: Fn(&Value) -> T,
+{
+    let values = values.iter().map(|v| if v.is_null() { None } else { Some(convert(v)) }).collect();
+    Column::from_options(index, values, false)
+}
+
+/// Creates a `Column` from the JSON values of a column, using the first of
+/// `Bool`, `I64`, `F64`, or `Str` that every non-`null` value converts to.
+/// Values that are not strings are stored as JSON text in `Str` columns.
+fn infer_column(values: &[Value], index: Index<usize>) -> Column<usize> {
+    let present = values.iter().filter(|v| !v.is_null()).collect::<Vec<_>>();
+
+    if !present.is_empty() && present.iter().all(|v| v.is_boolean()) {
+        convert_column(values, index, |v| v.as_bool().unwrap())
+    } else if !present.is_empty() && present.iter().all(|v| v.is_i64()) {
+        convert_column(values, index, |v| v.as_i64().unwrap())
+    } else if !present.is_empty() && present.iter().all(|v| v.is_number()) {
+        convert_column(values, index, |v| v.as_f64().unwrap())
+    } else {
+        convert_column(values, index, |v| v.as_str().map_or_else(|| v.to_string(), String::from))
+    }
+}
+
+/// Creates a `Frame` from one JSON object per row. Keys missing from a row
+/// are read as `null`.
+fn from_rows(rows: Vec<Map<String, Value>>) -> Frame<usize> {
+    let names = rows.iter().flat_map(|row| row.keys()).collect::<IndexSet<_>>();
+    let index = Index::from_iter(0..rows.len());
+
+    let columns =
+        names
+        .iter()
+        .map(|&name| {
+            let values = rows.iter().map(|row| row.get(name).cloned().unwrap_or(Value::Null)).collect::<Vec<_>>();
+            (name.clone(), infer_column(&values, index.clone()))
+        })
+        .collect::<Vec<_>>()
+    ;
+
+    Frame::from_columns(index, columns).unwrap()
+}
+
+/// Returns the JSON object in the given value, or an `UnexpectedJson` error.
+fn expect_object(value: Value) -> Result<Map<String, Value>, UnexpectedJson> {
+    match value {
+        Value::Object(map) => Ok(map),
+        _ => Err(UnexpectedJson { expected: String::from("an object") }),
+    }
+}
+
+impl Frame<usize> {
+    /// Reads JSON data in the given layout into a new `Frame` with one row per
+    /// record, labeled `0..n`. The type of each column is inferred from its
+    /// values, and columns with `null` or missing values have `Option` values.
+    /// If the data is not valid JSON, a `Json` error is returned, and if it
+    /// does not have the given layout, an `UnexpectedJson` error is returned.
+    /// Arrays in the `Columns` layout must have the same length, or else a
+    /// `LengthMismatch` error is returned.
+    pub fn read_json<R>(reader: R, orient: JsonOrient) -> Result<Self, InvalidJson>
+    where
+        R: Read,
+    {
+        match orient {
+            JsonOrient::Records => {
+                let rows = match serde_json::from_reader(reader)? {
+                    Value::Array(values) => values.into_iter().map(expect_object).collect::<Result<_, _>>()?,
+                    _ => return Err(UnexpectedJson { expected: String::from("an array") }.into()),
+                };
+
+                Ok(from_rows(rows))
+            },
+            JsonOrient::Lines => {
+                let rows =
+                    serde_json::Deserializer::from_reader(reader)
+                    .into_iter::<Value>()
+                    .map(|value| Ok(expect_object(value?)?))
+                    .collect::<Result<_, InvalidJson>>()?
+                ;
+
+                Ok(from_rows(rows))
+            },
+            JsonOrient::Columns => {
+                let map = expect_object(serde_json::from_reader(reader)?)?;
+
+                let mut arrays = Vec::with_capacity(map.len());
+
+                for (name, value) in map {
+                    match value {
+                        Value::Array(values) => arrays.push((name, values)),
+                        _ => return Err(UnexpectedJson { expected: String::from("an array") }.into()),
+                    }
+                }
+
+                let len = arrays.first().map_or(0, |(_, values)| values.len());
+
+                if let Some((name, values)) = arrays.iter().find(|(_, values)| values.len() != len) {
+                    return Err(LengthMismatch { name: name.clone(), expected: len, found: values.len() }.into());
+                }
+
+                let index = Index::from_iter(0..len);
+
+                let columns =
+                    arrays
+                    .into_iter()
+                    .map(|(name, values)| (name, infer_column(&values, index.clone())))
+                    .collect::<Vec<_>>()
+                ;
+
+                Ok(Self::from_columns(index, columns).unwrap())
+            },
+        }
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Returns one JSON object per row, mapping column names to values.
+    fn json_rows(&self) -> impl Iterator<Item = Value> + '_ {
+        self.rows().map(|row| {
+            let map = row.iter().map(|(name, datum)| (name.to_string(), to_json(datum))).collect();
+            Value::Object(map)
+        })
+    }
+
+    /// Writes the columns of this `Frame` as JSON data in the given layout.
+    /// The row labels are not written.
+    pub fn write_json<W>(&self, mut writer: W, orient: JsonOrient) -> Result<(), serde_json::Error>
+    where
+        W: Write,
+    {
+        match orient {
+            JsonOrient::Records => {
+                serde_json::to_writer(writer, &Value::Array(self.json_rows().collect()))
+            },
+            JsonOrient::Lines => {
+                for row in self.json_rows() {
+                    serde_json::to_writer(&mut writer, &row)?;
+                    writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+                }
+
+                Ok(())
+            },
+            JsonOrient::Columns => {
+                let map =
+                    self.1
+                    .iter()
+                    .map(|(name, column)| {
+                        let values = (0..column.len()).map(|p| to_json(column.datum(p).unwrap())).collect();
+                        (name.clone(), Value::Array(values))
+                    })
+                    .collect()
+                ;
+
+                serde_json::to_writer(writer, &Value::Object(map))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::series::Series;
+    use crate::types::DType;
+
+    #[test]
+    fn read_json_records() {
+        let data = r#"[
+            {"name": "ann", "age": 31, "score": 2.5, "tags": [1]},
+            {"name": "bob", "score": 4, "member": true},
+            {"name": null, "age": null, "score": null, "tags": "x"}
+        ]"#;
+        let frame = Frame::read_json(data.as_bytes(), JsonOrient::Records).unwrap();
+
+        assert_eq!(frame.index(), &Index::from_iter(0..3));
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["name", "age", "score", "tags", "member"]);
+        assert_eq!(
+            frame.dtypes().into_iter().map(|(_, dtype)| dtype).collect::<Vec<_>>(),
+            vec![DType::Str, DType::I64, DType::F64, DType::Str, DType::Bool],
+        );
+
+        assert_eq!(frame.column_as::<Option<String>>("name").unwrap().values()[0], Some(String::from("ann")));
+        assert_eq!(frame.column_as::<Option<i64>>("age").unwrap().values(), &[Some(31), None, None]);
+        assert_eq!(frame.column_as::<Option<f64>>("score").unwrap().values(), &[Some(2.5), Some(4.0), None]);
+        assert_eq!(
+            frame.column_as::<Option<String>>("tags").unwrap().values(),
+            &[Some(String::from("[1]")), None, Some(String::from("x"))],
+        );
+        assert_eq!(frame.column_as::<Option<bool>>("member").unwrap().values(), &[None, Some(true), None]);
+    }
+
+    #[test]
+    fn read_json_lines_columns() {
+        let data = "{\"x\": 1, \"y\": \"a\"}\n\n{\"x\": 2, \"y\": \"b\"}\n";
+        let lines = Frame::read_json(data.as_bytes(), JsonOrient::Lines).unwrap();
+
+        assert_eq!(lines.column_as::<i64>("x").unwrap().values(), &[1, 2]);
+        assert_eq!(lines.column_as::<String>("y").unwrap().values(), &[String::from("a"), String::from("b")]);
+
+        let data = r#"{"x": [1, 2], "y": ["a", "b"]}"#;
+        let columns = Frame::read_json(data.as_bytes(), JsonOrient::Columns).unwrap();
+
+        assert_eq!(columns.schema(), lines.schema());
+        assert_eq!(columns.column_as::<i64>("x").unwrap().values(), &[1, 2]);
+
+        let empty = Frame::read_json("[]".as_bytes(), JsonOrient::Records).unwrap();
+        assert_eq!(empty.shape(), (0, 0));
+    }
+
+    #[test]
+    fn read_json_errors() {
+        let err = Frame::read_json("[1, 2]".as_bytes(), JsonOrient::Records).unwrap_err();
+        assert!(matches!(err, InvalidJson::UnexpectedJson(_)));
+
+        let err = Frame::read_json("{\"x\": 1}".as_bytes(), JsonOrient::Records).unwrap_err();
+        assert!(matches!(err, InvalidJson::UnexpectedJson(_)));
+
+        let err = Frame::read_json("{\"x\": 1}\n{".as_bytes(), JsonOrient::Lines).unwrap_err();
+        assert!(matches!(err, InvalidJson::Json(_)));
+
+        let err = Frame::read_json(r#"{"x": [1, 2], "y": [3]}"#.as_bytes(), JsonOrient::Columns).unwrap_err();
+        assert!(matches!(err, InvalidJson::LengthMismatch(LengthMismatch { ref name, expected: 2, found: 1 }) if name == "y"));
+    }
+
+    #[test]
+    fn write_json() {
+        let data = r#"[{"name": "ann", "age": 31, "score": 2.5}, {"name": "bob", "age": null, "score": 4.0}]"#;
+        let frame = Frame::read_json(data.as_bytes(), JsonOrient::Records).unwrap();
+
+        let mut out = Vec::new();
+        frame.write_json(&mut out, JsonOrient::Records).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"[{"name":"ann","age":31,"score":2.5},{"name":"bob","age":null,"score":4.0}]"#,
+        );
+
+        let mut out = Vec::new();
+        frame.write_json(&mut out, JsonOrient::Lines).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"name\":\"ann\",\"age\":31,\"score\":2.5}\n{\"name\":\"bob\",\"age\":null,\"score\":4.0}\n",
+        );
+
+        let mut out = Vec::new();
+        frame.write_json(&mut out, JsonOrient::Columns).unwrap();
+        assert_eq!(String::from_utf8(out.clone()).unwrap(), r#"{"name":["ann","bob"],"age":[31,null],"score":[2.5,4.0]}"#);
+
+        let round_trip = Frame::read_json(&out[..], JsonOrient::Columns).unwrap();
+        assert_eq!(round_trip.schema(), frame.schema());
+
+        let chars = Frame::from_columns(Index::from_iter(0..2), vec![
+            ("c", Column::from(Series::from_values(Index::from_iter(0..2), vec!['x', 'y']).unwrap())),
+            ("f", Column::from(Series::from_values(Index::from_iter(0..2), vec![f64::NAN, 1.0]).unwrap())),
+        ]).unwrap();
+
+        let mut out = Vec::new();
+        chars.write_json(&mut out, JsonOrient::Columns).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), r#"{"c":["x","y"],"f":[null,1.0]}"#);
+    }
+}
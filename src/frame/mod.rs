@@ -0,0 +1,1084 @@
+//! Two-dimensional tables of labeled, heterogeneously-typed columns.
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod concat;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod display;
+pub mod error;
+#[cfg(feature = "excel")]
+pub mod excel;
+pub mod group;
+#[cfg(feature = "ipc")]
+pub mod ipc;
+pub mod iter;
+pub mod join;
+#[cfg(feature = "serde_json")]
+pub mod json;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+pub mod pivot;
+pub mod record;
+pub mod reindex;
+pub mod row;
+pub mod schema;
+pub mod sort;
+#[cfg(feature = "sql")]
+pub mod sql;
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::RangeBounds;
+
+use indexmap::IndexMap;
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use crate::index::Index;
+use crate::series::Keep;
+use crate::series::MisalignedIndex;
+use crate::series::Series;
+use crate::traits::ColumnType;
+use crate::traits::Label;
+use crate::traits::Storable;
+use crate::types::Column;
+
+use self::group::RowKey;
+
+pub use self::concat::MismatchPolicy;
+pub use self::error::DTypeMismatch;
+pub use self::error::DuplicateColumnName;
+pub use self::error::DuplicateEntry;
+pub use self::error::InvalidAgg;
+pub use self::error::InvalidColumns;
+pub use self::error::InvalidConcat;
+pub use self::error::InvalidJoin;
+pub use self::error::InvalidPivot;
+pub use self::error::InvalidRecord;
+pub use self::error::KeyTypeMismatch;
+pub use self::error::LengthMismatch;
+pub use self::error::MissingColumn;
+pub use self::error::UnsupportedAgg;
+pub use self::group::Agg;
+pub use self::group::GroupBy;
+pub use self::iter::IterRows;
+pub use self::iter::Rows;
+pub use self::record::Record;
+pub use self::reindex::ReindexFill;
+pub use self::row::Row;
+pub use self::schema::Field;
+pub use self::schema::Schema;
+pub use self::sort::SortOrder;
+
+#[cfg(feature = "arrow")]
+pub use self::error::UnsupportedArrowType;
+#[cfg(feature = "csv")]
+pub use self::csv::ReadCsvOptions;
+#[cfg(feature = "csv")]
+pub use self::csv::WriteCsvOptions;
+#[cfg(feature = "serde_json")]
+pub use self::error::InvalidJson;
+#[cfg(feature = "serde_json")]
+pub use self::error::UnexpectedJson;
+#[cfg(feature = "serde_json")]
+pub use self::json::JsonOrient;
+#[cfg(feature = "csv")]
+pub use self::error::InvalidCsv;
+#[cfg(feature = "excel")]
+pub use self::error::InvalidExcel;
+#[cfg(feature = "excel")]
+pub use self::excel::ReadExcelOptions;
+#[cfg(feature = "ipc")]
+pub use self::error::InvalidIpc;
+#[cfg(feature = "ipc")]
+pub use self::ipc::IpcFormat;
+#[cfg(feature = "parquet")]
+pub use self::error::InvalidParquet;
+#[cfg(feature = "parquet")]
+pub use self::parquet::ParquetBatches;
+#[cfg(feature = "parquet")]
+pub use self::parquet::ParquetCompression;
+#[cfg(feature = "parquet")]
+pub use self::parquet::ReadParquetOptions;
+#[cfg(feature = "parquet")]
+pub use self::parquet::WriteParquetOptions;
+#[cfg(feature = "sql")]
+pub use self::error::InvalidSql;
+#[cfg(feature = "sql")]
+pub use self::error::UnsupportedSqlType;
+
+#[cfg(feature = "derive")]
+pub use rustable_derive::Record;
+
+/// A table of named columns of possibly differing types, which all share a
+/// single `Index` of row labels.
+#[derive(Debug, Clone)]
+pub struct Frame<L: Label>(
+    pub(crate) Index<L>,
+    pub(crate) IndexMap<String, Column<L>>,
+);
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Creates a new, empty `Frame` with no rows and no columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `Frame` with the given row `Index` and no columns.
+    pub fn with_index(index: Index<L>) -> Self {
+        Self::new_inner(index, IndexMap::new())
+    }
+
+    /// Creates a new `Frame` from a row `Index` and an iterable of named
+    /// columns, in order. The values of each column are assigned to the rows
+    /// by position, and each column is renamed to its column name. If a column
+    /// has a different length than the `Index`, a `LengthMismatch` error is
+    /// returned, and if a column name is repeated, a `DuplicateColumnName`
+    /// error is returned.
+    pub fn from_columns<I, S>(index: Index<L>, columns: I) -> Result<Self, InvalidColumns>
+    where
+        I: IntoIterator<Item = (S, Column<L>)>,
+        S: Into<String>,
+    {
+        let columns = columns.into_iter();
+        let mut map = IndexMap::with_capacity(columns.size_hint().0);
+
+        for (name, column) in columns {
+            let name = name.into();
+
+            if column.len() != index.len() {
+                let (expected, found) = (index.len(), column.len());
+                return Err(LengthMismatch { name, expected, found }.into());
+            }
+
+            if map.contains_key(&name) {
+                return Err(DuplicateColumnName { name }.into());
+            }
+
+            let column = column.relabeled(index.clone(), &name);
+            map.insert(name, column);
+        }
+
+        Ok(Self::new_inner(index, map))
+    }
+
+    /// Asserts that every column shares the row `Index` of this `Frame`.
+    fn assert_aligned(&self) {
+        assert!(self.1.values().all(|column| column.index() == &self.0));
+    }
+
+    fn new_inner(index: Index<L>, columns: IndexMap<String, Column<L>>) -> Self {
+        let new = Self(index, columns);
+        new.assert_aligned();
+
+        // Each column is given a clone of the row `Index`, so that they all
+        // share its labels instead of holding equal copies.
+        let Self(index, columns) = new;
+        let columns =
+            columns
+            .into_iter()
+            .map(|(name, column)| {
+                let column = column.relabeled(index.clone(), &name);
+                (name, column)
+            })
+            .collect()
+        ;
+
+        Self(index, columns)
+    }
+
+    /// Returns a read-only reference to the row `Index` of this `Frame`.
+    pub fn index(&self) -> &Index<L> {
+        &self.0
+    }
+
+    /// Returns the number of rows in this `Frame`.
+    pub fn num_rows(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the number of columns in this `Frame`.
+    pub fn num_cols(&self) -> usize {
+        self.1.len()
+    }
+
+    /// Returns the number of rows and columns in this `Frame`, in that order.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.num_rows(), self.num_cols())
+    }
+
+    /// Returns `true` if this `Frame` contains no rows or no columns.
+    pub fn is_empty(&self) -> bool {
+        self.num_rows() == 0 || self.num_cols() == 0
+    }
+
+    /// Returns an estimate of the number of bytes used to store the values of
+    /// each column of this `Frame`, in order, excluding the row labels. If
+    /// `deep` is `true`, the heap contents of the values (e.g. of `String`s)
+    /// are also included.
+    pub fn memory_usage(&self, deep: bool) -> Vec<(String, usize)> {
+        self.1.iter().map(|(name, column)| (name.clone(), column.values_memory_usage(deep))).collect()
+    }
+
+    /// Returns an estimate of the total number of bytes used to store this
+    /// `Frame`, including its row `Index`, which is counted once since it is
+    /// shared by all columns. If `deep` is `true`, the heap contents of the
+    /// labels and values are also included.
+    pub fn total_memory_usage(&self, deep: bool) -> usize {
+        self.0.memory_usage(deep) + self.memory_usage(deep).into_iter().map(|(_, usage)| usage).sum::<usize>()
+    }
+
+    /// Returns an iterator over the names of the columns of this `Frame`,
+    /// in order.
+    pub fn column_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.1.keys().map(String::as_str)
+    }
+
+    /// Returns `true` if this `Frame` contains a column with the given name.
+    pub fn contains_column(&self, name: &str) -> bool {
+        self.1.contains_key(name)
+    }
+
+    /// Returns a read-only reference to the column with the given name, if it
+    /// exists.
+    pub fn column(&self, name: &str) -> Option<&Column<L>> {
+        self.1.get(name)
+    }
+
+    /// Returns a read-only reference to the column at the given position, if
+    /// it exists.
+    pub fn column_at(&self, col_pos: usize) -> Option<&Column<L>> {
+        self.1.get_index(col_pos).map(|(_, column)| column)
+    }
+
+    /// Returns a read-only reference to the column with the given name as a
+    /// `Series`, if it exists and has values of type `V`.
+    pub fn column_as<V>(&self, name: &str) -> Option<&Series<L, V>>
+    where
+        V: ColumnType,
+    {
+        self.column(name).and_then(V::from_column)
+    }
+
+    /// Returns a mutable slice of the values of the column with the given
+    /// name, if it exists and has values of type `V`. Only the values are
+    /// exposed, so that the column keeps sharing the row `Index`.
+    pub fn column_as_mut<V>(&mut self, name: &str) -> Option<&mut [V]>
+    where
+        V: ColumnType,
+    {
+        self.1.get_mut(name).and_then(V::from_column_mut).map(|series| series.values_mut())
+    }
+
+    /// Inserts a `Series` as a column with the given name, reordering its
+    /// values to match the row `Index`. If a column with that name already
+    /// exists, it is replaced in-place and returned. If the `Series` does not
+    /// have the same set of labels as the row `Index`, a `MisalignedIndex`
+    /// error is returned and this `Frame` is unchanged. A `Frame` with no rows
+    /// and no columns takes on the `Index` of the `Series`.
+    pub fn insert_column<S, V>(&mut self, name: S, series: Series<L, V>) -> Result<Option<Column<L>>, MisalignedIndex<L>>
+    where
+        S: Into<String>,
+        V: ColumnType,
+    {
+        if self.num_rows() == 0 && self.num_cols() == 0 {
+            self.0 = series.index().clone();
+        }
+
+        let missing = self.0.difference(series.index()).cloned().collect::<Vec<_>>();
+        let extra = series.index().difference(&self.0).cloned().collect::<Vec<_>>();
+
+        if !missing.is_empty() || !extra.is_empty() {
+            return Err(MisalignedIndex { missing, extra });
+        }
+
+        // Only reorder if the labels are not already in row order.
+        let series =
+            if series.index() == &self.0 { series }
+            else { series.loc_multi(self.0.iter()).unwrap() }
+        ;
+
+        let name = name.into();
+        let column = V::into_column(series).relabeled(self.0.clone(), &name);
+
+        Ok(self.1.insert(name, column))
+    }
+
+    /// Removes the column with the given name, and returns it, or `None` if
+    /// the name is not found. The order of the remaining columns is preserved.
+    pub fn drop_column(&mut self, name: &str) -> Option<Column<L>> {
+        self.1.shift_remove(name)
+    }
+
+    /// Renames the column with the given name, keeping its position. Returns
+    /// `false` if the name is not found. If another column already has the new
+    /// name, a `DuplicateColumnName` error is returned and this `Frame` is
+    /// unchanged.
+    pub fn rename_column<S>(&mut self, old: &str, new: S) -> Result<bool, DuplicateColumnName>
+    where
+        S: Into<String>,
+    {
+        let new = new.into();
+
+        if !self.contains_column(old) { return Ok(false); }
+        if old != new && self.contains_column(&new) {
+            return Err(DuplicateColumnName { name: new });
+        }
+
+        self.1 =
+            std::mem::take(&mut self.1)
+            .into_iter()
+            .map(|(name, mut column)| {
+                if name == old {
+                    column.set_name(&new);
+                    (new.clone(), column)
+                } else {
+                    (name, column)
+                }
+            })
+            .collect()
+        ;
+
+        Ok(true)
+    }
+
+    /// Given a position, returns a view of its row in the `Frame`, if it
+    /// exists.
+    pub fn iloc(&self, pos: usize) -> Option<Row<'_, L>> {
+        if pos < self.num_rows() { Some(Row::new(self, pos)) } else { None }
+    }
+
+    /// Given a label, returns a view of its row in the `Frame`, if it exists.
+    pub fn loc<Q>(&self, label: &Q) -> Option<Row<'_, L>>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.0.index_of(label).map(|pos| Row::new(self, pos))
+    }
+
+    /// Returns a new `Frame` containing the rows at the given positions, in
+    /// that order, with all columns. Returns `None` if any position is out of
+    /// bounds or repeated, since labels in an `Index` must be unique.
+    fn select_positions(&self, positions: &[usize]) -> Option<Self> {
+        let mut seen = HashSet::with_capacity(positions.len());
+
+        if !positions.iter().all(|&p| p < self.num_rows() && seen.insert(p)) { return None; }
+
+        let index = Index::from_iter(positions.iter().map(|&p| self.0.iloc(p).unwrap().clone()));
+        let positions = positions.iter().copied().map(Some).collect::<Vec<_>>();
+
+        let columns =
+            self.1
+            .iter()
+            .map(|(name, column)| (name.clone(), column.gather(&positions, index.clone(), false)))
+            .collect()
+        ;
+
+        Some(Self::new_inner(index, columns))
+    }
+
+    /// Returns a new `Frame` containing the rows at the given positions, in
+    /// that order. Returns `None` if any position is out of bounds or
+    /// repeated.
+    pub fn iloc_multi<'a, I>(&self, pos_iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        let positions = pos_iter.into_iter().copied().collect::<Vec<_>>();
+        self.select_positions(&positions)
+    }
+
+    /// Returns a new `Frame` containing the rows within the given range of
+    /// positions, or `None` if the range is out of bounds.
+    pub fn iloc_range<R>(&self, range: R) -> Option<Self>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, close) = self.0.range_to_nodules(range)?;
+        let positions = (start..close.max(start)).collect::<Vec<_>>();
+
+        self.select_positions(&positions)
+    }
+
+    /// Returns a new `Frame` containing the rows with the given labels, in
+    /// that order. Returns `None` if any label is missing or repeated.
+    pub fn loc_multi<'a, I, Q>(&self, labels: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Q>,
+        L: Borrow<Q>,
+        Q: 'a + Hash + Eq + ?Sized,
+    {
+        let positions =
+            labels
+            .into_iter()
+            .map(|lbl| self.0.index_of(lbl))
+            .collect::<Option<Vec<_>>>()?
+        ;
+
+        self.select_positions(&positions)
+    }
+
+    /// Returns a new `Frame` containing the rows within the given range of
+    /// labels, or `None` if an endpoint of the range is not contained in the
+    /// `Index`.
+    pub fn loc_range<'a, R, Q>(&self, range: R) -> Option<Self>
+    where
+        R: RangeBounds<&'a Q>,
+        L: Borrow<Q>,
+        Q: 'a + Hash + Eq + ?Sized,
+    {
+        self.iloc_range(self.0.label_range_to_pos_range(range)?)
+    }
+
+    /// Randomly selects `n` rows from this `Frame`, returning them as a new
+    /// `Frame` with rows labeled `0..n` in the order they were selected. If
+    /// `replace` is `true`, the same row may be selected more than once. The
+    /// label each row had in this `Frame` is kept in a leading `label` column.
+    /// Returns `None` if this `Frame` already has a `label` column, if `n` is
+    /// greater than the number of rows without replacement, or if `n` is
+    /// non-zero and this `Frame` has no rows with replacement.
+    #[cfg(feature = "rand")]
+    pub fn sample<R>(&self, n: usize, replace: bool, rng: &mut R) -> Option<Frame<usize>>
+    where
+        L: ColumnType,
+        R: Rng + ?Sized,
+    {
+        if self.1.contains_key("label") { return None; }
+
+        let positions = self.0.sample_positions(n, replace, rng)?;
+        let index = Index::from_iter(0..n);
+
+        let labels = positions.iter().map(|&p| self.0.iloc(p).unwrap().clone()).collect();
+        let positions = positions.into_iter().map(Some).collect::<Vec<_>>();
+
+        let mut columns = IndexMap::with_capacity(self.1.len() + 1);
+        columns.insert(String::from("label"), L::into_column(Series::from_values(index.clone(), labels).unwrap()));
+        columns.extend(
+            self.1
+            .iter()
+            .map(|(name, column)| (name.clone(), column.gather(&positions, index.clone(), false)))
+        );
+
+        Some(Frame::new_inner(index, columns))
+    }
+
+    /// Randomly selects a fraction of the rows from this `Frame` without
+    /// replacement, returning them as a new `Frame`. See `Index::sample_frac`
+    /// for details.
+    #[cfg(feature = "rand")]
+    pub fn sample_frac<R>(&self, frac: f64, rng: &mut R) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+    {
+        let positions = self.0.sample_positions(self.0.frac_len(frac)?, false, rng)?;
+
+        self.select_positions(&positions)
+    }
+
+    /// Returns a new `Frame` containing only the rows for which the predicate
+    /// returns `true`, in their original order. The predicate accepts a view
+    /// of each row.
+    pub fn filter<F>(&self, mut pred: F) -> Self
+    where
+        F: FnMut(&Row<'_, L>) -> bool,
+    {
+        let positions =
+            self.rows()
+            .filter(|row| pred(row))
+            .map(|row| row.position())
+            .collect::<Vec<_>>()
+        ;
+
+        self.select_positions(&positions).unwrap()
+    }
+
+    /// Returns a new `Frame` containing only the rows whose label is flagged
+    /// `true` in the aligned boolean `Series`, in their original order. If the
+    /// `Series` does not have the same set of labels as the row `Index`, a
+    /// `MisalignedIndex` error is returned.
+    pub fn filter_mask(&self, mask: &Series<L, bool>) -> Result<Self, MisalignedIndex<L>> {
+        let missing = self.0.difference(mask.index()).cloned().collect::<Vec<_>>();
+        let extra = mask.index().difference(&self.0).cloned().collect::<Vec<_>>();
+
+        if !missing.is_empty() || !extra.is_empty() {
+            return Err(MisalignedIndex { missing, extra });
+        }
+
+        let positions =
+            self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| *mask.loc(*l).unwrap())
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>()
+        ;
+
+        Ok(self.select_positions(&positions).unwrap())
+    }
+
+    /// Returns a boolean `Series` with the same `Index`, marking which rows are
+    /// duplicates of other rows according to the `Keep` mode. Rows are compared
+    /// by the values of the given key columns, or of all columns if `None`.
+    /// If a key column is not found, a `MissingColumn` error is returned.
+    pub fn duplicated(&self, keys: Option<&[&str]>, keep: Keep) -> Result<Series<L, bool>, MissingColumn> {
+        let key_columns = match keys {
+            Some(keys) => {
+                keys.iter()
+                    .map(|&k| self.column(k).ok_or_else(|| MissingColumn { name: k.to_string() }))
+                    .collect::<Result<Vec<_>, _>>()?
+            },
+            None => self.1.values().collect(),
+        };
+
+        let row_keys =
+            (0..self.num_rows())
+            .map(|pos| RowKey(key_columns.iter().map(|c| c.datum(pos).unwrap()).collect()))
+            .collect::<Vec<_>>()
+        ;
+
+        let mut counts = HashMap::<&RowKey<'_>, usize>::with_capacity(row_keys.len());
+        for key in &row_keys { *counts.entry(key).or_insert(0) += 1; }
+
+        let mut seen = HashMap::<&RowKey<'_>, usize>::with_capacity(counts.len());
+
+        let flags =
+            row_keys
+            .iter()
+            .map(|key| {
+                let count = counts[key];
+                let nth = seen.entry(key).or_insert(0);
+                *nth += 1;
+
+                match keep {
+                    Keep::First => *nth > 1,
+                    Keep::Last => *nth < count,
+                    Keep::All => count > 1,
+                }
+            })
+            .collect()
+        ;
+
+        Ok(Series::from_values(self.0.clone(), flags).unwrap())
+    }
+
+    /// Returns a new `Frame` without the rows that are duplicates of other
+    /// rows according to the `Keep` mode, in their original order. See
+    /// `duplicated` for details.
+    pub fn drop_duplicates(&self, keys: Option<&[&str]>, keep: Keep) -> Result<Self, MissingColumn> {
+        let flags = self.duplicated(keys, keep)?;
+        let positions = flags.values().iter().enumerate().filter(|(_, &d)| !d).map(|(p, _)| p).collect::<Vec<_>>();
+
+        Ok(self.select_positions(&positions).unwrap())
+    }
+
+    /// Returns a new `Frame` with each column replaced by the result of the
+    /// given function, keeping the column names and row `Index`. The values of
+    /// each new column are assigned to the rows by position. If a new column
+    /// has a different length than the `Index`, a `LengthMismatch` error is
+    /// returned.
+    pub fn apply_columns<F>(&self, mut func: F) -> Result<Self, LengthMismatch>
+    where
+        F: FnMut(&Column<L>) -> Column<L>,
+    {
+        let mut map = IndexMap::with_capacity(self.num_cols());
+
+        for (name, column) in &self.1 {
+            let column = func(column);
+
+            if column.len() != self.num_rows() {
+                let (expected, found) = (self.num_rows(), column.len());
+                return Err(LengthMismatch { name: name.clone(), expected, found });
+            }
+
+            map.insert(name.clone(), column.relabeled(self.0.clone(), name));
+        }
+
+        Ok(Self::new_inner(self.0.clone(), map))
+    }
+
+    /// Applies a function to a view of each row in this `Frame`, and produces
+    /// a new `Series` of the results that shares the row `Index`.
+    pub fn apply_rows<F, V>(&self, mut func: F) -> Series<L, V>
+    where
+        F: FnMut(&Row<'_, L>) -> V,
+        V: Storable,
+    {
+        let values = self.rows().map(|row| func(&row)).collect();
+
+        Series::from_values(self.0.clone(), values).unwrap()
+    }
+
+    /// Returns an iterator over the rows of this `Frame` in order, as `Row`
+    /// views with per-column access.
+    pub fn rows(&self) -> Rows<'_, L> {
+        Rows::new(self)
+    }
+
+    /// Returns an iterator over the rows of this `Frame` in order, as pairs of
+    /// a label and the values of that row in column order.
+    pub fn iter_rows(&self) -> IterRows<'_, L> {
+        IterRows::new(self)
+    }
+}
+
+impl<L> Default for Frame<L>
+where
+    L: Label,
+{
+    fn default() -> Self {
+        Self::with_index(Index::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::series::Series;
+    use crate::types::DType;
+    use crate::types::Datum;
+
+    #[test]
+    fn frame() {
+        let empty = Frame::<char>::new();
+        assert_eq!(empty.shape(), (0, 0));
+        assert!(empty.is_empty());
+
+        let index = Index::from_iter("abc".chars());
+        let frame = Frame::with_index(index.clone());
+        assert_eq!(frame.shape(), (3, 0));
+        assert!(frame.is_empty());
+
+        let ints = Series::from_values(index.clone(), vec![1i32, 2, 3]).unwrap();
+        let strs = Series::from_values(index.clone(), vec![String::from("x"), String::from("y"), String::from("z")]).unwrap();
+
+        let mut columns = IndexMap::new();
+        columns.insert(String::from("ints"), Column::I32(ints));
+        columns.insert(String::from("strs"), Column::Str(strs));
+
+        let frame = Frame::new_inner(index.clone(), columns);
+
+        assert_eq!(frame.index(), &index);
+        assert_eq!(frame.shape(), (3, 2));
+        assert_eq!(frame.num_rows(), 3);
+        assert_eq!(frame.num_cols(), 2);
+        assert!(!frame.is_empty());
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["ints", "strs"]);
+        assert!(frame.contains_column("strs"));
+        assert!(!frame.contains_column("floats"));
+    }
+
+    #[test]
+    fn memory_usage() {
+        let index = Index::from_iter(0u32..3);
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("ints", Column::from(Series::from_values(index.clone(), vec![1u8, 2, 3]).unwrap())),
+            ("strs", Column::from(Series::from_values(index.clone(), vec![Some(String::from("abc")), None, Some(String::from("de"))]).unwrap())),
+        ]).unwrap();
+
+        let strs_usage = 3 * std::mem::size_of::<Option<String>>();
+
+        assert_eq!(frame.memory_usage(false), vec![(String::from("ints"), 3), (String::from("strs"), strs_usage)]);
+        assert_eq!(frame.memory_usage(true), vec![(String::from("ints"), 3), (String::from("strs"), strs_usage + 5)]);
+
+        let index_usage = index.memory_usage(false);
+        assert_eq!(frame.total_memory_usage(false), index_usage + 3 + strs_usage);
+        assert_eq!(frame.total_memory_usage(true), index_usage + 3 + strs_usage + 5);
+
+        assert_eq!(Frame::<u32>::new().memory_usage(true), vec![]);
+    }
+
+    #[test]
+    fn from_columns() {
+        let index = Index::from_iter("abc".chars());
+
+        let ints = Series::from_iter_checked(vec![('x', 1i32), ('y', 2), ('z', 3)]).unwrap();
+        let strs = Series::from_iter_checked(vec![('x', Some(String::from("x"))), ('y', None), ('z', None)]).unwrap();
+
+        let frame = Frame::from_columns(
+            index.clone(),
+            vec![("ints", Column::from(ints.clone())), ("strs", Column::from(strs.clone()))],
+        ).unwrap();
+
+        assert_eq!(frame.shape(), (3, 2));
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["ints", "strs"]);
+
+        let column = frame.1.get("ints").unwrap().as_i32().unwrap();
+        assert_eq!(column.index(), &index);
+        assert_eq!(column.name(), Some("ints"));
+        assert_eq!(column.values(), &[1, 2, 3]);
+
+        // Every column shares the labels of the row `Index`.
+        assert!(frame.1.values().all(|c| std::ptr::eq(c.index().iloc(0).unwrap(), frame.0.iloc(0).unwrap())));
+
+        let err = Frame::from_columns(
+            Index::from_iter("ab".chars()),
+            vec![("ints", Column::from(ints.clone()))],
+        ).unwrap_err();
+
+        match err {
+            InvalidColumns::LengthMismatch(err) => {
+                assert_eq!(err.name, "ints");
+                assert_eq!((err.expected, err.found), (2, 3));
+            },
+            _ => panic!("expected a length mismatch"),
+        }
+
+        let err = Frame::from_columns(
+            index,
+            vec![("x", Column::from(ints.clone())), ("y", Column::from(strs)), ("x", Column::from(ints))],
+        ).unwrap_err();
+
+        match err {
+            InvalidColumns::DuplicateColumnName(err) => assert_eq!(err.name, "x"),
+            _ => panic!("expected a duplicate column name"),
+        }
+    }
+
+    #[test]
+    fn iter_rows() {
+        let frame = Frame::from_columns(
+            Index::from_iter("ab".chars()),
+            vec![
+                ("ints", Column::from(Series::from_iter_checked(vec![('a', 1i32), ('b', 2)]).unwrap())),
+                ("opts", Column::from(Series::from_iter_checked(vec![('a', None), ('b', Some(true))]).unwrap())),
+            ],
+        ).unwrap();
+
+        let rows = frame.iter_rows().collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (&'a', vec![Datum::I32(&1), Datum::OptBool(&None)]),
+                (&'b', vec![Datum::I32(&2), Datum::OptBool(&Some(true))]),
+            ],
+        );
+        assert_eq!(frame.iter_rows().len(), 2);
+        assert_eq!(frame.iter_rows().next_back().unwrap().0, &'b');
+
+        let row = frame.rows().nth(1).unwrap();
+        assert_eq!(row.label(), &'b');
+        assert_eq!(row.position(), 1);
+        assert_eq!(row.len(), 2);
+        assert_eq!(row.get("ints"), Some(Datum::I32(&2)));
+        assert_eq!(row.get("floats"), None);
+        assert_eq!(row.get_at(1), Some(Datum::OptBool(&Some(true))));
+        assert_eq!(row.get_at(2), None);
+        assert_eq!(
+            row.iter().collect::<Vec<_>>(),
+            vec![("ints", Datum::I32(&2)), ("opts", Datum::OptBool(&Some(true)))],
+        );
+
+        let empty = Frame::with_index(Index::from_iter("ab".chars()));
+        assert!(empty.rows().all(|row| row.is_empty()));
+    }
+
+    #[test]
+    fn selection() {
+        let frame = Frame::from_columns(
+            Index::from_iter("abcd".chars()),
+            vec![
+                ("ints", Column::from(Series::from_iter_checked(vec![('a', 1i32), ('b', 2), ('c', 3), ('d', 4)]).unwrap())),
+                ("chars", Column::from(Series::from_iter_checked(vec![('a', 'w'), ('b', 'x'), ('c', 'y'), ('d', 'z')]).unwrap())),
+            ],
+        ).unwrap();
+
+        let row = frame.iloc(2).unwrap();
+        assert_eq!(row.label(), &'c');
+        assert_eq!(row.to_vec(), vec![Datum::I32(&3), Datum::Char(&'y')]);
+        assert!(frame.iloc(4).is_none());
+
+        let row = frame.loc(&'d').unwrap();
+        assert_eq!(row.position(), 3);
+        assert_eq!(row.get("chars"), Some(Datum::Char(&'z')));
+        assert!(frame.loc(&'e').is_none());
+
+        let sub = frame.iloc_range(1..3).unwrap();
+        assert_eq!(sub.index(), &Index::from_iter("bc".chars()));
+        assert_eq!(sub.column_names().collect::<Vec<_>>(), vec!["ints", "chars"]);
+        assert_eq!(sub.1["ints"].as_i32().unwrap().values(), &[2, 3]);
+        assert_eq!(sub.1["chars"].index(), &Index::from_iter("bc".chars()));
+        assert!(frame.iloc_range(2..5).is_none());
+        assert_eq!(frame.iloc_range(4..).unwrap().shape(), (0, 2));
+
+        let sub = frame.loc_multi(&['d', 'a']).unwrap();
+        assert_eq!(sub.index(), &Index::from_iter("da".chars()));
+        assert_eq!(sub.1["chars"].as_char().unwrap().values(), &['z', 'w']);
+        assert!(frame.loc_multi(&['a', 'a']).is_none());
+        assert!(frame.loc_multi(&['a', 'e']).is_none());
+
+        let sub = frame.iloc_multi(&[3, 1]).unwrap();
+        assert_eq!(sub.index(), &Index::from_iter("db".chars()));
+        assert!(frame.iloc_multi(&[4]).is_none());
+
+        let sub = frame.loc_range(&'b'..=&'c').unwrap();
+        assert_eq!(sub.1["ints"].as_i32().unwrap().values(), &[2, 3]);
+    }
+
+    #[test]
+    fn filter() {
+        let frame = Frame::from_columns(
+            Index::from_iter("abcd".chars()),
+            vec![
+                ("ints", Column::from(Series::from_iter_checked(vec![('a', 1i32), ('b', 2), ('c', 3), ('d', 4)]).unwrap())),
+                ("chars", Column::from(Series::from_iter_checked(vec![('a', 'w'), ('b', 'x'), ('c', 'y'), ('d', 'z')]).unwrap())),
+            ],
+        ).unwrap();
+
+        let sub = frame.filter(|row| row.get_as::<i32>("ints").is_some_and(|v| v % 2 == 0));
+        assert_eq!(sub.index(), &Index::from_iter("bd".chars()));
+        assert_eq!(sub.1["chars"].as_char().unwrap().values(), &['x', 'z']);
+        assert_eq!(sub.1["chars"].index(), sub.index());
+
+        assert_eq!(frame.filter(|_| false).shape(), (0, 2));
+
+        let mask = Series::from_iter_checked(vec![('d', true), ('c', false), ('b', false), ('a', true)]).unwrap();
+        let sub = frame.filter_mask(&mask).unwrap();
+        assert_eq!(sub.index(), &Index::from_iter("ad".chars()));
+        assert_eq!(sub.1["ints"].as_i32().unwrap().values(), &[1, 4]);
+
+        let mask = Series::from_iter_checked(vec![('a', true), ('b', true), ('e', true)]).unwrap();
+        let err = frame.filter_mask(&mask).unwrap_err();
+        assert_eq!(err.missing, vec!['c', 'd']);
+        assert_eq!(err.extra, vec!['e']);
+    }
+
+    #[test]
+    fn drop_duplicates() {
+        let index = Index::from_iter("abcdef".chars());
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("city", Column::from(Series::from_values(index.clone(), vec!["A", "B", "A", "A", "B", "C"].into_iter().map(String::from).collect()).unwrap())),
+            ("score", Column::from(Series::from_values(index, vec![Some(1.0), None, Some(1.0), Some(2.0), None, Some(f64::NAN)]).unwrap())),
+        ]).unwrap();
+
+        let (dup_index, flags) = frame.duplicated(None, Keep::First).unwrap().into_index_values();
+        assert_eq!(dup_index, Index::from_iter("abcdef".chars()));
+        assert_eq!(flags, vec![false, false, true, false, true, false]);
+
+        let sub = frame.drop_duplicates(None, Keep::Last).unwrap();
+        assert_eq!(sub.index(), &Index::from_iter("cdef".chars()));
+        assert_eq!(sub.1["score"].index(), sub.index());
+
+        let sub = frame.drop_duplicates(Some(&["city"]), Keep::First).unwrap();
+        assert_eq!(sub.index(), &Index::from_iter("abf".chars()));
+
+        let sub = frame.drop_duplicates(Some(&["city"]), Keep::All).unwrap();
+        assert_eq!(sub.index(), &Index::from_iter("f".chars()));
+
+        assert_eq!(frame.drop_duplicates(Some(&[]), Keep::First).unwrap().num_rows(), 1);
+
+        let err = frame.drop_duplicates(Some(&["city", "missing"]), Keep::First).unwrap_err();
+        assert_eq!(err.name, "missing");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let index = Index::from_iter(1..=6);
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("tens", Column::from(Series::from_values(index.clone(), (1..=6).map(|i| i * 10).collect()).unwrap())),
+            ("chars", Column::from(Series::from_values(index, "uvwxyz".chars().collect()).unwrap())),
+        ]).unwrap();
+
+        let consistent = |sub: &Frame<i32>| {
+            sub.rows().all(|row| {
+                let l = *row.label();
+                row.get_as::<i32>("tens") == Some(&(l * 10))
+                && row.get_as::<char>("chars") == "uvwxyz".chars().nth(l as usize - 1).as_ref()
+            })
+        };
+
+        let sampled = |sub: &Frame<usize>| {
+            sub.rows().all(|row| {
+                let l = *row.get_as::<i32>("label").unwrap();
+                row.get_as::<i32>("tens") == Some(&(l * 10))
+                && row.get_as::<char>("chars") == "uvwxyz".chars().nth(l as usize - 1).as_ref()
+            })
+        };
+
+        let sub = frame.sample(4, false, &mut rng).unwrap();
+        assert_eq!(sub.shape(), (4, 3));
+        assert_eq!(sub.column_names().collect::<Vec<_>>(), vec!["label", "tens", "chars"]);
+        assert_eq!(sub.column_as::<i32>("label").unwrap().values().iter().collect::<HashSet<_>>().len(), 4);
+        assert!(sampled(&sub));
+
+        assert!(frame.sample(7, false, &mut rng).is_none());
+
+        let sub = frame.sample(20, true, &mut rng).unwrap();
+        assert_eq!(sub.shape(), (20, 3));
+        assert_eq!(sub.index(), &Index::from_iter(0..20));
+        assert!(sampled(&sub));
+
+        // A sampled `Frame` already has a `label` column to collide with.
+        assert!(sub.sample(5, false, &mut rng).is_none());
+
+        let sub = frame.sample_frac(0.5, &mut rng).unwrap();
+        assert_eq!(sub.num_rows(), 3);
+        assert!(consistent(&sub));
+
+        assert!(frame.sample_frac(-0.5, &mut rng).is_none());
+    }
+
+    #[test]
+    fn apply() {
+        let frame = Frame::from_columns(
+            Index::from_iter("abc".chars()),
+            vec![
+                ("wins", Column::from(Series::from_iter_checked(vec![('a', 3i32), ('b', 0), ('c', 5)]).unwrap())),
+                ("games", Column::from(Series::from_iter_checked(vec![('a', 4i32), ('b', 2), ('c', 5)]).unwrap())),
+            ],
+        ).unwrap();
+
+        let doubled = frame.apply_columns(|column| {
+            let series = column.as_i32().unwrap().clone();
+            Column::from(series.map(|v| v * 2))
+        }).unwrap();
+        assert_eq!(doubled.column_names().collect::<Vec<_>>(), vec!["wins", "games"]);
+        assert_eq!(doubled.column_as::<i32>("wins").unwrap().values(), &[6, 0, 10]);
+        assert_eq!(doubled.column_as::<i32>("games").unwrap().name(), Some("games"));
+
+        let err = frame.apply_columns(|column| column.take(&[0]).unwrap()).unwrap_err();
+        assert_eq!((err.name.as_str(), err.expected, err.found), ("wins", 3, 1));
+
+        let ratios = frame.apply_rows(|row| {
+            let (wins, games) = (row.get_as::<i32>("wins").unwrap(), row.get_as::<i32>("games").unwrap());
+            *wins as f64 / *games as f64
+        });
+        assert_eq!(ratios.index(), frame.index());
+        assert_eq!(ratios.values(), &[0.75, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn typed_columns() {
+        let mut frame = Frame::from_columns(
+            Index::from_iter("ab".chars()),
+            vec![
+                ("ints", Column::from(Series::from_iter_checked(vec![('a', 1i32), ('b', 2)]).unwrap())),
+                ("opts", Column::from(Series::from_iter_checked(vec![('a', None), ('b', Some(0.5f64))]).unwrap())),
+            ],
+        ).unwrap();
+
+        assert_eq!(frame.column("ints").unwrap().dtype(), DType::I32);
+        assert!(frame.column("floats").is_none());
+        assert!(frame.column_at(1).unwrap().is_optional());
+        assert!(frame.column_at(2).is_none());
+
+        assert_eq!(frame.column_as::<i32>("ints").unwrap().values(), &[1, 2]);
+        assert_eq!(frame.column_as::<Option<f64>>("opts").unwrap().values(), &[None, Some(0.5)]);
+        assert!(frame.column_as::<i64>("ints").is_none());
+        assert!(frame.column_as::<f64>("opts").is_none());
+        assert!(frame.column_as::<i32>("floats").is_none());
+
+        frame.column_as_mut::<i32>("ints").unwrap()[1] = 20;
+        frame.column_as_mut::<Option<f64>>("opts").unwrap()[0] = Some(1.5);
+        assert!(frame.column_as_mut::<u8>("ints").is_none());
+
+        assert_eq!(frame.column_as::<i32>("ints").unwrap().values(), &[1, 20]);
+        assert_eq!(frame.iloc(0).unwrap().get_as::<Option<f64>>("opts"), Some(&Some(1.5)));
+        assert_eq!(frame.loc(&'b').unwrap().get_as::<i32>("ints"), Some(&20));
+        assert_eq!(frame.loc(&'b').unwrap().get_as::<bool>("ints"), None);
+    }
+
+    #[test]
+    fn column_mutation() {
+        let mut frame = Frame::new();
+
+        let ints = Series::from_iter_checked(vec![('a', 1i32), ('b', 2), ('c', 3)]).unwrap();
+        assert!(frame.insert_column("ints", ints).unwrap().is_none());
+        assert_eq!(frame.index(), &Index::from_iter("abc".chars()));
+
+        // Values are reordered to match the row `Index`.
+        let chars = Series::from_iter_checked(vec![('c', 'z'), ('a', 'x'), ('b', 'y')]).unwrap();
+        assert!(frame.insert_column("chars", chars).unwrap().is_none());
+
+        let chars = frame.column_as::<char>("chars").unwrap();
+        assert_eq!(chars.index(), &Index::from_iter("abc".chars()));
+        assert_eq!(chars.values(), &['x', 'y', 'z']);
+        assert_eq!(chars.name(), Some("chars"));
+
+        let bad = Series::from_iter_checked(vec![('a', true), ('d', false), ('c', true)]).unwrap();
+        let err = frame.insert_column("bools", bad).unwrap_err();
+        assert_eq!(err.missing, vec!['b']);
+        assert_eq!(err.extra, vec!['d']);
+        assert_eq!(frame.num_cols(), 2);
+
+        let floats = Series::from_iter_checked(vec![('a', 0.5f64), ('b', 1.5), ('c', 2.5)]).unwrap();
+        let old = frame.insert_column("ints", floats).unwrap().unwrap();
+        assert_eq!(old.as_i32().unwrap().values(), &[1, 2, 3]);
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["ints", "chars"]);
+        assert_eq!(frame.column("ints").unwrap().dtype(), DType::F64);
+
+        assert!(frame.rename_column("ints", "floats").unwrap());
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["floats", "chars"]);
+        assert_eq!(frame.column_as::<f64>("floats").unwrap().name(), Some("floats"));
+        assert!(!frame.rename_column("ints", "other").unwrap());
+        assert_eq!(frame.rename_column("chars", "floats").unwrap_err().name, "floats");
+        assert!(frame.rename_column("chars", "chars").unwrap());
+
+        let dropped = frame.drop_column("floats").unwrap();
+        assert_eq!(dropped.as_f64().unwrap().values(), &[0.5, 1.5, 2.5]);
+        assert!(frame.drop_column("floats").is_none());
+        assert_eq!(frame.shape(), (3, 1));
+    }
+
+    #[test]
+    fn column() {
+        let ints = Column::from(Series::from_iter_checked(vec![('a', 1i32), ('b', 2)]).unwrap());
+        let opts = Column::from(Series::from_iter_checked(vec![('a', Some(1.5f64)), ('b', None)]).unwrap());
+
+        assert_eq!(ints.dtype(), DType::I32);
+        assert_eq!(opts.dtype(), DType::F64);
+        assert!(!ints.is_optional());
+        assert!(opts.is_optional());
+        assert_eq!(ints.dtype_name(), "I32");
+        assert_eq!(opts.dtype_name(), "Option<F64>");
+
+        assert_eq!(ints.len(), 2);
+        assert!(!opts.is_empty());
+        assert_eq!(ints.index(), &Index::from_iter("ab".chars()));
+
+        assert_eq!(ints.datum(1), Some(Datum::I32(&2)));
+        assert_eq!(opts.datum(0), Some(Datum::OptF64(&Some(1.5))));
+        assert_eq!(opts.datum(1), Some(Datum::OptF64(&None)));
+        assert_eq!(ints.datum(2), None);
+
+        assert_eq!(ints.as_i32().unwrap().values(), &[1, 2]);
+        assert!(ints.as_f64().is_none());
+        assert!(ints.as_opt_i32().is_none());
+        assert_eq!(opts.as_opt_f64().unwrap().values(), &[Some(1.5), None]);
+        assert!(opts.as_f64().is_none());
+
+        let strs = Column::<char>::from(Series::<char, String>::new());
+        assert!(strs.is_empty());
+        assert!(strs.as_str().is_some());
+        assert_eq!(strs.datum(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn misaligned() {
+        let ints = Series::from_iter_checked(vec![('a', 1i32), ('b', 2)]).unwrap();
+
+        let mut columns = IndexMap::new();
+        columns.insert(String::from("ints"), Column::I32(ints));
+
+        Frame::new_inner(Index::from_iter("ba".chars()), columns);
+    }
+}
@@ -0,0 +1,366 @@
+//! Reading and writing of `Frame`s as Parquet files, through their conversion
+//! to and from Arrow record batches.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use arrow_array::RecordBatch;
+use arrow_array::RecordBatchReader;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReader;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::ProjectionMask;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::ChunkReader;
+
+use crate::traits::Label;
+
+use super::Frame;
+use super::InvalidParquet;
+use super::MismatchPolicy;
+use super::MissingColumn;
+
+/// Options for reading a Parquet file into a `Frame`.
+#[derive(Debug, Clone, Default)]
+pub struct ReadParquetOptions {
+    columns: Option<Vec<String>>,
+    batch_size: Option<usize>,
+    row_groups: Option<Vec<usize>>,
+}
+
+impl ReadParquetOptions {
+    /// Creates a new `ReadParquetOptions` that reads all columns of all row
+    /// groups.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the names of the columns to read, in the order they should appear
+    /// in the `Frame`. Other columns are not decoded.
+    pub fn columns<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the maximum number of rows decoded at a time, which is the size of
+    /// each `Frame` yielded by `Frame::read_parquet_batches`.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Sets the positions of the row groups to read, in the order they should
+    /// be read.
+    pub fn row_groups(mut self, row_groups: Vec<usize>) -> Self {
+        self.row_groups = Some(row_groups);
+        self
+    }
+}
+
+/// The compression codecs for writing Parquet files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParquetCompression {
+    Uncompressed,
+    Snappy,
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::Uncompressed => Self::UNCOMPRESSED,
+            ParquetCompression::Snappy => Self::SNAPPY,
+        }
+    }
+}
+
+/// Options for writing a `Frame` as a Parquet file.
+#[derive(Debug, Clone)]
+pub struct WriteParquetOptions {
+    compression: ParquetCompression,
+    row_group_size: Option<usize>,
+}
+
+impl Default for WriteParquetOptions {
+    fn default() -> Self {
+        Self {
+            compression: ParquetCompression::Snappy,
+            row_group_size: None,
+        }
+    }
+}
+
+impl WriteParquetOptions {
+    /// Creates a new `WriteParquetOptions` that writes Snappy-compressed row
+    /// groups of the default maximum size.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the compression codec for all columns.
+    pub fn compression(mut self, compression: ParquetCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the maximum number of rows in each row group.
+    pub fn row_group_size(mut self, row_group_size: usize) -> Self {
+        self.row_group_size = Some(row_group_size);
+        self
+    }
+
+    fn properties(&self) -> WriterProperties {
+        let mut builder = WriterProperties::builder().set_compression(self.compression.into());
+
+        if let Some(row_group_size) = self.row_group_size {
+            builder = builder.set_max_row_group_size(row_group_size);
+        }
+
+        builder.build()
+    }
+}
+
+/// An iterator over a Parquet file that yields a `Frame` for each batch of
+/// rows, labeled continuously from `0`.
+///
+/// This `struct` is created by `Frame::read_parquet_batches`.
+pub struct ParquetBatches {
+    reader: ParquetRecordBatchReader,
+    projection: Option<Vec<usize>>,
+    offset: usize,
+}
+
+impl ParquetBatches {
+    fn next_batch(&mut self) -> Option<Result<RecordBatch, InvalidParquet>> {
+        let batch = match self.reader.next()? {
+            Ok(batch) => batch,
+            Err(err) => return Some(Err(ParquetError::from(err).into())),
+        };
+
+        Some(match &self.projection {
+            Some(projection) => batch.project(projection).map_err(|err| ParquetError::from(err).into()),
+            None => Ok(batch),
+        })
+    }
+}
+
+impl Iterator for ParquetBatches {
+    type Item = Result<Frame<usize>, InvalidParquet>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = match self.next_batch()? {
+            Ok(batch) => batch,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let frame = Frame::from_record_batch_at(&batch, self.offset);
+        self.offset += batch.num_rows();
+
+        Some(frame.map_err(Into::into))
+    }
+}
+
+impl Frame<usize> {
+    /// Reads a Parquet file in batches of rows. Each `Frame` is labeled
+    /// continuously from the last, starting from `0`. If a column in the
+    /// options does not exist, a `MissingColumn` error is returned.
+    pub fn read_parquet_batches<R>(reader: R, options: &ReadParquetOptions) -> Result<ParquetBatches, InvalidParquet>
+    where
+        R: ChunkReader + 'static,
+    {
+        let mut builder = ParquetRecordBatchReaderBuilder::try_new(reader)?;
+        let mut projection = None;
+
+        if let Some(columns) = &options.columns {
+            let schema = builder.schema().clone();
+
+            let positions =
+                columns
+                .iter()
+                .map(|name| schema.index_of(name).map_err(|_| MissingColumn { name: name.clone() }))
+                .collect::<Result<Vec<_>, _>>()?
+            ;
+
+            // The projected columns keep their order in the file, so each batch
+            // is then projected again into the requested order.
+            let mut sorted = positions.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+
+            let mask = ProjectionMask::roots(builder.parquet_schema(), sorted.iter().copied());
+            builder = builder.with_projection(mask);
+
+            projection = Some(positions.iter().map(|p| sorted.binary_search(p).unwrap()).collect());
+        }
+
+        if let Some(batch_size) = options.batch_size {
+            builder = builder.with_batch_size(batch_size);
+        }
+
+        if let Some(row_groups) = &options.row_groups {
+            builder = builder.with_row_groups(row_groups.clone());
+        }
+
+        Ok(ParquetBatches { reader: builder.build()?, projection, offset: 0 })
+    }
+
+    /// Reads a Parquet file into a new `Frame`, with one row per record,
+    /// labeled `0..n`, and one column per field. Column types are converted as
+    /// in `Frame::from_record_batch`.
+    pub fn read_parquet<R>(reader: R, options: &ReadParquetOptions) -> Result<Self, InvalidParquet>
+    where
+        R: ChunkReader + 'static,
+    {
+        let mut batches = Self::read_parquet_batches(reader, options)?;
+
+        let frames = batches.by_ref().collect::<Result<Vec<_>, _>>()?;
+
+        if frames.is_empty() {
+            let schema = match &batches.projection {
+                Some(projection) => batches.reader.schema().project(projection).map_err(ParquetError::from)?,
+                None => batches.reader.schema().as_ref().clone(),
+            };
+
+            return Ok(Self::from_record_batch(&RecordBatch::new_empty(schema.into()))?);
+        }
+
+        Ok(Self::concat_rows(frames, MismatchPolicy::Error).unwrap())
+    }
+
+    /// Reads the Parquet file at the given path into a new `Frame`.
+    pub fn read_parquet_path<P>(path: P, options: &ReadParquetOptions) -> Result<Self, InvalidParquet>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).map_err(ParquetError::from)?;
+        Self::read_parquet(file, options)
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Writes this `Frame` as a Parquet file, with one field per column. The
+    /// row labels are not written. Column types are converted as in
+    /// `Frame::to_record_batch`.
+    pub fn write_parquet<W>(&self, writer: W, options: &WriteParquetOptions) -> Result<(), ParquetError>
+    where
+        W: Write + Send,
+    {
+        let batch = self.to_record_batch();
+
+        let mut writer = ArrowWriter::try_new(writer, batch.schema(), Some(options.properties()))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        Ok(())
+    }
+
+    /// Writes this `Frame` as a Parquet file at the given path, creating or
+    /// truncating it.
+    pub fn write_parquet_path<P>(&self, path: P, options: &WriteParquetOptions) -> Result<(), ParquetError>
+    where
+        P: AsRef<Path>,
+    {
+        self.write_parquet(File::create(path)?, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use bytes::Bytes;
+
+    use crate::index::Index;
+    use crate::testing::fixtures;
+
+    fn write_bytes(frame: &Frame<char>, options: &WriteParquetOptions) -> Bytes {
+        let mut bytes = Vec::new();
+        frame.write_parquet(&mut bytes, options).unwrap();
+        Bytes::from(bytes)
+    }
+
+    #[test]
+    fn round_trip() {
+        let frame = fixtures::id_score_name();
+
+        for compression in [ParquetCompression::Uncompressed, ParquetCompression::Snappy] {
+            let options = WriteParquetOptions::new().compression(compression);
+            let bytes = write_bytes(&frame, &options);
+
+            let read = Frame::read_parquet(bytes, &ReadParquetOptions::new()).unwrap();
+
+            assert_eq!(read.index(), &Index::from_iter(0..5));
+            assert_eq!(read.schema(), frame.schema());
+            assert_eq!(read.column_as::<i64>("id").unwrap().values(), &[1, 2, 3, 4, 5]);
+            assert_eq!(read.column_as::<Option<f64>>("score").unwrap().values(), frame.column_as::<Option<f64>>("score").unwrap().values());
+            assert_eq!(read.column_as::<String>("name").unwrap().values()[4], "t");
+        }
+    }
+
+    #[test]
+    fn read_projection() {
+        let bytes = write_bytes(&fixtures::id_score_name(), &WriteParquetOptions::new());
+
+        let options = ReadParquetOptions::new().columns(vec!["name", "id"]);
+        let read = Frame::read_parquet(bytes.clone(), &options).unwrap();
+
+        assert_eq!(read.column_names().collect::<Vec<_>>(), vec!["name", "id"]);
+        assert_eq!(read.num_rows(), 5);
+
+        let options = ReadParquetOptions::new().columns(vec!["id", "missing"]);
+        let err = Frame::read_parquet(bytes, &options).unwrap_err();
+
+        assert!(matches!(err, InvalidParquet::MissingColumn(MissingColumn { name }) if name == "missing"));
+    }
+
+    #[test]
+    fn read_batches() {
+        let options = WriteParquetOptions::new().row_group_size(2);
+        let bytes = write_bytes(&fixtures::id_score_name(), &options);
+
+        let frames =
+            Frame::read_parquet_batches(bytes.clone(), &ReadParquetOptions::new().batch_size(2))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+        ;
+
+        assert_eq!(frames.iter().map(Frame::num_rows).collect::<Vec<_>>(), vec![2, 2, 1]);
+        assert_eq!(frames[2].index(), &Index::from_iter(4..5));
+
+        let options = ReadParquetOptions::new().row_groups(vec![2, 0]).columns(vec!["id"]);
+        let read = Frame::read_parquet(bytes, &options).unwrap();
+
+        assert_eq!(read.column_as::<i64>("id").unwrap().values(), &[5, 1, 2]);
+    }
+
+    #[test]
+    fn read_empty() {
+        let frame = fixtures::id_score_name().iloc_range(0..0).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("empty.parquet");
+        frame.write_parquet_path(&path, &WriteParquetOptions::new()).unwrap();
+
+        let read = Frame::read_parquet_path(&path, &ReadParquetOptions::new().columns(vec!["score"])).unwrap();
+
+        assert_eq!(read.shape(), (0, 1));
+        assert_eq!(read.column("score").unwrap().dtype_name(), "Option<F64>");
+    }
+
+    #[test]
+    fn read_invalid() {
+        let err = Frame::read_parquet_path("/nonexistent/rustable.parquet", &ReadParquetOptions::new()).unwrap_err();
+        assert!(matches!(err, InvalidParquet::Parquet(_)));
+    }
+}
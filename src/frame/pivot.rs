@@ -0,0 +1,232 @@
+//! Reshaping of long `Frame`s into wide form.
+
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+use indexmap::IndexMap;
+
+use crate::index::Index;
+use crate::traits::Label;
+use crate::types::Column;
+
+use super::group::aggregate;
+use super::group::RowKey;
+use super::Agg;
+use super::DuplicateColumnName;
+use super::DuplicateEntry;
+use super::Frame;
+use super::InvalidPivot;
+use super::MissingColumn;
+use super::UnsupportedAgg;
+
+/// The cells of a pivoted `Frame`: the positions of the source rows for each
+/// pair of a row key and a column key.
+struct Cells {
+    /// The position of the first source row of each row key.
+    row_firsts: Vec<usize>,
+    /// The position of the first source row of each column key.
+    col_firsts: Vec<usize>,
+    /// The positions of the source rows for each row key, for each column key.
+    cells: Vec<Vec<Vec<usize>>>,
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Groups the positions of the rows of this `Frame` by the values of the
+    /// row and column key columns, in order of first occurrence.
+    fn pivot_cells(&self, index_col: &str, columns_col: &str, values_col: &str) -> Result<Cells, MissingColumn> {
+        let get = |name: &str| self.column(name).ok_or_else(|| MissingColumn { name: name.to_string() });
+        let (row_keys, col_keys) = (get(index_col)?, get(columns_col)?);
+        get(values_col)?;
+
+        let mut row_codes = HashMap::new();
+        let mut col_codes = HashMap::new();
+        let (mut row_firsts, mut col_firsts) = (Vec::new(), Vec::new());
+        let mut cells: Vec<Vec<Vec<usize>>> = Vec::new();
+
+        for pos in 0..self.num_rows() {
+            let r = *row_codes.entry(RowKey(vec![row_keys.datum(pos).unwrap()])).or_insert_with(|| {
+                row_firsts.push(pos);
+                for column in cells.iter_mut() { column.push(Vec::new()); }
+                row_firsts.len() - 1
+            });
+
+            let c = *col_codes.entry(RowKey(vec![col_keys.datum(pos).unwrap()])).or_insert_with(|| {
+                col_firsts.push(pos);
+                cells.push(vec![Vec::new(); row_firsts.len()]);
+                col_firsts.len() - 1
+            });
+
+            cells[c][r].push(pos);
+        }
+
+        Ok(Cells { row_firsts, col_firsts, cells })
+    }
+
+    /// Builds a pivoted `Frame` from the row key column and one column for
+    /// each column key.
+    fn pivot_frame<I>(&self, index_col: &str, columns_col: &str, cells: &Cells, columns: I) -> Result<Frame<usize>, DuplicateColumnName>
+    where
+        I: IntoIterator<Item = Column<usize>>,
+    {
+        let index = Index::from_iter(0..cells.row_firsts.len());
+        let row_positions = cells.row_firsts.iter().copied().map(Some).collect::<Vec<_>>();
+
+        let mut map = IndexMap::new();
+
+        let mut row_keys = self.1[index_col].gather(&row_positions, index, false);
+        row_keys.set_name(index_col);
+        map.insert(index_col.to_string(), row_keys);
+
+        for (&first, mut column) in cells.col_firsts.iter().zip(columns) {
+            let name = self.1[columns_col].datum(first).unwrap().to_string();
+
+            if map.contains_key(&name) {
+                return Err(DuplicateColumnName { name });
+            }
+
+            column.set_name(&name);
+            map.insert(name, column);
+        }
+
+        Ok(Frame::new_inner(Index::from_iter(0..cells.row_firsts.len()), map))
+    }
+
+    /// Reshapes this `Frame` into wide form, with one row for each value of
+    /// the index column and one column for each value of the columns column,
+    /// named after the displayed value. Each cell contains the value of the
+    /// values column for that pair, or `None` if there is no such row. The
+    /// result is labeled by position and starts with the index column.
+    ///
+    /// If a column is not found, if a pair of values occurs in more than one
+    /// row, or if the result would have duplicate column names, an
+    /// `InvalidPivot` error is returned.
+    pub fn pivot(&self, index_col: &str, columns_col: &str, values_col: &str) -> Result<Frame<usize>, InvalidPivot> {
+        let cells = self.pivot_cells(index_col, columns_col, values_col)?;
+        let values = &self.1[values_col];
+        let index = Index::from_iter(0..cells.row_firsts.len());
+
+        let mut columns = Vec::with_capacity(cells.cells.len());
+
+        for (c, column_cells) in cells.cells.iter().enumerate() {
+            if let Some(r) = column_cells.iter().position(|ps| ps.len() > 1) {
+                let index = self.1[index_col].datum(cells.row_firsts[r]).unwrap().to_string();
+                let column = self.1[columns_col].datum(cells.col_firsts[c]).unwrap().to_string();
+
+                return Err(DuplicateEntry { index, column }.into());
+            }
+
+            let positions = column_cells.iter().map(|ps| ps.first().copied()).collect::<Vec<_>>();
+            columns.push(values.gather(&positions, index.clone(), true));
+        }
+
+        Ok(self.pivot_frame(index_col, columns_col, &cells, columns)?)
+    }
+
+    /// Like `pivot`, but combines the values of rows with the same pair of
+    /// values using the given aggregation, instead of returning an error.
+    /// Cells without any rows are `None`.
+    pub fn pivot_table(&self, index_col: &str, columns_col: &str, values_col: &str, agg: Agg) -> Result<Frame<usize>, InvalidPivot> {
+        let cells = self.pivot_cells(index_col, columns_col, values_col)?;
+        let values = &self.1[values_col];
+        let index = Index::from_iter(0..cells.row_firsts.len());
+
+        let mut columns = Vec::with_capacity(cells.cells.len());
+
+        for column_cells in &cells.cells {
+            // Aggregate the non-empty cells, then spread them out over all rows.
+            let groups = column_cells.iter().filter(|ps| !ps.is_empty()).cloned().collect::<Vec<_>>();
+
+            let aggregated = aggregate(values, &groups, agg, values_col).ok_or_else(|| {
+                UnsupportedAgg { name: values_col.to_string(), agg, dtype_name: values.dtype_name() }
+            })?;
+
+            let mut n = 0;
+            let positions =
+                column_cells
+                .iter()
+                .map(|ps| {
+                    if ps.is_empty() { return None; }
+
+                    let pos = n;
+                    n += 1;
+                    Some(pos)
+                })
+                .collect::<Vec<_>>()
+            ;
+
+            columns.push(aggregated.gather(&positions, index.clone(), true));
+        }
+
+        Ok(self.pivot_frame(index_col, columns_col, &cells, columns)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::series::Series;
+    use crate::testing::fixtures;
+
+    #[test]
+    fn pivot() {
+        let frame = fixtures::store_sales().iloc_range(..5).unwrap();
+
+        let wide = frame.pivot("store", "year", "total").unwrap();
+        assert_eq!(wide.index(), &Index::from_iter(0..3));
+        assert_eq!(wide.column_names().collect::<Vec<_>>(), vec!["store", "2020", "2021"]);
+        assert_eq!(wide.column_as::<String>("store").unwrap().values(), &["A", "B", "C"]);
+        assert_eq!(wide.column_as::<Option<f64>>("2020").unwrap().values(), &[Some(1.0), Some(3.0), None]);
+        assert_eq!(wide.column_as::<Option<f64>>("2021").unwrap().values(), &[Some(2.0), Some(4.0), Some(5.0)]);
+        assert_eq!(wide.column_as::<Option<f64>>("2021").unwrap().name(), Some("2021"));
+
+        match fixtures::store_sales().pivot("store", "year", "total").unwrap_err() {
+            InvalidPivot::DuplicateEntry(err) => {
+                assert_eq!(err.index, "A");
+                assert_eq!(err.column, "2021");
+            },
+            _ => panic!("expected a duplicate entry"),
+        }
+
+        match frame.pivot("store", "month", "total").unwrap_err() {
+            InvalidPivot::MissingColumn(err) => assert_eq!(err.name, "month"),
+            _ => panic!("expected a missing column"),
+        }
+
+        let index = Index::from_iter(0..1);
+        let clashing = Frame::from_columns(index.clone(), vec![
+            ("key", Column::from(Series::from_values(index.clone(), vec![1]).unwrap())),
+            ("name", Column::from(Series::from_values(index.clone(), vec![String::from("key")]).unwrap())),
+            ("value", Column::from(Series::from_values(index, vec![true]).unwrap())),
+        ]).unwrap();
+
+        match clashing.pivot("key", "name", "value").unwrap_err() {
+            InvalidPivot::DuplicateColumnName(err) => assert_eq!(err.name, "key"),
+            _ => panic!("expected a duplicate column name"),
+        }
+    }
+
+    #[test]
+    fn pivot_table() {
+        let frame = fixtures::store_sales();
+
+        let wide = frame.pivot_table("store", "year", "total", Agg::Sum).unwrap();
+        assert_eq!(wide.column_names().collect::<Vec<_>>(), vec!["store", "2020", "2021"]);
+        assert_eq!(wide.column_as::<Option<f64>>("2020").unwrap().values(), &[Some(1.0), Some(3.0), None]);
+        assert_eq!(wide.column_as::<Option<f64>>("2021").unwrap().values(), &[Some(8.0), Some(4.0), Some(5.0)]);
+
+        let wide = frame.pivot_table("year", "store", "total", Agg::Count).unwrap();
+        assert_eq!(wide.column_names().collect::<Vec<_>>(), vec!["year", "A", "B", "C"]);
+        assert_eq!(wide.column_as::<i32>("year").unwrap().values(), &[2020, 2021]);
+        assert_eq!(wide.column_as::<Option<usize>>("A").unwrap().values(), &[Some(1), Some(2)]);
+        assert_eq!(wide.column_as::<Option<usize>>("C").unwrap().values(), &[None, Some(1)]);
+
+        match frame.pivot_table("total", "year", "store", Agg::Mean).unwrap_err() {
+            InvalidPivot::UnsupportedAgg(err) => assert_eq!(err.name, "store"),
+            _ => panic!("expected an unsupported aggregation"),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Conversion between strongly-typed structs and the rows of a `Frame`.
+//!
+//! With the `derive` feature, `Record` can be derived for structs with named
+//! fields, where each field has a type that can be stored in a `Column`:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() {
+//! use rustable::frame::Frame;
+//! use rustable::frame::Record;
+//!
+//! #[derive(Record)]
+//! struct Score {
+//!     player: String,
+//!     points: Option<u32>,
+//! }
+//!
+//! let frame = Frame::from_records(vec![Score { player: String::from("a"), points: None }]);
+//! let scores = frame.to_records::<Score>().unwrap();
+//!
+//! assert_eq!(scores[0].player, "a");
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use std::iter::FromIterator;
+
+use crate::index::Index;
+use crate::traits::Label;
+use crate::types::Column;
+
+use super::DTypeMismatch;
+use super::Frame;
+use super::InvalidRecord;
+use super::MissingColumn;
+use super::Row;
+
+/// A struct whose fields map one-to-one onto the columns of a `Frame`.
+pub trait Record: Sized {
+    /// Returns the name and dtype name of each field, in order.
+    fn fields() -> Vec<(&'static str, String)>;
+
+    /// Consumes the given records and creates one `Column` per field, in
+    /// order, each with the given `Index`. The caller must ensure that the
+    /// `Index` has the same length as the records.
+    fn into_columns<L: Label>(records: Vec<Self>, index: &Index<L>) -> Vec<Column<L>>;
+
+    /// Creates a record from the values of a row, or returns `None` if a field
+    /// has no column of the matching name and type.
+    fn from_row<L: Label>(row: &Row<'_, L>) -> Option<Self>;
+}
+
+impl Frame<usize> {
+    /// Creates a new `Frame` with one row per record, labeled `0..n`, and one
+    /// column per field, in field order.
+    pub fn from_records<T>(records: Vec<T>) -> Self
+    where
+        T: Record,
+    {
+        let index = Index::from_iter(0..records.len());
+        let names = T::fields().into_iter().map(|(name, _)| name);
+        let columns = names.zip(T::into_columns(records, &index));
+
+        Self::from_columns(index, columns).unwrap()
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Converts each row of this `Frame` into a record, in order. Columns that
+    /// do not match a field are ignored. If a field has no column of the same
+    /// name, a `MissingColumn` error is returned, and if the column has a
+    /// different type than the field, a `DTypeMismatch` error is returned.
+    pub fn to_records<T>(&self) -> Result<Vec<T>, InvalidRecord>
+    where
+        T: Record,
+    {
+        for (name, expected) in T::fields() {
+            let column = self.column(name).ok_or_else(|| MissingColumn { name: name.to_string() })?;
+            let found = column.dtype_name();
+
+            if found != expected {
+                return Err(DTypeMismatch { name: name.to_string(), expected, found }.into());
+            }
+        }
+
+        Ok(self.rows().map(|row| T::from_row(&row).unwrap()).collect())
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+
+    use crate::frame::Record;
+
+    #[derive(Debug, Clone, PartialEq, Record)]
+    struct Score {
+        player: String,
+        round: u8,
+        points: Option<f64>,
+    }
+
+    fn scores() -> Vec<Score> {
+        vec![
+            Score { player: String::from("ann"), round: 1, points: Some(2.5) },
+            Score { player: String::from("bob"), round: 1, points: None },
+            Score { player: String::from("ann"), round: 2, points: Some(4.0) },
+        ]
+    }
+
+    #[test]
+    fn round_trip() {
+        let frame = Frame::from_records(scores());
+
+        assert_eq!(frame.index(), &Index::from_iter(0..3));
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["player", "round", "points"]);
+        assert_eq!(frame.column_as::<u8>("round").unwrap().values(), &[1, 1, 2]);
+        assert_eq!(frame.column_as::<Option<f64>>("points").unwrap().name(), Some("points"));
+
+        assert_eq!(frame.to_records::<Score>().unwrap(), scores());
+
+        let empty = Frame::from_records(Vec::<Score>::new());
+        assert_eq!(empty.shape(), (0, 3));
+        assert!(empty.to_records::<Score>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn field_names() {
+        // Field names that match the parameters and locals of the generated
+        // code, and a raw identifier.
+        #[derive(Debug, Clone, PartialEq, Record)]
+        struct Shadowing {
+            index: u32,
+            records: String,
+            record: bool,
+            row: Option<i8>,
+            r#type: u8,
+        }
+
+        let records = vec![
+            Shadowing { index: 7, records: String::from("a"), record: true, row: None, r#type: 1 },
+            Shadowing { index: 3, records: String::from("b"), record: false, row: Some(-1), r#type: 2 },
+        ];
+
+        let frame = Frame::from_records(records.clone());
+
+        assert_eq!(frame.column_names().collect::<Vec<_>>(), vec!["index", "records", "record", "row", "type"]);
+        assert_eq!(frame.column_as::<u32>("index").unwrap().values(), &[7, 3]);
+        assert_eq!(frame.column_as::<u8>("type").unwrap().values(), &[1, 2]);
+        assert_eq!(frame.to_records::<Shadowing>().unwrap(), records);
+    }
+
+    #[test]
+    fn to_records_errors() {
+        let mut frame = Frame::from_records(scores());
+
+        let round = frame.drop_column("round").unwrap();
+        let err = frame.to_records::<Score>().unwrap_err();
+        assert!(matches!(err, InvalidRecord::MissingColumn(MissingColumn { ref name }) if name == "round"));
+
+        let round = round.as_u8().unwrap().clone().map(u16::from);
+        frame.insert_column("round", round).unwrap();
+        let err = frame.to_records::<Score>().unwrap_err();
+        assert!(matches!(
+            err,
+            InvalidRecord::DTypeMismatch(DTypeMismatch { ref expected, ref found, .. }) if expected == "U8" && found == "U16"
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+//! Conforming the rows of a `Frame` to a new row `Index`.
+
+use crate::index::Index;
+use crate::traits::Label;
+
+use super::Frame;
+
+/// How to fill the rows introduced by `Frame::reindex` for labels that are not
+/// in the original `Frame`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReindexFill {
+    /// Leave the rows as `None`.
+    None,
+    /// Copy the nearest preceding row of the new `Index` that has a label in
+    /// the original `Frame`.
+    Forward,
+    /// Copy the nearest following row of the new `Index` that has a label in
+    /// the original `Frame`.
+    Backward,
+}
+
+/// Replaces each missing position with the last present position, in the
+/// order given by the iterator.
+fn fill_positions<'a, I>(positions: I)
+where
+    I: Iterator<Item = &'a mut Option<usize>>,
+{
+    let mut last = None;
+
+    for pos in positions {
+        if pos.is_some() { last = *pos; } else { *pos = last; }
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Returns a new `Frame` with the given row `Index`, taking each row from
+    /// the row with the same label in this `Frame`. Rows for labels not in this
+    /// `Frame` are filled according to the given mode, and rows of this
+    /// `Frame` whose labels are not in the new `Index` are dropped. If any rows
+    /// are left unfilled, every column of the new `Frame` has `Option` values,
+    /// with `None` in those rows; otherwise, columns keep their types.
+    pub fn reindex(&self, index: Index<L>, fill: ReindexFill) -> Self {
+        let mut positions = index.iter().map(|l| self.0.index_of(l)).collect::<Vec<_>>();
+
+        match fill {
+            ReindexFill::None => {},
+            ReindexFill::Forward => fill_positions(positions.iter_mut()),
+            ReindexFill::Backward => fill_positions(positions.iter_mut().rev()),
+        }
+
+        let optional = positions.iter().any(Option::is_none);
+
+        let columns =
+            self.1
+            .iter()
+            .map(|(name, column)| (name.clone(), column.gather(&positions, index.clone(), optional)))
+            .collect()
+        ;
+
+        Self::new_inner(index, columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::testing::fixtures;
+
+    #[test]
+    fn reindex() {
+        let frame = fixtures::even_labels();
+
+        let sub = frame.reindex(Index::from_iter(vec![6, 2]), ReindexFill::None);
+        assert_eq!(sub.index(), &Index::from_iter(vec![6, 2]));
+        assert_eq!(sub.column_as::<i32>("ints").unwrap().values(), &[60, 20]);
+        assert_eq!(sub.column_as::<Option<char>>("opts").unwrap().values(), &[Some('f'), Some('b')]);
+        assert_eq!(sub.1["ints"].index(), sub.index());
+
+        let calendar = Index::from_iter(1..=7);
+
+        let sub = frame.reindex(calendar.clone(), ReindexFill::None);
+        assert_eq!(sub.index(), &calendar);
+        assert_eq!(
+            sub.column_as::<Option<i32>>("ints").unwrap().values(),
+            &[None, Some(20), None, Some(40), None, Some(60), None],
+        );
+        assert_eq!(sub.column_as::<Option<char>>("opts").unwrap().values()[5], Some('f'));
+
+        let sub = frame.reindex(calendar.clone(), ReindexFill::Forward);
+        assert_eq!(
+            sub.column_as::<Option<i32>>("ints").unwrap().values(),
+            &[None, Some(20), Some(20), Some(40), Some(40), Some(60), Some(60)],
+        );
+
+        let sub = frame.reindex(Index::from_iter(2..=6), ReindexFill::Backward);
+        assert_eq!(sub.column_as::<i32>("ints").unwrap().values(), &[20, 40, 40, 60, 60]);
+        assert_eq!(sub.column_as::<Option<char>>("opts").unwrap().values(), &[Some('b'), None, None, Some('f'), Some('f')]);
+
+        let sub = frame.reindex(Index::new(), ReindexFill::None);
+        assert_eq!(sub.shape(), (0, 2));
+        assert_eq!(sub.1["ints"].dtype_name(), "I32");
+    }
+}
@@ -0,0 +1,77 @@
+//! Read-only views of single rows of a `Frame`.
+
+use crate::traits::ColumnType;
+use crate::traits::Label;
+use crate::types::Datum;
+
+use super::Frame;
+
+/// A read-only view of a single row of a `Frame`, giving access to its label
+/// and to the value of each column.
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a, L: Label> {
+    frame: &'a Frame<L>,
+    pos: usize,
+}
+
+impl<'a, L> Row<'a, L>
+where
+    L: Label,
+{
+    /// The caller must ensure that the position is in bounds.
+    pub(crate) fn new(frame: &'a Frame<L>, pos: usize) -> Self {
+        Self { frame, pos }
+    }
+
+    /// Returns the label of this row.
+    pub fn label(&self) -> &'a L {
+        self.frame.0.iloc(self.pos).unwrap()
+    }
+
+    /// Returns the position of this row in its `Frame`.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of values in this row, one for each column.
+    pub fn len(&self) -> usize {
+        self.frame.num_cols()
+    }
+
+    /// Returns `true` if this row contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value of the column with the given name, if it exists.
+    pub fn get(&self, name: &str) -> Option<Datum<'a>> {
+        self.frame.1.get(name).and_then(|column| column.datum(self.pos))
+    }
+
+    /// Returns a reference to the value of the column with the given name, if
+    /// it exists and has values of type `V`.
+    pub fn get_as<V>(&self, name: &str) -> Option<&'a V>
+    where
+        V: ColumnType,
+    {
+        self.frame.column_as::<V>(name).and_then(|series| series.iloc(self.pos))
+    }
+
+    /// Returns the value of the column at the given position, if it exists.
+    pub fn get_at(&self, col_pos: usize) -> Option<Datum<'a>> {
+        self.frame.1.get_index(col_pos).and_then(|(_, column)| column.datum(self.pos))
+    }
+
+    /// Returns an iterator over the column names and values of this row, in
+    /// column order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, Datum<'a>)> + 'a {
+        let pos = self.pos;
+
+        self.frame.1.iter().map(move |(name, column)| (name.as_str(), column.datum(pos).unwrap()))
+    }
+
+    /// Returns the values of this row, in column order.
+    pub fn to_vec(&self) -> Vec<Datum<'a>> {
+        self.iter().map(|(_, datum)| datum).collect()
+    }
+}
@@ -0,0 +1,147 @@
+//! Descriptions of the names and types of the columns of a `Frame`.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use crate::traits::Label;
+use crate::types::DType;
+
+use super::Frame;
+
+/// The name, base type, and nullability of a single column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub dtype: DType,
+    pub nullable: bool,
+}
+
+/// Displays a `Field` as its name and type, e.g. `score: Option<F64>`.
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if self.nullable {
+            write!(f, "{}: Option<{:?}>", self.name, self.dtype)
+        } else {
+            write!(f, "{}: {:?}", self.name, self.dtype)
+        }
+    }
+}
+
+/// An ordered description of the columns of a `Frame`. Two `Frame`s with equal
+/// `Schema`s have the same column names, in the same order, with the same
+/// types.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Schema(Vec<Field>);
+
+impl Schema {
+    /// Creates a new `Schema` from the given `Field`s, in order.
+    pub fn new(fields: Vec<Field>) -> Self {
+        Self(fields)
+    }
+
+    /// Returns a read-only slice of the `Field`s of this `Schema`, in order.
+    pub fn fields(&self) -> &[Field] {
+        &self.0
+    }
+
+    /// Returns the number of `Field`s in this `Schema`.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if this `Schema` contains no `Field`s.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a read-only reference to the `Field` with the given name, if it
+    /// exists.
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.0.iter().find(|field| field.name == name)
+    }
+
+    /// Returns an iterator over the names of the `Field`s of this `Schema`,
+    /// in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.0.iter().map(|field| field.name.as_str())
+    }
+}
+
+/// Displays a `Schema` with one `Field` per line.
+impl Display for Schema {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        for field in &self.0 {
+            writeln!(f, "{}", field)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Returns the name and base type of each column of this `Frame`, in
+    /// order.
+    pub fn dtypes(&self) -> Vec<(String, DType)> {
+        self.1.iter().map(|(name, column)| (name.clone(), column.dtype())).collect()
+    }
+
+    /// Returns a `Schema` describing the name, base type, and nullability of
+    /// each column of this `Frame`, in order.
+    pub fn schema(&self) -> Schema {
+        let fields =
+            self.1
+            .iter()
+            .map(|(name, column)| Field {
+                name: name.clone(),
+                dtype: column.dtype(),
+                nullable: column.is_optional(),
+            })
+            .collect()
+        ;
+
+        Schema(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+    use crate::series::Series;
+    use crate::types::Column;
+
+    #[test]
+    fn schema() {
+        let index = Index::from_iter("ab".chars());
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("id", Column::from(Series::from_values(index.clone(), vec![1u32, 2]).unwrap())),
+            ("score", Column::from(Series::from_values(index, vec![Some(0.5f64), None]).unwrap())),
+        ]).unwrap();
+
+        assert_eq!(frame.dtypes(), vec![(String::from("id"), DType::U32), (String::from("score"), DType::F64)]);
+
+        let schema = frame.schema();
+        assert_eq!(schema.len(), 2);
+        assert_eq!(schema.names().collect::<Vec<_>>(), vec!["id", "score"]);
+        assert_eq!(schema.field("score"), Some(&Field { name: String::from("score"), dtype: DType::F64, nullable: true }));
+        assert!(!schema.field("id").unwrap().nullable);
+        assert!(schema.field("rank").is_none());
+        assert_eq!(schema.to_string(), "id: U32\nscore: Option<F64>\n");
+
+        assert_eq!(schema, Schema::new(schema.fields().to_vec()));
+
+        let mut other = frame.clone();
+        other.drop_column("id");
+        assert_ne!(schema, other.schema());
+
+        assert!(Frame::<char>::new().schema().is_empty());
+    }
+}
@@ -0,0 +1,106 @@
+//! Sorting of the rows of a `Frame` by the values of its columns.
+
+use std::cmp::Ordering;
+
+use crate::traits::Label;
+use crate::types::Datum;
+
+use super::Frame;
+use super::MissingColumn;
+
+/// The direction to sort the values of a column in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Returns `true` for values that sort last regardless of direction: `None`
+/// and values that are not comparable with themselves (e.g. NaN).
+fn is_missing(datum: &Datum<'_>) -> bool {
+    datum.is_none() || datum.partial_cmp(datum).is_none()
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Returns a new `Frame` with its rows sorted by the values of the given
+    /// columns, compared lexicographically in the given order. The sort is
+    /// stable, and the row `Index` and every column are reordered together.
+    /// Missing values (`None` or NaN) are always placed last. If a column is
+    /// not found, a `MissingColumn` error is returned.
+    pub fn sort_values(&self, by: &[(&str, SortOrder)]) -> Result<Self, MissingColumn> {
+        let keys =
+            by
+            .iter()
+            .map(|&(name, order)| {
+                self.column(name)
+                    .map(|column| (column, order))
+                    .ok_or_else(|| MissingColumn { name: name.to_string() })
+            })
+            .collect::<Result<Vec<_>, _>>()?
+        ;
+
+        let mut positions = (0..self.num_rows()).collect::<Vec<_>>();
+
+        positions.sort_by(|&a, &b| {
+            for &(column, order) in &keys {
+                let (da, db) = (column.datum(a).unwrap(), column.datum(b).unwrap());
+
+                let ord = match (is_missing(&da), is_missing(&db)) {
+                    (false, false) => {
+                        let ord = da.partial_cmp(&db).unwrap_or(Ordering::Equal);
+                        if order == SortOrder::Ascending { ord } else { ord.reverse() }
+                    },
+                    (false, true) => Ordering::Less,
+                    (true, false) => Ordering::Greater,
+                    (true, true) => Ordering::Equal,
+                };
+
+                if ord != Ordering::Equal { return ord; }
+            }
+
+            Ordering::Equal
+        });
+
+        Ok(self.select_positions(&positions).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+    use crate::series::Series;
+    use crate::types::Column;
+
+    #[test]
+    fn sort_values() {
+        let index = Index::from_iter("abcdef".chars());
+
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("group", Column::from(Series::from_values(index.clone(), vec![2, 1, 2, 1, 2, 1]).unwrap())),
+            ("score", Column::from(Series::from_values(index.clone(), vec![
+                Some(0.5), None, Some(f64::NAN), Some(3.0), Some(1.5), Some(3.0),
+            ]).unwrap())),
+            ("name", Column::from(Series::from_values(index, vec!['u', 'v', 'w', 'x', 'y', 'z']).unwrap())),
+        ]).unwrap();
+
+        let sorted = frame.sort_values(&[("group", SortOrder::Ascending), ("score", SortOrder::Descending)]).unwrap();
+        assert_eq!(sorted.index(), &Index::from_iter("dfbeac".chars()));
+        assert_eq!(sorted.column_as::<char>("name").unwrap().values(), &['x', 'z', 'v', 'y', 'u', 'w']);
+        assert_eq!(sorted.column_as::<i32>("group").unwrap().index(), sorted.index());
+
+        let sorted = frame.sort_values(&[("score", SortOrder::Ascending)]).unwrap();
+        assert_eq!(sorted.index(), &Index::from_iter("aedfbc".chars()));
+
+        let sorted = frame.sort_values(&[]).unwrap();
+        assert_eq!(sorted.index(), frame.index());
+
+        assert_eq!(frame.sort_values(&[("rank", SortOrder::Ascending)]).unwrap_err().name, "rank");
+    }
+}
@@ -0,0 +1,321 @@
+//! Reading and writing of `Frame`s from and to SQLite databases.
+
+use std::convert::TryFrom;
+use std::iter::FromIterator;
+
+use rusqlite::types::Value;
+use rusqlite::Connection;
+use rusqlite::Error as SqlError;
+
+use crate::index::Index;
+use crate::traits::ColumnType;
+use crate::traits::Label;
+use crate::types::Column;
+use crate::types::DType;
+use crate::types::Datum;
+
+use super::DuplicateColumnName;
+use super::Frame;
+use super::InvalidSql;
+use super::UnsupportedSqlType;
+
+/// Quotes an SQL identifier, escaping any quotes within it.
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Returns the declared SQL type for columns of a `DType`.
+fn sql_type(dtype: DType) -> &'static str {
+    match dtype {
+        DType::Bool => "BOOLEAN",
+        dtype if dtype.is_integer() => "INTEGER",
+        dtype if dtype.is_float() => "REAL",
+        _ => "TEXT",
+    }
+}
+
+/// Converts a value to SQL. Integers, floats, and strings map onto their SQL
+/// equivalents, booleans onto `0` or `1`, missing values onto `NULL`, and all
+/// other values onto their display strings. Integers that do not fit in an
+/// `i64` cannot be converted.
+fn to_sql(datum: Datum<'_>) -> Result<Value, SqlError> {
+    macro_rules! to_sql_variants {
+        (
+            from: [ $( $name:ident, $opt_name:ident; )* ]
+            try_from: [ $( $try_name:ident, $try_opt_name:ident; )* ]
+        ) => {
+            match datum {
+                Datum::I64(v) | Datum::OptI64(Some(v)) => Ok(Value::Integer(*v)),
+                $( Datum::$name(v) | Datum::$opt_name(Some(v)) => Ok(Value::Integer(i64::from(*v))), )*
+                $(
+                    Datum::$try_name(v) | Datum::$try_opt_name(Some(v)) => {
+                        i64::try_from(*v)
+                            .map(Value::Integer)
+                            .map_err(|err| SqlError::ToSqlConversionFailure(Box::new(err)))
+                    },
+                )*
+                Datum::F32(v) | Datum::OptF32(Some(v)) => Ok(Value::Real(f64::from(*v))),
+                Datum::F64(v) | Datum::OptF64(Some(v)) => Ok(Value::Real(*v)),
+                Datum::Bool(v) | Datum::OptBool(Some(v)) => Ok(Value::Integer(i64::from(*v))),
+                datum if datum.is_none() => Ok(Value::Null),
+                datum => Ok(Value::Text(datum.to_string())),
+            }
+        };
+    }
+
+    #[cfg(not(feature = "128"))]
+    return to_sql_variants!(
+        from: [ I8, OptI8; I16, OptI16; I32, OptI32; U8, OptU8; U16, OptU16; U32, OptU32; ]
+        try_from: [ ISize, OptISize; U64, OptU64; USize, OptUSize; ]
+    );
+
+    #[cfg(feature = "128")]
+    return to_sql_variants!(
+        from: [ I8, OptI8; I16, OptI16; I32, OptI32; U8, OptU8; U16, OptU16; U32, OptU32; ]
+        try_from: [ ISize, OptISize; U64, OptU64; USize, OptUSize; I128, OptI128; U128, OptU128; ]
+    );
+}
+
+/// Converts every non-`NULL` value with the given function, creating a
+/// `Column` with `Option` values if any values are `NULL`.
+fn convert_column<T, F>(values: &[Value], index: Index<usize>, convert: F) -> Column<usize>
+where
+    T: ColumnType,
+    Option<T>: ColumnType,
+    F: Fn(&Value) -> T,
+{
+    let values = values.iter().map(|v| if v == &Value::Null { None } else { Some(convert(v)) }).collect();
+    Column::from_options(index, values, false)
+}
+
+/// Converts an integer or real value to a float.
+fn as_f64(value: &Value) -> f64 {
+    match value {
+        Value::Integer(i) => *i as f64,
+        Value::Real(f) => *f,
+        _ => unreachable!(),
+    }
+}
+
+/// Converts a non-`BLOB` value to a string.
+fn as_string(value: &Value) -> String {
+    match value {
+        Value::Integer(i) => i.to_string(),
+        Value::Real(f) => f.to_string(),
+        Value::Text(s) => s.clone(),
+        _ => unreachable!(),
+    }
+}
+
+/// Creates a `Column` from the SQL values of a result column. The declared
+/// type of the column picks the `DType` by SQLite's type affinity rules, as
+/// `Bool` for `BOOL` types, `I64` for `INT` types, `Str` for `CHAR`, `CLOB`,
+/// and `TEXT` types, and `F64` for `REAL`, `FLOA`, and `DOUB` types. If the
+/// column has no declared type, or a value does not fit it, the first of
+/// `I64`, `F64`, or `Str` that every non-`NULL` value fits is used instead.
+fn read_column(name: &str, decl_type: Option<&str>, values: &[Value], index: Index<usize>) -> Result<Column<usize>, UnsupportedSqlType> {
+    let present = values.iter().filter(|&v| v != &Value::Null).collect::<Vec<_>>();
+
+    if present.iter().any(|v| matches!(v, Value::Blob(_))) {
+        return Err(UnsupportedSqlType { name: String::from(name), sql_type: String::from("BLOB") });
+    }
+
+    let is_integer = |v: &&Value| matches!(v, Value::Integer(_));
+    let is_number = |v: &&Value| matches!(v, Value::Integer(_) | Value::Real(_));
+    let is_text = |v: &&Value| matches!(v, Value::Text(_));
+
+    let decl_type = decl_type.map(str::to_uppercase).unwrap_or_default();
+    let declares = |words: &[&str]| words.iter().any(|w| decl_type.contains(w));
+
+    let column =
+        if declares(&["BOOL"]) && present.iter().all(is_integer) {
+            convert_column(values, index, |v| v != &Value::Integer(0))
+        } else if declares(&["INT"]) && present.iter().all(is_integer) {
+            convert_column(values, index, |v| if let Value::Integer(i) = v { *i } else { unreachable!() })
+        } else if declares(&["CHAR", "CLOB", "TEXT"]) {
+            convert_column(values, index, as_string)
+        } else if declares(&["REAL", "FLOA", "DOUB"]) && present.iter().all(is_number) {
+            convert_column(values, index, as_f64)
+        } else if !present.is_empty() && present.iter().all(is_integer) {
+            convert_column(values, index, |v| if let Value::Integer(i) = v { *i } else { unreachable!() })
+        } else if !present.is_empty() && present.iter().all(is_number) {
+            convert_column(values, index, as_f64)
+        } else {
+            debug_assert!(present.iter().all(|v| is_number(v) || is_text(v)));
+            convert_column(values, index, as_string)
+        }
+    ;
+
+    Ok(column)
+}
+
+impl Frame<usize> {
+    /// Runs an SQL query on a SQLite connection and reads its results into a
+    /// new `Frame`, with one row per result row, labeled `0..n`, and one column
+    /// per result column. Columns have `Option` values if any of their values
+    /// are `NULL`. Columns are typed by their declared SQL type if their values
+    /// fit it, otherwise by their values. If a value is a `BLOB`, an
+    /// `UnsupportedSqlType` error is returned, and if a column name is
+    /// repeated, a `DuplicateColumnName` error is returned.
+    pub fn from_sql(query: &str, conn: &Connection) -> Result<Self, InvalidSql> {
+        let mut stmt = conn.prepare(query)?;
+
+        let columns =
+            stmt.columns()
+            .iter()
+            .map(|c| (String::from(c.name()), c.decl_type().map(String::from)))
+            .collect::<Vec<_>>()
+        ;
+
+        let mut values = vec![Vec::new(); columns.len()];
+        let mut rows = stmt.query([])?;
+
+        while let Some(row) = rows.next()? {
+            for (c, column_values) in values.iter_mut().enumerate() {
+                column_values.push(row.get::<_, Value>(c)?);
+            }
+        }
+
+        let index = Index::from_iter(0..values.first().map_or(0, Vec::len));
+
+        let mut frame_columns = Vec::with_capacity(columns.len());
+
+        for ((name, decl_type), values) in columns.into_iter().zip(&values) {
+            if frame_columns.iter().any(|(n, _)| n == &name) {
+                return Err(DuplicateColumnName { name }.into());
+            }
+
+            let column = read_column(&name, decl_type.as_deref(), values, index.clone())?;
+            frame_columns.push((name, column));
+        }
+
+        Ok(Self::from_columns(index, frame_columns).unwrap())
+    }
+}
+
+impl<L> Frame<L>
+where
+    L: Label,
+{
+    /// Writes this `Frame` into a new table of a SQLite database, with one
+    /// column per column and one row per row, in a single transaction. The row
+    /// labels are not written. Columns are declared as `INTEGER`, `REAL`,
+    /// `BOOLEAN`, or `TEXT` by their `DType`, and as `NOT NULL` unless they
+    /// have `Option` values. Values of other types are written as their
+    /// display strings. If the table already exists, an error is returned.
+    pub fn to_sql(&self, table: &str, conn: &Connection) -> Result<(), SqlError> {
+        let definitions =
+            self.1
+            .iter()
+            .map(|(name, column)| {
+                let constraint = if column.is_optional() { "" } else { " NOT NULL" };
+                format!("{} {}{}", quote_ident(name), sql_type(column.dtype()), constraint)
+            })
+            .collect::<Vec<_>>()
+        ;
+
+        let tx = conn.unchecked_transaction()?;
+
+        tx.execute(&format!("CREATE TABLE {} ({})", quote_ident(table), definitions.join(", ")), [])?;
+
+        {
+            let placeholders = vec!["?"; self.num_cols()].join(", ");
+            let mut stmt = tx.prepare(&format!("INSERT INTO {} VALUES ({})", quote_ident(table), placeholders))?;
+
+            for p in 0..self.num_rows() {
+                let row =
+                    self.1
+                    .values()
+                    .map(|column| to_sql(column.datum(p).unwrap()))
+                    .collect::<Result<Vec<_>, _>>()?
+                ;
+
+                stmt.execute(rusqlite::params_from_iter(row))?;
+            }
+        }
+
+        tx.commit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::series::Series;
+    use crate::testing::fixtures;
+
+    #[test]
+    fn round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        fixtures::id_score_flag_name_initial().to_sql("my \"table\"", &conn).unwrap();
+
+        let read = Frame::from_sql("SELECT * FROM \"my \"\"table\"\"\"", &conn).unwrap();
+
+        assert_eq!(read.index(), &Index::from_iter(0..3));
+        assert_eq!(
+            read.schema().fields().iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["id: I64", "score: Option<F64>", "flag: Bool", "name: Str", "initial: Option<Str>"],
+        );
+        assert_eq!(read.column_as::<i64>("id").unwrap().values(), &[1, 2, 3]);
+        assert_eq!(read.column_as::<Option<f64>>("score").unwrap().values(), &[Some(0.5), None, Some(1.5)]);
+        assert_eq!(read.column_as::<bool>("flag").unwrap().values(), &[true, false, true]);
+        assert_eq!(read.column_as::<String>("name").unwrap().values()[1], "q\"");
+        assert_eq!(read.column_as::<Option<String>>("initial").unwrap().values()[2], Some(String::from("z")));
+
+        let err = fixtures::id_score_flag_name_initial().to_sql("my \"table\"", &conn).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn from_sql_inferred() {
+        let conn = Connection::open_in_memory().unwrap();
+
+        let read = Frame::from_sql("SELECT 1 AS a, 2.5 AS b, 'x' AS c, NULL AS d UNION ALL SELECT 2, 3, 'y', 4", &conn).unwrap();
+
+        assert_eq!(
+            read.schema().fields().iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["a: I64", "b: F64", "c: Str", "d: Option<I64>"],
+        );
+        assert_eq!(read.column_as::<f64>("b").unwrap().values(), &[2.5, 3.0]);
+
+        conn.execute_batch("CREATE TABLE t (n INTEGER, v REAL); INSERT INTO t VALUES ('text', 1);").unwrap();
+        let read = Frame::from_sql("SELECT * FROM t WHERE v > 1", &conn).unwrap();
+
+        assert_eq!(read.shape(), (0, 2));
+        assert_eq!(read.column("n").unwrap().dtype(), DType::I64);
+        assert_eq!(read.column("v").unwrap().dtype(), DType::F64);
+
+        let read = Frame::from_sql("SELECT * FROM t", &conn).unwrap();
+        assert_eq!(read.column_as::<String>("n").unwrap().values(), &[String::from("text")]);
+    }
+
+    #[test]
+    fn from_sql_invalid() {
+        let conn = Connection::open_in_memory().unwrap();
+
+        let err = Frame::from_sql("SELECT x'00' AS bytes", &conn).unwrap_err();
+        assert!(matches!(err, InvalidSql::UnsupportedSqlType(UnsupportedSqlType { ref name, .. }) if name == "bytes"));
+
+        let err = Frame::from_sql("SELECT 1 AS a, 2 AS a", &conn).unwrap_err();
+        assert!(matches!(err, InvalidSql::DuplicateColumnName(DuplicateColumnName { ref name }) if name == "a"));
+
+        let err = Frame::from_sql("SELECT * FROM missing", &conn).unwrap_err();
+        assert!(matches!(err, InvalidSql::Sql(_)));
+    }
+
+    #[test]
+    fn to_sql_overflow() {
+        let index = Index::from_iter(0..1);
+        let frame = Frame::from_columns(index.clone(), vec![
+            ("big", Column::from(Series::from_values(index, vec![u64::MAX]).unwrap())),
+        ]).unwrap();
+
+        let conn = Connection::open_in_memory().unwrap();
+        let err = frame.to_sql("t", &conn).unwrap_err();
+        assert!(matches!(err, SqlError::ToSqlConversionFailure(_)));
+
+        // The table is not created if any row fails.
+        assert!(Frame::from_sql("SELECT * FROM t", &conn).is_err());
+    }
+}
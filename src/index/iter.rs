@@ -1,6 +1,8 @@
 
 //! Iterators for use with `Index`.
 
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
 use std::iter::Chain;
 
 use crate::traits::Label;
@@ -60,24 +62,20 @@ impl<L: Label> ExactSizeIterator for IntoIter<L> {
 }
 
 /// A lazy iterator producing elements in the difference of `Index`s.
-pub struct Difference<'a, L: Label>(Iter<'a, L>, &'a Index<L>);
+pub struct Difference<'a, L: Label, S = RandomState>(Iter<'a, L>, &'a Index<L, S>);
 
-impl<'a, L: Label> Difference<'a, L> {
-    pub(crate) fn new(index_a: &'a Index<L>, index_b: &'a Index<L>) -> Self {
+impl<'a, L: Label, S: BuildHasher> Difference<'a, L, S> {
+    pub(crate) fn new(index_a: &'a Index<L, S>, index_b: &'a Index<L, S>) -> Self {
         Self(index_a.iter(), index_b)
     }
 }
 
-impl<'a, L: Label> Iterator for Difference<'a, L> {
+impl<'a, L: Label, S: BuildHasher> Iterator for Difference<'a, L, S> {
     type Item = &'a L;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(label) = self.0.next() {
-            if !self.1.contains(label) {
-                return Some(label);
-            }
-        }
-        None
+        let other = self.1;
+        self.0.find(|label| !other.contains(*label))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -85,7 +83,7 @@ impl<'a, L: Label> Iterator for Difference<'a, L> {
     }
 }
 
-impl<'a, L: Label> DoubleEndedIterator for Difference<'a, L> {
+impl<'a, L: Label, S: BuildHasher> DoubleEndedIterator for Difference<'a, L, S> {
     fn next_back(&mut self) -> Option<Self::Item> {
         while let Some(label) = self.0.next_back() {
             if !self.1.contains(label) {
@@ -97,15 +95,15 @@ impl<'a, L: Label> DoubleEndedIterator for Difference<'a, L> {
 }
 
 /// A lazy iterator producing elements in the symmetric difference of `Index`s.
-pub struct SymmetricDifference<'a, L: Label>(Chain<Difference<'a, L>, Difference<'a, L>>);
+pub struct SymmetricDifference<'a, L: Label, S = RandomState>(Chain<Difference<'a, L, S>, Difference<'a, L, S>>);
 
-impl<'a, L: Label> SymmetricDifference<'a, L> {
-    pub(crate) fn new(index_a: &'a Index<L>, index_b: &'a Index<L>) -> Self {
+impl<'a, L: Label, S: BuildHasher> SymmetricDifference<'a, L, S> {
+    pub(crate) fn new(index_a: &'a Index<L, S>, index_b: &'a Index<L, S>) -> Self {
         Self(Difference::new(index_a, index_b).chain(Difference::new(index_b, index_a)))
     }
 }
 
-impl<'a, L: Label> Iterator for SymmetricDifference<'a, L> {
+impl<'a, L: Label, S: BuildHasher> Iterator for SymmetricDifference<'a, L, S> {
     type Item = &'a L;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -117,31 +115,27 @@ impl<'a, L: Label> Iterator for SymmetricDifference<'a, L> {
     }
 }
 
-impl<'a, L: Label> DoubleEndedIterator for SymmetricDifference<'a, L> {
+impl<'a, L: Label, S: BuildHasher> DoubleEndedIterator for SymmetricDifference<'a, L, S> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.0.next_back()
     }
 }
 
 /// A lazy iterator producing elements in the intersection of `Index`s.
-pub struct Intersection<'a, L: Label>(Iter<'a, L>, &'a Index<L>);
+pub struct Intersection<'a, L: Label, S = RandomState>(Iter<'a, L>, &'a Index<L, S>);
 
-impl<'a, L: Label> Intersection<'a, L> {
-    pub(crate) fn new(index_a: &'a Index<L>, index_b: &'a Index<L>) -> Self {
+impl<'a, L: Label, S: BuildHasher> Intersection<'a, L, S> {
+    pub(crate) fn new(index_a: &'a Index<L, S>, index_b: &'a Index<L, S>) -> Self {
         Self(index_a.iter(), index_b)
     }
 }
 
-impl<'a, L: Label> Iterator for Intersection<'a, L> {
+impl<'a, L: Label, S: BuildHasher> Iterator for Intersection<'a, L, S> {
     type Item = &'a L;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(label) = self.0.next() {
-            if self.1.contains(label) {
-                return Some(label);
-            }
-        }
-        None
+        let other = self.1;
+        self.0.find(|label| other.contains(*label))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -149,7 +143,7 @@ impl<'a, L: Label> Iterator for Intersection<'a, L> {
     }
 }
 
-impl<'a, L: Label> DoubleEndedIterator for Intersection<'a, L> {
+impl<'a, L: Label, S: BuildHasher> DoubleEndedIterator for Intersection<'a, L, S> {
     fn next_back(&mut self) -> Option<Self::Item> {
         while let Some(label) = self.0.next_back() {
             if self.1.contains(label) {
@@ -161,15 +155,15 @@ impl<'a, L: Label> DoubleEndedIterator for Intersection<'a, L> {
 }
 
 /// A lazy iterator producing elements in the union of `Index`s.
-pub struct Union<'a, L: Label>(Chain<Iter<'a, L>, Difference<'a, L>>);
+pub struct Union<'a, L: Label, S = RandomState>(Chain<Iter<'a, L>, Difference<'a, L, S>>);
 
-impl<'a, L: Label> Union<'a, L> {
-    pub(crate) fn new(index_a: &'a Index<L>, index_b: &'a Index<L>) -> Self {
+impl<'a, L: Label, S: BuildHasher> Union<'a, L, S> {
+    pub(crate) fn new(index_a: &'a Index<L, S>, index_b: &'a Index<L, S>) -> Self {
         Self(index_a.iter().chain(Difference::new(index_b, index_a)))
     }
 }
 
-impl<'a, L: Label> Iterator for Union<'a, L> {
+impl<'a, L: Label, S: BuildHasher> Iterator for Union<'a, L, S> {
     type Item = &'a L;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -181,7 +175,7 @@ impl<'a, L: Label> Iterator for Union<'a, L> {
     }
 }
 
-impl<'a, L: Label> DoubleEndedIterator for Union<'a, L> {
+impl<'a, L: Label, S: BuildHasher> DoubleEndedIterator for Union<'a, L, S> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.0.next_back()
     }
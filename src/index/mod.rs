@@ -1,16 +1,36 @@
 
 pub mod iter;
+#[cfg(feature = "serde")]
+mod serde_support;
 
 use std::borrow::Borrow;
+use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
 use std::hash::Hash;
 use std::iter::FromIterator;
+use std::mem::size_of;
+use std::ops::BitAnd;
+use std::ops::BitOr;
+use std::ops::BitXor;
 use std::ops::Bound;
 use std::ops::RangeBounds;
+use std::ops::Sub;
+use std::sync::Arc;
 
 use indexmap::IndexSet;
 use is_sorted::IsSorted;
-
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "rayon")]
+use rayon::iter::IndexedParallelIterator;
+#[cfg(feature = "rayon")]
+use rayon::iter::IntoParallelRefIterator;
+#[cfg(feature = "rayon")]
+use rayon::slice::ParallelSliceMut;
+
+use crate::series::error::DuplicateIndexLabel;
 use crate::traits::Label;
 
 use self::iter::Iter;
@@ -20,8 +40,29 @@ use self::iter::SymmetricDifference;
 use self::iter::Intersection;
 use self::iter::Union;
 
-#[derive(Debug, Clone, Eq)]
-pub struct Index<L>(IndexSet<L>)
+/// Specifies which labels are kept when combining two `Index`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Join {
+    /// Keep only the labels contained in both `Index`s, in left order.
+    Inner,
+    /// Keep all labels from both `Index`s, left labels first.
+    Outer,
+    /// Keep only the labels of the left `Index`.
+    Left,
+    /// Keep only the labels of the right `Index`.
+    Right,
+}
+
+/// A combined `Index` along with a take-map into each of two aligned inputs,
+/// as returned by `Index::align` and `Index::merge_sorted`.
+pub type Alignment<L, S> = (Index<L, S>, Vec<Option<usize>>, Vec<Option<usize>>);
+
+/// An ordered set of unique labels. The hasher used to look up labels can be
+/// customized via the `S` type parameter, which defaults to `RandomState`.
+/// Clones share their labels until one of them is modified, so cloning an
+/// `Index` (e.g. for each column of a `Frame`) is cheap.
+#[derive(Debug, Clone)]
+pub struct Index<L, S = RandomState>(Arc<IndexSet<L, S>>)
 where
     L: Label,
 ;
@@ -35,7 +76,100 @@ where
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(IndexSet::with_capacity(capacity))
+        Self(Arc::new(IndexSet::with_capacity(capacity)))
+    }
+
+    /// Creates a new `Index` from an iterable of labels. If duplicated labels
+    /// are encountered, a `DuplicateIndexLabel` error is returned.
+    pub fn from_iter_checked<I>(iter: I) -> Result<Self, DuplicateIndexLabel<L>>
+    where
+        I: IntoIterator<Item = L>,
+    {
+        let labels = iter.into_iter();
+        let mut index = Self::with_capacity(labels.size_hint().0);
+
+        for label in labels {
+            // Report an error if a duplicated label is found.
+            if index.contains(&label) {
+                return Err(DuplicateIndexLabel { label });
+            }
+
+            index.push(label);
+        }
+
+        Ok(index)
+    }
+
+    /// Encodes an iterable of possibly-repeated labels as integer codes,
+    /// returning the codes along with an `Index` of the unique labels in
+    /// first-seen order. Each code is the position of its label within the
+    /// returned `Index`.
+    pub fn factorize_iter<I>(iter: I) -> (Vec<usize>, Index<L>)
+    where
+        I: IntoIterator<Item = L>,
+    {
+        let labels = iter.into_iter();
+        let mut codes = Vec::with_capacity(labels.size_hint().0);
+        let mut uniques = IndexSet::new();
+
+        for label in labels {
+            let (code, _) = uniques.insert_full(label);
+            codes.push(code);
+        }
+
+        (codes, Self(Arc::new(uniques)))
+    }
+}
+
+impl<L, S> Index<L, S>
+where
+    L: Label,
+    S: BuildHasher,
+{
+    /// Creates a new, empty `Index` that uses the given hasher.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self(Arc::new(IndexSet::with_hasher(hash_builder)))
+    }
+
+    /// Creates a new, empty `Index` with the given capacity that uses the given
+    /// hasher.
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self(Arc::new(IndexSet::with_capacity_and_hasher(capacity, hash_builder)))
+    }
+
+    /// Returns a reference to the hasher used by this `Index`.
+    pub fn hasher(&self) -> &S {
+        self.0.hasher()
+    }
+
+    /// Returns the labels of this `Index` for modification, first copying them
+    /// if they are shared with a clone.
+    fn labels_mut(&mut self) -> &mut IndexSet<L, S>
+    where
+        S: Clone,
+    {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Creates a new, empty `Index` that uses a clone of the hasher of this
+    /// `Index`.
+    fn empty_like(&self, capacity: usize) -> Self
+    where
+        S: Clone,
+    {
+        Self::with_capacity_and_hasher(capacity, self.hasher().clone())
+    }
+
+    /// Collects labels into a new `Index` that uses a clone of the hasher of
+    /// this `Index`.
+    fn collect_like<I>(&self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        S: Clone,
+    {
+        let mut index = self.empty_like(0);
+        index.extend(iter);
+        index
     }
 
     pub fn len(&self) -> usize {
@@ -50,18 +184,126 @@ where
         self.0.capacity()
     }
 
-    pub fn clear(&mut self) {
-        self.0.clear()
+    /// Returns an estimate of the number of bytes used to store the labels of
+    /// this `Index`, including the hash table used to look them up. If `deep`
+    /// is `true`, the heap contents of the labels (e.g. of `String`s) are also
+    /// included.
+    pub fn memory_usage(&self, deep: bool) -> usize {
+        // Each entry stores its label and hash, and the hash table stores the
+        // position of each entry.
+        let entry_size = size_of::<L>() + 2 * size_of::<usize>();
+        let shallow = self.capacity() * entry_size;
+
+        if deep { shallow + self.iter().map(L::heap_size).sum::<usize>() } else { shallow }
+    }
+
+    pub fn clear(&mut self)
+    where
+        S: Clone,
+    {
+        self.labels_mut().clear()
+    }
+
+    pub fn push(&mut self, key: L) -> bool
+    where
+        S: Clone,
+    {
+        self.labels_mut().insert(key)
+    }
+
+    /// Reserves capacity for at least `additional` more labels.
+    pub fn reserve(&mut self, additional: usize)
+    where
+        S: Clone,
+    {
+        self.labels_mut().reserve(additional)
+    }
+
+    /// Inserts a label at the given position, shifting all following labels up
+    /// by one. Returns `false` and leaves this `Index` unchanged if the label
+    /// is already present.
+    ///
+    /// Panics if `pos > len`.
+    pub fn insert_at(&mut self, pos: usize, label: L) -> bool
+    where
+        S: Clone,
+    {
+        assert!(pos <= self.len(), "insertion position {} out of bounds for length {}", pos, self.len());
+
+        let labels = self.labels_mut();
+        if !labels.insert(label) { return false; }
+
+        labels.move_index(labels.len() - 1, pos);
+        true
+    }
+
+    /// Removes and returns the label at the given position, shifting all
+    /// following labels down by one. Returns `None` if the position is out of
+    /// bounds.
+    pub fn remove_at(&mut self, pos: usize) -> Option<L>
+    where
+        S: Clone,
+    {
+        self.labels_mut().shift_remove_index(pos)
+    }
+
+    /// Removes and returns the label at the given position in O(1) time, by
+    /// swapping it with the last label. Returns `None` if the position is out
+    /// of bounds.
+    pub fn swap_remove_at(&mut self, pos: usize) -> Option<L>
+    where
+        S: Clone,
+    {
+        self.labels_mut().swap_remove_index(pos)
+    }
+
+    /// Shortens this `Index`, keeping the first `len` labels and dropping the
+    /// rest. Has no effect if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize)
+    where
+        S: Clone,
+    {
+        self.labels_mut().truncate(len)
+    }
+
+    /// Consumes this `Index` and splits it into two at the given position.
+    /// The first `Index` contains the labels in `[0, pos)`, and the second
+    /// contains the labels in `[pos, len)`.
+    ///
+    /// Panics if `pos > len`.
+    pub fn split_at(mut self, pos: usize) -> (Self, Self)
+    where
+        S: Clone,
+    {
+        let tail = self.labels_mut().split_off(pos);
+        (self, Self(Arc::new(tail)))
     }
 
-    pub fn push(&mut self, key: L) -> bool {
-        self.0.insert(key)
+    /// Splits this `Index` into two at the given position, keeping the labels
+    /// in `[0, pos)` and returning a new `Index` with the labels in
+    /// `[pos, len)`.
+    ///
+    /// Panics if `pos > len`.
+    pub fn split_off(&mut self, pos: usize) -> Self
+    where
+        S: Clone,
+    {
+        Self(Arc::new(self.labels_mut().split_off(pos)))
     }
 
     pub fn iter(&self) -> Iter<'_, L> {
         Iter(self.0.iter())
     }
 
+    /// Parallel version of `iter`.
+    #[cfg(feature = "rayon")]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &L>
+    where
+        L: Sync,
+    {
+        self.0.par_iter()
+    }
+
     pub fn contains<Q>(&self, label: &Q) -> bool
     where
         L: Borrow<Q>,
@@ -70,22 +312,49 @@ where
         self.0.contains(label)
     }
 
-    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, L> {
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, L, S> {
         Difference::new(self, other)
     }
 
-    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, L> {
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, L, S> {
         SymmetricDifference::new(self, other)
     }
 
-    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, L> {
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, L, S> {
         Intersection::new(self, other)
     }
 
-    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, L> {
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, L, S> {
         Union::new(self, other)
     }
 
+    /// Adds all labels from another `Index` that are not already contained in
+    /// this `Index` in-place, appending them in their original order.
+    pub fn union_with(&mut self, other: &Self)
+    where
+        S: Clone,
+    {
+        self.labels_mut().extend(other.iter().cloned())
+    }
+
+    /// Retains only the labels in this `Index` that are also contained in
+    /// another `Index`, in-place.
+    pub fn intersect_with(&mut self, other: &Self)
+    where
+        S: Clone,
+    {
+        self.retain(|label| other.contains(label))
+    }
+
+    /// Removes all labels from this `Index` that are contained in another
+    /// `Index`, in-place.
+    pub fn difference_with(&mut self, other: &Self)
+    where
+        S: Clone,
+    {
+        self.retain(|label| !other.contains(label))
+    }
+
     fn to_nodule(&self, idx: &usize) -> Option<usize> {
         if idx <= &self.len() { Some(*idx) } else { None }
     }
@@ -113,7 +382,7 @@ where
             .collect::<Option<Vec<_>>>()
     }
 
-    pub fn iloc_range<R>(&self, range: R) -> Option<Vec<&L>>
+    pub(crate) fn range_to_nodules<R>(&self, range: R) -> Option<(usize, usize)>
     where
         R: RangeBounds<usize>,
     {
@@ -130,12 +399,32 @@ where
             Bound::Unbounded => self.len(),
         };
 
-        (start_nodule..close_nodule)
-            .map(|p| self.iloc(p))
-            .collect::<Option<Vec<_>>>()
+        Some((start_nodule, close_nodule))
+    }
+
+    pub fn iloc_range<R>(&self, range: R) -> Option<Vec<&L>>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.iloc_range_iter(range).map(Iterator::collect)
+    }
+
+    /// Lazy version of `iloc_range`, which validates the range bounds up front
+    /// and then yields the labels within the range without allocating.
+    pub fn iloc_range_iter<R>(&self, range: R) -> Option<impl Iterator<Item = &L>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start_nodule, close_nodule) = self.range_to_nodules(range)?;
+
+        Some(
+            self.iter()
+                .skip(start_nodule)
+                .take(close_nodule.saturating_sub(start_nodule))
+        )
     }
 
-    pub fn bloc<'a, I, A>(&self, bools: I) -> Option<Vec<&L>>
+    pub fn bloc<I, A>(&self, bools: I) -> Option<Vec<&L>>
     where
         I: IntoIterator<Item = A>,
         I::IntoIter: ExactSizeIterator,
@@ -171,7 +460,7 @@ where
         labels.into_iter().map(|lbl| self.loc(lbl)).collect()
     }
 
-    pub fn loc_range<'a, R, Q>(&'a self, range: R) -> Option<Vec<&L>>
+    pub(crate) fn label_range_to_pos_range<'a, R, Q>(&self, range: R) -> Option<(Bound<usize>, Bound<usize>)>
     where
         R: RangeBounds<&'a Q>,
         L: Borrow<Q>,
@@ -189,17 +478,179 @@ where
             Bound::Unbounded => Bound::Unbounded,
         };
 
-        self.iloc_range((start_bound, close_bound))
+        Some((start_bound, close_bound))
+    }
+
+    pub fn loc_range<'a, R, Q>(&'a self, range: R) -> Option<Vec<&'a L>>
+    where
+        R: RangeBounds<&'a Q>,
+        L: Borrow<Q>,
+        Q: 'a + Hash + Eq + ?Sized,
+    {
+        self.iloc_range(self.label_range_to_pos_range(range)?)
+    }
+
+    /// Lazy version of `loc_range`, which validates the range bounds up front
+    /// and then yields the labels within the range without allocating.
+    pub fn loc_range_iter<'a, R, Q>(&'a self, range: R) -> Option<impl Iterator<Item = &'a L>>
+    where
+        R: RangeBounds<&'a Q>,
+        L: Borrow<Q>,
+        Q: 'a + Hash + Eq + ?Sized,
+    {
+        self.iloc_range_iter(self.label_range_to_pos_range(range)?)
+    }
+
+    /// Returns the first position whose label does not satisfy the predicate,
+    /// assuming that all labels satisfying it come before all that do not.
+    fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&L) -> bool,
+    {
+        let (mut lo, mut hi) = (0, self.len());
+
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(self.iloc(mid).unwrap()) { lo = mid + 1; } else { hi = mid; }
+        }
+
+        lo
+    }
+
+    /// Selects the labels within a range of label values using binary search.
+    /// Unlike `loc_range`, the endpoints of the range do not need to be present
+    /// in this `Index`. This `Index` must be sorted according to `Ord::cmp`,
+    /// otherwise the result is unspecified.
+    pub fn loc_range_sorted<'a, R, Q>(&'a self, range: R) -> Vec<&'a L>
+    where
+        R: RangeBounds<&'a Q>,
+        L: Borrow<Q>,
+        Q: 'a + Ord + ?Sized,
+    {
+        let start_nodule = match range.start_bound() {
+            Bound::Included(q) => self.partition_point(|l| l.borrow() < *q),
+            Bound::Excluded(q) => self.partition_point(|l| l.borrow() <= *q),
+            Bound::Unbounded => 0,
+        };
+
+        let close_nodule = match range.end_bound() {
+            Bound::Included(q) => self.partition_point(|l| l.borrow() <= *q),
+            Bound::Excluded(q) => self.partition_point(|l| l.borrow() < *q),
+            Bound::Unbounded => self.len(),
+        };
+
+        self.iter()
+            .skip(start_nodule)
+            .take(close_nodule.saturating_sub(start_nodule))
+            .collect()
+    }
+
+    /// Encodes this `Index` as integer codes, returning the codes along with an
+    /// `Index` of the unique labels. Since labels in an `Index` are already
+    /// unique, each code is simply the position of its label.
+    pub fn factorize(&self) -> (Vec<usize>, Index<L>) {
+        Index::factorize_iter(self.iter().cloned())
+    }
+
+    /// Randomly selects `n` positions of this `Index`, with or without
+    /// replacement. Returns `None` if not enough positions are available to
+    /// select from.
+    #[cfg(feature = "rand")]
+    pub(crate) fn sample_positions<R>(&self, n: usize, replace: bool, rng: &mut R) -> Option<Vec<usize>>
+    where
+        R: Rng + ?Sized,
+    {
+        let len = self.len();
+
+        if replace {
+            if len == 0 && n > 0 { return None; }
+
+            Some((0..n).map(|_| rng.gen_range(0, len)).collect())
+        }
+        else {
+            if n > len { return None; }
+
+            Some(rand::seq::index::sample(rng, len, n).into_vec())
+        }
+    }
+
+    /// Randomly selects `n` labels from this `Index` without replacement,
+    /// returning them as a new `Index`. Since labels in an `Index` are unique,
+    /// sampling with replacement is not supported. Returns `None` if `n` is
+    /// greater than the length of this `Index`.
+    #[cfg(feature = "rand")]
+    pub fn sample<R>(&self, n: usize, rng: &mut R) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+        S: Clone,
+    {
+        let positions = self.sample_positions(n, false, rng)?;
+        Some(self.collect_like(positions.into_iter().map(|p| self.iloc(p).unwrap().clone())))
+    }
+
+    /// Randomly selects a fraction of the labels from this `Index` without
+    /// replacement, returning them as a new `Index`. The number of labels to
+    /// select is rounded to the nearest integer. See `sample` for details.
+    #[cfg(feature = "rand")]
+    pub fn sample_frac<R>(&self, frac: f64, rng: &mut R) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+        S: Clone,
+    {
+        self.sample(self.frac_len(frac)?, rng)
+    }
+
+    /// Returns the number of labels making up the given fraction of this
+    /// `Index`, rounded to the nearest integer, or `None` if the fraction is
+    /// negative or `NaN`.
+    #[cfg(feature = "rand")]
+    pub(crate) fn frac_len(&self, frac: f64) -> Option<usize> {
+        if frac.is_nan() || frac < 0.0 { return None; }
+
+        Some((frac * self.len() as f64).round() as usize)
     }
 
     /// Reverses the order of the labels in this `Index` in-place.
-    pub fn reverse(&mut self) {
-        // TODO: Replace with `IndexSet::reverse()` once added.
-        self.0 = self.0.drain(..).rev().collect()
+    pub fn reverse(&mut self)
+    where
+        S: Clone,
+    {
+        self.labels_mut().reverse()
+    }
+
+    /// Rotates the labels in this `Index` in-place, such that the first `n`
+    /// labels move to the end. Rotating by more than the length wraps around.
+    pub fn rotate_left(&mut self, n: usize)
+    where
+        S: Clone,
+    {
+        if self.is_empty() { return; }
+
+        // TODO: Replace with `IndexSet::rotate_left()` if/when available.
+        let n = n % self.len();
+        let labels = self.labels_mut();
+        let tail = labels.split_off(n);
+        let head = std::mem::replace(labels, tail);
+        labels.extend(head);
+    }
+
+    /// Rotates the labels in this `Index` in-place, such that the last `n`
+    /// labels move to the front. Rotating by more than the length wraps around.
+    pub fn rotate_right(&mut self, n: usize)
+    where
+        S: Clone,
+    {
+        if self.is_empty() { return; }
+
+        let len = self.len();
+        self.rotate_left(len - n % len)
     }
 
     /// Sorts this `Index` in-place using `Ord::cmp`.
-    pub fn sort(&mut self) {
+    pub fn sort(&mut self)
+    where
+        S: Clone,
+    {
         self.sort_by(Ord::cmp)
     }
 
@@ -207,8 +658,9 @@ where
     pub fn sort_by<F>(&mut self, compare: F)
     where
         F: FnMut(&L, &L) -> Ordering,
+        S: Clone,
     {
-        self.0.sort_by(compare)
+        self.labels_mut().sort_by(compare)
     }
 
     /// Sorts this `Index` in-place according to a custom key function.
@@ -216,6 +668,7 @@ where
     where
         F: FnMut(&L) -> K,
         K: Ord,
+        S: Clone,
     {
         // TODO: Replace with `IndexSet::sort_by_key` if/when available.
         self.sort_by(|a, b| Ord::cmp(&get_key(a), &get_key(b)))
@@ -225,10 +678,11 @@ where
     where
         F: FnMut(&L, &L) -> Ordering,
     {
+        let labels = self.iter().collect::<Vec<_>>();
         let mut indices = (0..self.len()).collect::<Vec<_>>();
 
         // Sort this vector of indices, using the original labels as a lookup.
-        indices.sort_by(|&a, &b| compare(&self.iloc(a).unwrap(), &self.iloc(b).unwrap()));
+        indices.sort_by(|&a, &b| compare(labels[a], labels[b]));
 
         indices
     }
@@ -258,6 +712,123 @@ where
         self.arg_sort_impl(|a, b| Ord::cmp(&get_key(a), &get_key(b)))
     }
 
+    fn arg_sort_unstable_impl<F>(&self, mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(&L, &L) -> Ordering,
+    {
+        let labels = self.iter().collect::<Vec<_>>();
+        let mut indices = (0..self.len()).collect::<Vec<_>>();
+
+        indices.sort_unstable_by(|&a, &b| compare(labels[a], labels[b]));
+
+        indices
+    }
+
+    /// Sorts this `Index` indirectly by returning the numeric indices in sorted
+    /// ascending order using `Ord::cmp`, without preserving the order of equal
+    /// elements.
+    pub fn arg_sort_unstable(&self) -> Vec<usize> {
+        self.arg_sort_unstable_impl(Ord::cmp)
+    }
+
+    /// Sorts this `Index` indirectly by returning the numeric indices in sorted
+    /// ascending order using a custom comparison function, without preserving
+    /// the order of equal elements.
+    pub fn arg_sort_unstable_by<F>(&self, compare: F) -> Vec<usize>
+    where
+        F: FnMut(&L, &L) -> Ordering,
+    {
+        self.arg_sort_unstable_impl(compare)
+    }
+
+    /// Sorts this `Index` indirectly by returning the numeric indices in sorted
+    /// ascending order using a custom key function, without preserving the
+    /// order of equal elements.
+    pub fn arg_sort_unstable_by_key<F, K>(&self, mut get_key: F) -> Vec<usize>
+    where
+        F: FnMut(&L) -> K,
+        K: Ord,
+    {
+        self.arg_sort_unstable_impl(|a, b| Ord::cmp(&get_key(a), &get_key(b)))
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_arg_sort_impl<F>(&self, compare: F, stable: bool) -> Vec<usize>
+    where
+        L: Sync,
+        F: Fn(&L, &L) -> Ordering + Sync,
+    {
+        let labels = self.iter().collect::<Vec<_>>();
+        let mut indices = (0..self.len()).collect::<Vec<_>>();
+
+        let compare = |&a: &usize, &b: &usize| compare(labels[a], labels[b]);
+
+        if stable { indices.par_sort_by(compare); }
+        else { indices.par_sort_unstable_by(compare); }
+
+        indices
+    }
+
+    /// Parallel version of `arg_sort`.
+    #[cfg(feature = "rayon")]
+    pub fn par_arg_sort(&self) -> Vec<usize>
+    where
+        L: Sync,
+    {
+        self.par_arg_sort_impl(Ord::cmp, true)
+    }
+
+    /// Parallel version of `arg_sort_by`.
+    #[cfg(feature = "rayon")]
+    pub fn par_arg_sort_by<F>(&self, compare: F) -> Vec<usize>
+    where
+        L: Sync,
+        F: Fn(&L, &L) -> Ordering + Sync,
+    {
+        self.par_arg_sort_impl(compare, true)
+    }
+
+    /// Parallel version of `arg_sort_by_key`.
+    #[cfg(feature = "rayon")]
+    pub fn par_arg_sort_by_key<F, K>(&self, get_key: F) -> Vec<usize>
+    where
+        L: Sync,
+        F: Fn(&L) -> K + Sync,
+        K: Ord,
+    {
+        self.par_arg_sort_impl(|a, b| Ord::cmp(&get_key(a), &get_key(b)), true)
+    }
+
+    /// Parallel version of `arg_sort_unstable`.
+    #[cfg(feature = "rayon")]
+    pub fn par_arg_sort_unstable(&self) -> Vec<usize>
+    where
+        L: Sync,
+    {
+        self.par_arg_sort_impl(Ord::cmp, false)
+    }
+
+    /// Parallel version of `arg_sort_unstable_by`.
+    #[cfg(feature = "rayon")]
+    pub fn par_arg_sort_unstable_by<F>(&self, compare: F) -> Vec<usize>
+    where
+        L: Sync,
+        F: Fn(&L, &L) -> Ordering + Sync,
+    {
+        self.par_arg_sort_impl(compare, false)
+    }
+
+    /// Parallel version of `arg_sort_unstable_by_key`.
+    #[cfg(feature = "rayon")]
+    pub fn par_arg_sort_unstable_by_key<F, K>(&self, get_key: F) -> Vec<usize>
+    where
+        L: Sync,
+        F: Fn(&L) -> K + Sync,
+        K: Ord,
+    {
+        self.par_arg_sort_impl(|a, b| Ord::cmp(&get_key(a), &get_key(b)), false)
+    }
+
     /// Returns `true` if this `Index` is sorted according to `Ord::cmp`.
     // TODO: Replace with stdlib `Iterator::is_sorted()` once stabilized.
     pub fn is_sorted(&self) -> bool {
@@ -283,6 +854,100 @@ where
         IsSorted::is_sorted_by_key(&mut self.iter(), |e| get_key(e))
     }
 
+    /// Combines this `Index` with another according to a `Join` mode, returning
+    /// the combined `Index` along with a take-map into each input. Each
+    /// take-map has one entry per combined label, holding the position of that
+    /// label in the corresponding input, or `None` if it is not present there.
+    pub fn align(&self, other: &Self, how: Join) -> (Self, Vec<Option<usize>>, Vec<Option<usize>>)
+    where
+        S: Clone,
+    {
+        let combined = match how {
+            Join::Inner => self.collect_like(self.intersection(other).cloned()),
+            Join::Outer => self.collect_like(self.union(other).cloned()),
+            Join::Left => self.clone(),
+            Join::Right => other.clone(),
+        };
+
+        let left_map = combined.iter().map(|l| self.index_of(l)).collect();
+        let right_map = combined.iter().map(|l| other.index_of(l)).collect();
+
+        (combined, left_map, right_map)
+    }
+
+    /// Like `align`, but for two `Index`s that are both sorted according to
+    /// `Ord::cmp`, using a linear merge instead of looking up labels. The
+    /// combined `Index` is also sorted. Returns `None` if either `Index` is
+    /// not sorted.
+    pub fn merge_sorted(&self, other: &Self, how: Join) -> Option<Alignment<L, S>>
+    where
+        S: Clone,
+    {
+        if !self.is_sorted() || !other.is_sorted() { return None; }
+
+        let keep_left = how == Join::Outer || how == Join::Left;
+        let keep_right = how == Join::Outer || how == Join::Right;
+
+        let mut labels = Vec::new();
+        let (mut left_map, mut right_map) = (Vec::new(), Vec::new());
+        let (mut i, mut j) = (0, 0);
+
+        loop {
+            let (lp, rp) = match (self.iloc(i), other.iloc(j)) {
+                (None, None) => break,
+                (Some(_), None) => (Some(i), None),
+                (None, Some(_)) => (None, Some(j)),
+                (Some(l), Some(r)) => match l.cmp(r) {
+                    Ordering::Less => (Some(i), None),
+                    Ordering::Greater => (None, Some(j)),
+                    Ordering::Equal => (Some(i), Some(j)),
+                },
+            };
+
+            if lp.is_some() { i += 1; }
+            if rp.is_some() { j += 1; }
+
+            let keep = match (lp, rp) {
+                (Some(_), Some(_)) => true,
+                (Some(_), None) => keep_left,
+                _ => keep_right,
+            };
+
+            if keep {
+                let label = match lp { Some(p) => self.iloc(p), None => other.iloc(rp.unwrap()) };
+
+                labels.push(label.unwrap().clone());
+                left_map.push(lp);
+                right_map.push(rp);
+            }
+        }
+
+        Some((self.collect_like(labels), left_map, right_map))
+    }
+
+    /// Returns the smallest label in this `Index` according to `Ord::cmp`, or
+    /// `None` if this `Index` is empty.
+    pub fn min(&self) -> Option<&L> {
+        self.iter().min()
+    }
+
+    /// Returns the largest label in this `Index` according to `Ord::cmp`, or
+    /// `None` if this `Index` is empty.
+    pub fn max(&self) -> Option<&L> {
+        self.iter().max()
+    }
+
+    /// Returns the smallest and largest labels in this `Index` in a single
+    /// pass, or `None` if this `Index` is empty.
+    pub fn bounds(&self) -> Option<(&L, &L)> {
+        let mut iter = self.iter();
+        let first = iter.next()?;
+
+        Some(iter.fold((first, first), |(lo, hi), label| {
+            (Ord::min(lo, label), Ord::max(hi, label))
+        }))
+    }
+
     /// Returns `true` if this `Index` has no labels in common with another `Index`.
     pub fn is_disjoint(&self, other: &Self) -> bool {
         Intersection::new(self, other).next().is_none()
@@ -324,17 +989,18 @@ where
     pub fn retain<F>(&mut self, pred: F)
     where
         F: FnMut(&L) -> bool,
+        S: Clone,
     {
-        self.0.retain(pred)
+        self.labels_mut().retain(pred)
     }
 }
 
-impl<L> From<Index<L>> for Vec<L>
+impl<L, S> From<Index<L, S>> for Vec<L>
 where
     L: Label,
 {
-    fn from(index: Index<L>) -> Self {
-        index.0.into_iter().collect()
+    fn from(index: Index<L, S>) -> Self {
+        index.into_iter().collect()
     }
 }
 
@@ -352,7 +1018,7 @@ where
     L: Label,
 {
     fn from_iter<I: IntoIterator<Item = L>>(iter: I) -> Self {
-        Self(iter.into_iter().collect())
+        Self(Arc::new(iter.into_iter().collect()))
     }
 }
 
@@ -361,20 +1027,41 @@ where
     L: 'a + Label + Copy
 {
     fn from_iter<I: IntoIterator<Item = &'a L>>(iter: I) -> Self {
-        Self(iter.into_iter().copied().collect())
+        Self(Arc::new(iter.into_iter().copied().collect()))
+    }
+}
+
+impl<L, S> Extend<L> for Index<L, S>
+where
+    L: Label,
+    S: BuildHasher + Clone,
+{
+    fn extend<I: IntoIterator<Item = L>>(&mut self, iter: I) {
+        self.labels_mut().extend(iter)
+    }
+}
+
+impl<'a, L, S> Extend<&'a L> for Index<L, S>
+where
+    L: 'a + Label + Copy,
+    S: BuildHasher + Clone,
+{
+    fn extend<I: IntoIterator<Item = &'a L>>(&mut self, iter: I) {
+        self.labels_mut().extend(iter)
     }
 }
 
-impl<L> Default for Index<L>
+impl<L, S> Default for Index<L, S>
 where
     L: Label,
+    S: Default,
 {
     fn default() -> Self {
-        Self(IndexSet::new())
+        Self(Arc::new(IndexSet::default()))
     }
 }
 
-impl<L> IntoIterator for Index<L>
+impl<L, S> IntoIterator for Index<L, S>
 where
     L: Label,
 {
@@ -382,16 +1069,98 @@ where
     type IntoIter = IntoIter<L>;
 
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter(self.0.into_iter())
+        match Arc::try_unwrap(self.0) {
+            Ok(labels) => IntoIter(labels.into_iter()),
+            // Labels that are shared with a clone are copied instead.
+            Err(labels) => IntoIter(labels.iter().cloned().collect::<IndexSet<_>>().into_iter()),
+        }
+    }
+}
+
+impl<'a, L> From<&'a Index<L>> for Cow<'a, Index<L>>
+where
+    L: Label,
+{
+    fn from(index: &'a Index<L>) -> Self {
+        Cow::Borrowed(index)
+    }
+}
+
+impl<'a, L> From<Index<L>> for Cow<'a, Index<L>>
+where
+    L: Label,
+{
+    fn from(index: Index<L>) -> Self {
+        Cow::Owned(index)
+    }
+}
+
+impl<L, S1, S2> PartialEq<Index<L, S2>> for Index<L, S1>
+where
+    L: Label,
+    S1: BuildHasher,
+    S2: BuildHasher,
+{
+    fn eq(&self, other: &Index<L, S2>) -> bool {
+        Iterator::eq(self.iter(), other.iter())
+    }
+}
+
+impl<L, S> Eq for Index<L, S>
+where
+    L: Label,
+    S: BuildHasher,
+{}
+
+impl<L, S> BitAnd<&Index<L, S>> for &Index<L, S>
+where
+    L: Label,
+    S: BuildHasher + Clone,
+{
+    type Output = Index<L, S>;
+
+    /// Returns the intersection of two `Index`s as a new `Index`.
+    fn bitand(self, rhs: &Index<L, S>) -> Self::Output {
+        self.collect_like(self.intersection(rhs).cloned())
+    }
+}
+
+impl<L, S> BitOr<&Index<L, S>> for &Index<L, S>
+where
+    L: Label,
+    S: BuildHasher + Clone,
+{
+    type Output = Index<L, S>;
+
+    /// Returns the union of two `Index`s as a new `Index`.
+    fn bitor(self, rhs: &Index<L, S>) -> Self::Output {
+        self.collect_like(self.union(rhs).cloned())
+    }
+}
+
+impl<L, S> BitXor<&Index<L, S>> for &Index<L, S>
+where
+    L: Label,
+    S: BuildHasher + Clone,
+{
+    type Output = Index<L, S>;
+
+    /// Returns the symmetric difference of two `Index`s as a new `Index`.
+    fn bitxor(self, rhs: &Index<L, S>) -> Self::Output {
+        self.collect_like(self.symmetric_difference(rhs).cloned())
     }
 }
 
-impl<L> PartialEq<Index<L>> for Index<L>
+impl<L, S> Sub<&Index<L, S>> for &Index<L, S>
 where
     L: Label,
+    S: BuildHasher + Clone,
 {
-    fn eq(&self, other: &Index<L>) -> bool {
-        Iterator::eq(self.iter(), other.iter())
+    type Output = Index<L, S>;
+
+    /// Returns the difference of two `Index`s as a new `Index`.
+    fn sub(self, rhs: &Index<L, S>) -> Self::Output {
+        self.collect_like(self.difference(rhs).cloned())
     }
 }
 
@@ -434,6 +1203,37 @@ mod tests {
         }
     }
 
+    // `Index::rotate_left` and `Index::rotate_right` should match the behavior
+    // of rotating a `Vec` of the same labels.
+    proptest! {
+        #[test]
+        fn rotate_as_vec_rotate(
+            (labels, n) in
+                LabelGen::ordered::<i32>()
+                .prop_flat_map(|l| {
+                    let n = l.len();
+                    (Just(l), 0..(2 * n + 1))
+                })
+        )
+        {
+            let m = if labels.is_empty() { 0 } else { n % labels.len() };
+
+            let mut expected = labels.clone();
+            expected.rotate_left(m);
+            let mut index = Index::from(labels.clone());
+            Index::rotate_left(&mut index, n);
+            let produced: Vec<_> = index.into();
+            assert_eq!(produced, expected);
+
+            let mut expected = labels.clone();
+            expected.rotate_right(m);
+            let mut index = Index::from(labels);
+            Index::rotate_right(&mut index, n);
+            let produced: Vec<_> = index.into();
+            assert_eq!(produced, expected);
+        }
+    }
+
     // `Index::sort` should produce the same result as `Index::sort_by`
     // called with `Ord::cmp`.
     proptest! {
@@ -508,6 +1308,42 @@ mod tests {
         }
     }
 
+    // Since labels in an `Index` are unique, `Index::arg_sort_unstable` should
+    // produce the same result as `Index::arg_sort`.
+    proptest! {
+        #[test]
+        fn arg_sort_unstable_as_arg_sort(index in IndexGen::index::<i32>()) {
+            assert_eq!(Index::arg_sort_unstable(&index), Index::arg_sort(&index));
+            assert_eq!(
+                Index::arg_sort_unstable_by(&index, |a, b| b.cmp(a)),
+                Index::arg_sort_by(&index, |a, b| b.cmp(a)),
+            );
+            assert_eq!(
+                Index::arg_sort_unstable_by_key(&index, |&l| l),
+                Index::arg_sort_by_key(&index, |&l| l),
+            );
+        }
+    }
+
+    // The parallel `arg_sort` variants should produce the same result as
+    // their sequential counterparts.
+    #[cfg(feature = "rayon")]
+    proptest! {
+        #[test]
+        fn par_arg_sort_as_arg_sort(index in IndexGen::index::<i32>()) {
+            assert_eq!(Index::par_arg_sort(&index), Index::arg_sort(&index));
+            assert_eq!(Index::par_arg_sort_unstable(&index), Index::arg_sort(&index));
+            assert_eq!(
+                Index::par_arg_sort_by(&index, |a, b| b.cmp(a)),
+                Index::arg_sort_by(&index, |a, b| b.cmp(a)),
+            );
+            assert_eq!(
+                Index::par_arg_sort_unstable_by_key(&index, |&l| l),
+                Index::arg_sort_by_key(&index, |&l| l),
+            );
+        }
+    }
+
     // `Index::iloc` should produce `Some(&L)` if the position is in bounds, and
     // `None` otherwise.
     proptest! {
@@ -623,6 +1459,238 @@ mod tests {
         }
     }
 
+    // `Index::union_with` should produce the same result as collecting
+    // `Index::union`.
+    proptest! {
+        #[test]
+        fn union_with_as_union(
+            index_a in IndexGen::index::<i32>(),
+            index_b in IndexGen::index::<i32>(),
+        )
+        {
+            let expected = Index::from_iter(index_a.union(&index_b).copied());
+
+            let mut produced = index_a;
+            Index::union_with(&mut produced, &index_b);
+
+            assert_eq!(produced, expected);
+        }
+    }
+
+    // `Index::intersect_with` should produce the same result as collecting
+    // `Index::intersection`.
+    proptest! {
+        #[test]
+        fn intersect_with_as_intersection(
+            index_a in IndexGen::index::<i32>(),
+            index_b in IndexGen::index::<i32>(),
+        )
+        {
+            let expected = Index::from_iter(index_a.intersection(&index_b).copied());
+
+            let mut produced = index_a;
+            Index::intersect_with(&mut produced, &index_b);
+
+            assert_eq!(produced, expected);
+        }
+    }
+
+    // `Index::difference_with` should produce the same result as collecting
+    // `Index::difference`.
+    proptest! {
+        #[test]
+        fn difference_with_as_difference(
+            index_a in IndexGen::index::<i32>(),
+            index_b in IndexGen::index::<i32>(),
+        )
+        {
+            let expected = Index::from_iter(index_a.difference(&index_b).copied());
+
+            let mut produced = index_a;
+            Index::difference_with(&mut produced, &index_b);
+
+            assert_eq!(produced, expected);
+        }
+    }
+
+    #[test]
+    fn with_hasher() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::BuildHasherDefault;
+
+        type Hasher = BuildHasherDefault<DefaultHasher>;
+
+        let mut a: Index<char, Hasher> = Index::with_hasher(Hasher::default());
+        a.extend("ideographs".chars());
+
+        let mut b: Index<char, Hasher> = Index::with_capacity_and_hasher(8, Hasher::default());
+        b.extend("hydrogen".chars());
+
+        assert!(a.contains(&'g'));
+        assert_eq!(a.index_of(&'g'), Some(4));
+        assert_eq!(a, Index::from_iter("ideographs".chars()));
+
+        assert_eq!(&a & &b, Index::from_iter("deogrh".chars()));
+        assert_eq!(&a - &b, Index::from_iter("iaps".chars()));
+
+        let (combined, _, _) = a.align(&b, Join::Outer);
+        assert_eq!(combined, Index::from_iter("ideographsyn".chars()));
+    }
+
+    #[test]
+    fn set_operators() {
+        let a = Index::from_iter("ideographs".chars());
+        let b = Index::from_iter("hydrogen".chars());
+
+        assert_eq!(&a & &b, Index::from_iter("deogrh".chars()));
+        assert_eq!(&a | &b, Index::from_iter("ideographsyn".chars()));
+        assert_eq!(&a ^ &b, Index::from_iter("iapsyn".chars()));
+        assert_eq!(&a - &b, Index::from_iter("iaps".chars()));
+        assert_eq!(&b - &a, Index::from_iter("yn".chars()));
+    }
+
+    // `Index::factorize` should produce the positions of each label as codes,
+    // along with an identical `Index`.
+    proptest! {
+        #[test]
+        fn factorize_produces_positions(index in IndexGen::index::<i32>()) {
+            let (codes, uniques) = Index::factorize(&index);
+
+            assert_eq!(codes, (0..index.len()).collect::<Vec<_>>());
+            assert_eq!(uniques, index);
+        }
+    }
+
+    #[test]
+    fn factorize_iter() {
+        let (codes, uniques) = Index::factorize_iter("mississippi".chars());
+
+        assert_eq!(codes, vec![0, 1, 2, 2, 1, 2, 2, 1, 3, 3, 1]);
+        assert_eq!(uniques, Index::from_iter("misp".chars()));
+
+        let (codes, uniques) = Index::<char>::factorize_iter(vec![]);
+
+        assert!(codes.is_empty());
+        assert!(uniques.is_empty());
+    }
+
+    // `Index::bounds` should agree with `Index::min` and `Index::max`.
+    proptest! {
+        #[test]
+        fn bounds_as_min_max(index in IndexGen::index::<i32>()) {
+            let expected = index.min().zip(index.max());
+            let produced = Index::bounds(&index);
+
+            assert_eq!(produced, expected);
+        }
+    }
+
+    #[test]
+    fn min_max() {
+        let i = Index::from_iter("ideographs".chars());
+
+        assert_eq!(i.min(), Some(&'a'));
+        assert_eq!(i.max(), Some(&'s'));
+        assert_eq!(i.bounds(), Some((&'a', &'s')));
+
+        let empty: Index<char> = Index::new();
+
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.bounds(), None);
+    }
+
+    // The take-maps produced by `Index::align` should point back to the
+    // combined labels in each input.
+    proptest! {
+        #[test]
+        fn align_take_maps_are_consistent(
+            index_a in IndexGen::index::<i32>(),
+            index_b in IndexGen::index::<i32>(),
+        )
+        {
+            for &how in &[Join::Inner, Join::Outer, Join::Left, Join::Right] {
+                let (combined, left_map, right_map) = Index::align(&index_a, &index_b, how);
+
+                assert_eq!(combined.len(), left_map.len());
+                assert_eq!(combined.len(), right_map.len());
+
+                for (pos, label) in combined.iter().enumerate() {
+                    assert_eq!(left_map[pos].and_then(|p| index_a.iloc(p)), index_a.loc(label));
+                    assert_eq!(right_map[pos].and_then(|p| index_b.iloc(p)), index_b.loc(label));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn align() {
+        let a = Index::from_iter("ideographs".chars());
+        let b = Index::from_iter("hydrogen".chars());
+
+        let (combined, left_map, right_map) = a.align(&b, Join::Inner);
+        assert_eq!(combined, Index::from_iter("deogrh".chars()));
+        assert_eq!(left_map, vec![Some(1), Some(2), Some(3), Some(4), Some(5), Some(8)]);
+        assert_eq!(right_map, vec![Some(2), Some(6), Some(4), Some(5), Some(3), Some(0)]);
+
+        let (combined, left_map, right_map) = a.align(&b, Join::Outer);
+        assert_eq!(combined, Index::from_iter("ideographsyn".chars()));
+        assert_eq!(left_map, (0..10).map(Some).chain(vec![None, None]).collect::<Vec<_>>());
+        assert_eq!(right_map, vec![
+            None, Some(2), Some(6), Some(4), Some(5), Some(3),
+            None, None, Some(0), None, Some(1), Some(7),
+        ]);
+
+        let (combined, left_map, right_map) = a.align(&b, Join::Left);
+        assert_eq!(combined, a);
+        assert_eq!(left_map, (0..10).map(Some).collect::<Vec<_>>());
+        assert_eq!(right_map, vec![
+            None, Some(2), Some(6), Some(4), Some(5), Some(3),
+            None, None, Some(0), None,
+        ]);
+
+        let (combined, left_map, right_map) = a.align(&b, Join::Right);
+        assert_eq!(combined, b);
+        assert_eq!(left_map, vec![Some(8), None, Some(1), Some(5), Some(3), Some(4), Some(2), None]);
+        assert_eq!(right_map, (0..8).map(Some).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let i = Index::from_iter("ideographs".chars());
+
+        let produced = i.sample(4, &mut rng).unwrap();
+        assert_eq!(produced.len(), 4);
+        assert!(produced.is_subset(&i));
+
+        let produced = i.sample(10, &mut rng).unwrap();
+        assert_eq!(produced.len(), 10);
+        assert!(produced.is_subset(&i));
+
+        assert!(i.sample(11, &mut rng).is_none());
+
+        let positions = i.sample_positions(30, true, &mut rng).unwrap();
+        assert_eq!(positions.len(), 30);
+        assert!(positions.iter().all(|&p| p < i.len()));
+
+        let produced = i.sample_frac(0.5, &mut rng).unwrap();
+        assert_eq!(produced.len(), 5);
+        assert!(produced.is_subset(&i));
+
+        assert!(i.sample_frac(1.5, &mut rng).is_none());
+        assert!(i.sample_frac(-0.5, &mut rng).is_none());
+
+        let empty: Index<char> = Index::new();
+        assert_eq!(empty.sample(0, &mut rng), Some(Index::new()));
+        assert_eq!(empty.sample_positions(0, true, &mut rng), Some(vec![]));
+        assert!(empty.sample_positions(1, true, &mut rng).is_none());
+    }
+
     #[test]
     fn iloc() {
         let i = Index::from_iter("ideographs".chars());
@@ -675,6 +1743,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn iloc_range() {
         let i = Index::from_iter("ideographs".chars());
 
@@ -813,6 +1882,229 @@ mod tests {
         assert_eq!(single.iloc_range(2..), None);
     }
 
+    // `Index::from_iter_checked` should succeed for unique labels, producing
+    // the same `Index` as `Index::from_iter`.
+    proptest! {
+        #[test]
+        fn from_iter_checked_unique(labels in LabelGen::ordered::<i32>()) {
+            let expected = Index::from_iter(labels.clone());
+            let produced = Index::from_iter_checked(labels).unwrap();
+
+            assert_eq!(produced, expected);
+        }
+    }
+
+    #[test]
+    fn from_iter_checked() {
+        let produced = Index::from_iter_checked("ideographs".chars());
+        assert_eq!(produced.unwrap(), Index::from_iter("ideographs".chars()));
+
+        let produced = Index::from_iter_checked("mississippi".chars());
+        assert_eq!(produced.unwrap_err().label, 's');
+
+        let produced = Index::<char>::from_iter_checked(vec![]);
+        assert!(produced.unwrap().is_empty());
+    }
+
+    #[test]
+    fn split_off() {
+        let mut i = Index::from_iter("ideographs".chars());
+
+        let tail = i.split_off(4);
+        assert_eq!(i, Index::from_iter("ideo".chars()));
+        assert_eq!(tail, Index::from_iter("graphs".chars()));
+
+        let tail = i.split_off(4);
+        assert_eq!(i, Index::from_iter("ideo".chars()));
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    fn clone_on_write() {
+        let i = Index::from_iter("ideo".chars());
+
+        let mut j = i.clone();
+        assert!(std::ptr::eq(i.iloc(0).unwrap(), j.iloc(0).unwrap()));
+
+        j.push('x');
+        assert_eq!(i, Index::from_iter("ideo".chars()));
+        assert_eq!(j, Index::from_iter("ideox".chars()));
+
+        let k = i.clone();
+        assert_eq!(k.into_iter().collect::<Vec<_>>(), vec!['i', 'd', 'e', 'o']);
+        assert_eq!(i.len(), 4);
+    }
+
+    #[test]
+    fn truncate() {
+        let mut i = Index::from_iter("ideographs".chars());
+
+        i.truncate(42);
+        assert_eq!(i, Index::from_iter("ideographs".chars()));
+
+        i.truncate(4);
+        assert_eq!(i, Index::from_iter("ideo".chars()));
+
+        i.truncate(0);
+        assert!(i.is_empty());
+    }
+
+    #[test]
+    fn insert_at() {
+        let mut i = Index::from_iter("ideo".chars());
+
+        assert!(i.insert_at(1, 'x'));
+        assert_eq!(i, Index::from_iter("ixdeo".chars()));
+
+        assert!(i.insert_at(5, 'y'));
+        assert_eq!(i, Index::from_iter("ixdeoy".chars()));
+
+        assert!(!i.insert_at(0, 'e'));
+        assert_eq!(i, Index::from_iter("ixdeoy".chars()));
+    }
+
+    #[test]
+    fn remove_at() {
+        let mut i = Index::from_iter("ideographs".chars());
+
+        assert_eq!(i.remove_at(2), Some('e'));
+        assert_eq!(i, Index::from_iter("idographs".chars()));
+
+        assert_eq!(i.remove_at(42), None);
+        assert_eq!(i.len(), 9);
+    }
+
+    #[test]
+    fn swap_remove_at() {
+        let mut i = Index::from_iter("ideographs".chars());
+
+        assert_eq!(i.swap_remove_at(1), Some('d'));
+        assert_eq!(i, Index::from_iter("iseograph".chars()));
+
+        assert_eq!(i.swap_remove_at(42), None);
+        assert_eq!(i.len(), 9);
+    }
+
+    #[test]
+    fn split_at() {
+        let i = Index::from_iter("ideographs".chars());
+
+        let (head, tail) = i.clone().split_at(4);
+        assert_eq!(head, Index::from_iter("ideo".chars()));
+        assert_eq!(tail, Index::from_iter("graphs".chars()));
+
+        let (head, tail) = i.clone().split_at(0);
+        assert!(head.is_empty());
+        assert_eq!(tail, i);
+
+        let (head, tail) = i.clone().split_at(i.len());
+        assert_eq!(head, i);
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_bounds() {
+        let i = Index::from_iter("ideographs".chars());
+        let _ = i.split_at(42);
+    }
+
+    // `Index::iloc_range_iter` should yield the same labels as `Index::iloc_range`.
+    proptest! {
+        #[test]
+        fn iloc_range_iter_as_iloc_range(
+            (index, start, close) in
+                IndexGen::index::<i32>()
+                .prop_flat_map(|i| {
+                    let n = i.len();
+                    (Just(i), 0..(n + 2), 0..(n + 2))
+                })
+        )
+        {
+            assert_eq!(
+                index.iloc_range_iter(start..close).map(Iterator::collect),
+                index.iloc_range(start..close),
+            );
+            assert_eq!(
+                index.iloc_range_iter(start..=close).map(Iterator::collect),
+                index.iloc_range(start..=close),
+            );
+            assert_eq!(
+                index.iloc_range_iter(start..).map(Iterator::collect),
+                index.iloc_range(start..),
+            );
+            assert_eq!(
+                index.iloc_range_iter(..close).map(Iterator::collect),
+                index.iloc_range(..close),
+            );
+        }
+    }
+
+    #[test]
+    fn loc_range_iter() {
+        let i = Index::from_iter("ideographs".chars());
+
+        assert_eq!(
+            i.loc_range_iter(&'e'..&'p').map(Iterator::collect::<Vec<_>>),
+            Some(vec![&'e', &'o', &'g', &'r', &'a']),
+        );
+        assert_eq!(
+            i.loc_range_iter(&'e'..=&'p').map(Iterator::collect::<Vec<_>>),
+            Some(vec![&'e', &'o', &'g', &'r', &'a', &'p']),
+        );
+        assert_eq!(
+            i.loc_range_iter(&'r'..&'g').map(Iterator::collect::<Vec<_>>),
+            Some(vec![]),
+        );
+        assert!(i.loc_range_iter(&'i'..&'x').is_none());
+        assert!(i.loc_range_iter(&'x'..).is_none());
+    }
+
+    // `Index::loc_range_sorted` should select the same labels as filtering a
+    // sorted `Index` by value.
+    proptest! {
+        #[test]
+        fn loc_range_sorted_as_filter(
+            index in IndexGen::index::<i8>(),
+            lo in proptest::num::i8::ANY,
+            hi in proptest::num::i8::ANY,
+        )
+        {
+            let mut index = index;
+            index.sort();
+
+            let expected = index.iter().filter(|&&l| lo <= l && l < hi).collect::<Vec<_>>();
+            assert_eq!(index.loc_range_sorted(&lo..&hi), expected);
+
+            let expected = index.iter().filter(|&&l| lo <= l && l <= hi).collect::<Vec<_>>();
+            assert_eq!(index.loc_range_sorted(&lo..=&hi), expected);
+
+            let expected = index.iter().filter(|&&l| lo <= l).collect::<Vec<_>>();
+            assert_eq!(index.loc_range_sorted(&lo..), expected);
+
+            let expected = index.iter().filter(|&&l| l < hi).collect::<Vec<_>>();
+            assert_eq!(index.loc_range_sorted(..&hi), expected);
+        }
+    }
+
+    #[test]
+    fn loc_range_sorted() {
+        let i = Index::from_iter(vec![10, 20, 30, 40, 50]);
+
+        assert_eq!(i.loc_range_sorted(&15..&45), vec![&20, &30, &40]);
+        assert_eq!(i.loc_range_sorted(&20..&40), vec![&20, &30]);
+        assert_eq!(i.loc_range_sorted(&20..=&40), vec![&20, &30, &40]);
+        assert_eq!(i.loc_range_sorted((Bound::Excluded(&20), Bound::Included(&40))), vec![&30, &40]);
+        assert_eq!(i.loc_range_sorted(&0..&100), vec![&10, &20, &30, &40, &50]);
+        assert_eq!(i.loc_range_sorted(..&25), vec![&10, &20]);
+        assert_eq!(i.loc_range_sorted(&35..), vec![&40, &50]);
+        assert_eq!(i.loc_range_sorted(&60..), Vec::<&i32>::new());
+        assert_eq!(i.loc_range_sorted(&45..&15), Vec::<&i32>::new());
+
+        let empty: Index<i32> = Index::new();
+        assert_eq!(empty.loc_range_sorted(..), Vec::<&i32>::new());
+    }
+
     #[test]
     fn loc() {
         let i = Index::from_iter("ideographs".chars());
@@ -864,6 +2156,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_sorted() {
+        let a = Index::from_iter("aceg".chars());
+        let b = Index::from_iter("bcdg".chars());
+
+        let (index, left_map, right_map) = a.merge_sorted(&b, Join::Outer).unwrap();
+        assert_eq!(index, Index::from_iter("abcdeg".chars()));
+        assert_eq!(left_map, vec![Some(0), None, Some(1), None, Some(2), Some(3)]);
+        assert_eq!(right_map, vec![None, Some(0), Some(1), Some(2), None, Some(3)]);
+
+        let (index, left_map, right_map) = a.merge_sorted(&b, Join::Inner).unwrap();
+        assert_eq!(index, Index::from_iter("cg".chars()));
+        assert_eq!(left_map, vec![Some(1), Some(3)]);
+        assert_eq!(right_map, vec![Some(1), Some(3)]);
+
+        let (index, _, right_map) = a.merge_sorted(&b, Join::Left).unwrap();
+        assert_eq!(index, a);
+        assert_eq!(right_map, vec![None, Some(1), None, Some(3)]);
+
+        let (index, left_map, _) = a.merge_sorted(&b, Join::Right).unwrap();
+        assert_eq!(index, b);
+        assert_eq!(left_map, vec![None, Some(1), None, Some(3)]);
+
+        // The results match those of `align` on sorted inputs.
+        for &how in &[Join::Inner, Join::Outer, Join::Left, Join::Right] {
+            let (merged, lm, rm) = a.merge_sorted(&b, how).unwrap();
+            let (mut aligned, _, _) = a.align(&b, how);
+            aligned.sort();
+
+            assert_eq!(merged, aligned);
+            assert_eq!(lm, merged.iter().map(|l| a.index_of(l)).collect::<Vec<_>>());
+            assert_eq!(rm, merged.iter().map(|l| b.index_of(l)).collect::<Vec<_>>());
+        }
+
+        assert!(Index::from_iter("ba".chars()).merge_sorted(&b, Join::Inner).is_none());
+        assert!(a.merge_sorted(&Index::from_iter("ba".chars()), Join::Inner).is_none());
+    }
+
+    #[test]
+    fn memory_usage() {
+        let entry_size = std::mem::size_of::<String>() + 2 * std::mem::size_of::<usize>();
+
+        let mut i = Index::with_capacity(4);
+        i.push(String::from("abc"));
+        i.push(String::from("defgh"));
+
+        let shallow = i.capacity() * entry_size;
+        let heap = i.iter().map(String::capacity).sum::<usize>();
+
+        assert_eq!(i.memory_usage(false), shallow);
+        assert_eq!(i.memory_usage(true), shallow + heap);
+        assert!(heap >= 8);
+
+        let i = Index::<u8>::new();
+        assert_eq!(i.memory_usage(true), 0);
+    }
+
     #[test]
     fn loc_range() {
         let i = Index::from_iter("ideographs".chars());
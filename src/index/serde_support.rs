@@ -0,0 +1,90 @@
+//! Serialization and deserialization of `Index` via `serde`.
+
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::hash::BuildHasher;
+use std::marker::PhantomData;
+
+use serde::de::Deserialize;
+use serde::de::Deserializer;
+use serde::de::Error as DeError;
+use serde::de::SeqAccess;
+use serde::de::Visitor;
+use serde::ser::Serialize;
+use serde::ser::SerializeSeq;
+use serde::ser::Serializer;
+
+use crate::series::error::DuplicateIndexLabel;
+use crate::traits::Label;
+
+use super::Index;
+
+/// Serializes an `Index` as a sequence of its labels, in order.
+impl<L, S> Serialize for Index<L, S>
+where
+    L: Label + Serialize,
+    S: BuildHasher,
+{
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for label in self.iter() { seq.serialize_element(label)?; }
+        seq.end()
+    }
+}
+
+struct IndexVisitor<L>(PhantomData<L>);
+
+impl<'de, L> Visitor<'de> for IndexVisitor<L>
+where
+    L: Label + Deserialize<'de>,
+{
+    type Value = Index<L>;
+
+    fn expecting(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "a sequence of unique index labels")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut index = Index::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(label) = seq.next_element::<L>()? {
+            if index.contains(&label) {
+                return Err(A::Error::custom(DuplicateIndexLabel { label }));
+            }
+
+            index.push(label);
+        }
+
+        Ok(index)
+    }
+}
+
+/// Deserializes an `Index` from a sequence of labels. If a label is repeated,
+/// an error is returned.
+impl<'de, L> Deserialize<'de> for Index<L>
+where
+    L: Label + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(IndexVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    #[test]
+    fn round_trip() {
+        let index = Index::from_iter("bca".chars());
+        let json = serde_json::to_string(&index).unwrap();
+
+        assert_eq!(json, r#"["b","c","a"]"#);
+        assert_eq!(serde_json::from_str::<Index<char>>(&json).unwrap(), index);
+
+        let err = serde_json::from_str::<Index<char>>(r#"["a","b","a"]"#).unwrap_err();
+        assert!(err.to_string().contains("duplicate"));
+    }
+}
@@ -1,7 +1,12 @@
+// Allows code generated by `rustable-derive` to refer to `::rustable` from
+// within this crate as well.
+extern crate self as rustable;
+
 pub mod types;
 pub mod traits;
 pub mod series;
 pub mod index;
+pub mod frame;
 #[cfg(test)] pub mod testing;
 
 #[cfg(test)]
@@ -0,0 +1,180 @@
+//! Incremental construction of `Series`.
+
+use crate::index::Index;
+use crate::traits::Label;
+use crate::traits::Storable;
+
+use super::DuplicateIndexLabel;
+use super::DuplicatePolicy;
+use super::Series;
+
+/// Builds a `Series` one label/value pair at a time, handling repeated labels
+/// according to a `DuplicatePolicy`, which defaults to `DuplicatePolicy::Error`.
+pub struct SeriesBuilder<L: Label, V: Storable> {
+    index: Index<L>,
+    values: Vec<V>,
+    policy: DuplicatePolicy<V>,
+    error: Option<DuplicateIndexLabel<L>>,
+}
+
+impl<L, V> SeriesBuilder<L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    /// Creates a new, empty `SeriesBuilder`.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new, empty `SeriesBuilder` with room for `capacity` pairs.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            index: Index::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+            policy: DuplicatePolicy::Error,
+            error: None,
+        }
+    }
+
+    /// Sets how repeated labels are handled.
+    pub fn policy(mut self, policy: DuplicatePolicy<V>) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Reserves room for at least `additional` more pairs.
+    pub fn reserve(&mut self, additional: usize) {
+        self.index.reserve(additional);
+        self.values.reserve(additional);
+    }
+
+    /// Returns the number of unique labels pushed so far.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no pairs have been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Adds a label/value pair. A repeated label keeps the position of its
+    /// first occurrence. With `DuplicatePolicy::Error`, the first repeated
+    /// label is recorded and reported by `finish`, and later pairs are ignored.
+    pub fn push(&mut self, label: L, value: V) -> &mut Self {
+        if self.error.is_some() { return self; }
+
+        let pos = match self.index.index_of(&label) {
+            None => {
+                self.index.push(label);
+                self.values.push(value);
+                return self;
+            },
+            Some(pos) => pos,
+        };
+
+        match self.policy {
+            DuplicatePolicy::Error => { self.error = Some(DuplicateIndexLabel { label }); },
+            DuplicatePolicy::KeepFirst => {},
+            DuplicatePolicy::KeepLast => { self.values[pos] = value; },
+            DuplicatePolicy::Aggregate(ref mut agg) => {
+                // Move the previous value out by swapping in the last value,
+                // then put the aggregate at the end and swap it back into place.
+                let prev = self.values.swap_remove(pos);
+                self.values.push(agg(prev, value));
+
+                let last = self.values.len() - 1;
+                self.values.swap(pos, last);
+            },
+        }
+
+        self
+    }
+
+    /// Consumes this `SeriesBuilder` and returns the built `Series`, or a
+    /// `DuplicateIndexLabel` error if a label was repeated under
+    /// `DuplicatePolicy::Error`.
+    pub fn finish(self) -> Result<Series<L, V>, DuplicateIndexLabel<L>> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(Series::new_inner(self.index, self.values)),
+        }
+    }
+}
+
+impl<L, V> Default for SeriesBuilder<L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L, V> Extend<(L, V)> for SeriesBuilder<L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    /// Pushes each label/value pair, stopping early if an error is recorded.
+    fn extend<I: IntoIterator<Item = (L, V)>>(&mut self, iter: I) {
+        let pairs = iter.into_iter();
+
+        let (lower_len, _) = pairs.size_hint();
+        self.reserve(lower_len);
+
+        for (label, value) in pairs {
+            if self.error.is_some() { break; }
+            self.push(label, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    #[test]
+    fn builder() {
+        let mut builder = SeriesBuilder::with_capacity(2);
+        builder.push('a', 1).push('b', 2);
+        builder.reserve(10);
+        builder.push('c', 3);
+
+        assert_eq!(builder.len(), 3);
+
+        let (index, values) = builder.finish().unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![1, 2, 3]);
+
+        let mut builder = SeriesBuilder::new();
+        builder.push('a', 1).push('a', 2).push('b', 3);
+        assert_eq!(builder.finish().unwrap_err().label, 'a');
+
+        assert!(SeriesBuilder::<char, i32>::default().finish().unwrap().is_empty());
+    }
+
+    #[test]
+    fn builder_policies() {
+        let pairs = vec![('a', 1), ('b', 2), ('c', 3), ('a', 4), ('c', 5), ('a', 6)];
+
+        let mut builder = SeriesBuilder::new().policy(DuplicatePolicy::KeepFirst);
+        builder.extend(pairs.clone());
+        assert_eq!(builder.finish().unwrap().into_values(), vec![1, 2, 3]);
+
+        let mut builder = SeriesBuilder::new().policy(DuplicatePolicy::KeepLast);
+        builder.extend(pairs.clone());
+        assert_eq!(builder.finish().unwrap().into_values(), vec![6, 2, 5]);
+
+        let mut builder = SeriesBuilder::new().policy(DuplicatePolicy::Aggregate(Box::new(|a, b| a + b)));
+        builder.extend(pairs.clone());
+
+        let (index, values) = builder.finish().unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![11, 2, 8]);
+    }
+}
@@ -0,0 +1,153 @@
+//! Type casting for use with `Series`.
+
+use crate::traits::Cast;
+use crate::traits::Label;
+use crate::traits::RawType;
+
+use super::CastFailed;
+use super::Series;
+
+impl<L, R> Series<L, R>
+where
+    L: Label,
+    R: RawType,
+{
+    /// Converts the values in this `Series` to another type, preserving the
+    /// `Index`. Values that fail to convert, or would lose information in the
+    /// conversion, become `None`.
+    pub fn astype<T>(self) -> Series<L, Option<T>>
+    where
+        R: Cast<T>,
+        T: RawType,
+    {
+        self.map(|v| v.cast())
+    }
+
+    /// Converts the values in this `Series` to another type, preserving the
+    /// `Index`. If any value fails to convert, or would lose information in the
+    /// conversion, a `CastFailed` error is returned for the first such value.
+    pub fn try_cast<T>(self) -> Result<Series<L, T>, CastFailed<L>>
+    where
+        R: Cast<T>,
+        T: RawType,
+    {
+        let mut values = Vec::with_capacity(self.1.len());
+
+        for (label, value) in self.iter() {
+            match value.cast() {
+                Some(v) => { values.push(v); },
+                None => {
+                    return Err(CastFailed { label: label.clone(), from: R::dtype(), to: T::dtype() });
+                },
+            }
+        }
+
+        Ok(Series::new_inner(self.0, values))
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: RawType,
+{
+    /// Converts the non-`None` values in this `Series` to another type,
+    /// preserving the `Index`. Values that fail to convert, or would lose
+    /// information in the conversion, become `None`.
+    pub fn astype<T>(self) -> Series<L, Option<T>>
+    where
+        R: Cast<T>,
+        T: RawType,
+    {
+        self.map(|o| o.and_then(|v| v.cast()))
+    }
+
+    /// Converts the non-`None` values in this `Series` to another type,
+    /// preserving the `Index`. If any value fails to convert, or would lose
+    /// information in the conversion, a `CastFailed` error is returned for the
+    /// first such value.
+    pub fn try_cast<T>(self) -> Result<Series<L, Option<T>>, CastFailed<L>>
+    where
+        R: Cast<T>,
+        T: RawType,
+    {
+        let mut values = Vec::with_capacity(self.1.len());
+
+        for (label, value) in self.iter() {
+            match value.as_ref().map(Cast::cast) {
+                None => { values.push(None); },
+                Some(Some(v)) => { values.push(Some(v)); },
+                Some(None) => {
+                    return Err(CastFailed { label: label.clone(), from: R::dtype(), to: T::dtype() });
+                },
+            }
+        }
+
+        Ok(Series::new_inner(self.0, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::types::DType;
+
+    #[test]
+    fn astype() {
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.5), ('c', -3.0), ('d', 1e10)]).unwrap();
+
+        assert_eq!(s.astype::<i32>().into_values(), vec![Some(1), None, Some(-3), None]);
+
+        let s = Series::from_iter_checked(vec![('a', 300), ('b', -1), ('c', 255)]).unwrap();
+
+        assert_eq!(s.astype::<u8>().into_values(), vec![None, None, Some(255)]);
+
+        let s = Series::from_iter_checked(vec![('a', 16_777_217i64), ('b', 16_777_216)]).unwrap();
+
+        assert_eq!(s.astype::<f32>().into_values(), vec![None, Some(16_777_216.0)]);
+
+        let s = Series::from_iter_checked(vec![('a', true), ('b', false)]).unwrap();
+
+        assert_eq!(s.astype::<f64>().into_values(), vec![Some(1.0), Some(0.0)]);
+
+        let s = Series::from_iter_checked(vec![('a', Some(1.5)), ('b', None)]).unwrap();
+
+        assert_eq!(s.astype::<String>().into_values(), vec![Some(String::from("1.5")), None]);
+
+        let s = Series::from_iter_checked(vec![
+            ('a', String::from("42")), ('b', String::from("x")), ('c', String::from("-7")),
+        ]).unwrap();
+
+        assert_eq!(s.astype::<i64>().into_values(), vec![Some(42), None, Some(-7)]);
+    }
+
+    #[test]
+    fn try_cast() {
+        let s = Series::from_iter_checked(vec![('a', 1u64), ('b', 2)]).unwrap();
+
+        assert_eq!(s.try_cast::<i8>().unwrap().into_values(), vec![1, 2]);
+
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', f64::NAN)]).unwrap();
+        let err = s.try_cast::<i32>().unwrap_err();
+
+        assert_eq!(err.label, 'b');
+        assert_eq!(err.from, DType::F64);
+        assert_eq!(err.to, DType::I32);
+
+        let s = Series::from_iter_checked(vec![('a', None), ('b', Some('z'))]).unwrap();
+
+        assert_eq!(s.try_cast::<String>().unwrap().into_values(), vec![None, Some(String::from("z"))]);
+    }
+
+    #[test]
+    fn cast_rules() {
+        assert!(DType::I32.can_cast_to(DType::F64));
+        assert!(DType::F32.can_cast_to(DType::U8));
+        assert!(DType::Bool.can_cast_to(DType::I8));
+        assert!(DType::Char.can_cast_to(DType::Str));
+        assert!(DType::Str.can_cast_to(DType::Bool));
+        assert!(!DType::Char.can_cast_to(DType::I32));
+        assert!(!DType::F64.can_cast_to(DType::Bool));
+    }
+}
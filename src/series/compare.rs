@@ -0,0 +1,118 @@
+//! NaN-aware equality comparisons between `Series`.
+
+use crate::traits::Float;
+use crate::traits::Label;
+use crate::traits::Storable;
+
+use super::Series;
+
+/// Checks if two values are equal, also treating two values that are each
+/// unequal to themselves (i.e. `NaN`s) as equal.
+#[allow(clippy::eq_op)]
+fn nan_eq<V: PartialEq>(a: &V, b: &V) -> bool {
+    a == b || (a != a && b != b)
+}
+
+/// Checks if two floats are within a tolerance of each other, also treating
+/// two `NaN`s as equal.
+fn approx_eq<F: Float>(a: F, b: F, tol: F) -> bool {
+    nan_eq(&a, &b) || (a - b).abs() <= tol
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    /// Checks if this `Series` has the same labels in the same order as
+    /// another `Series`, with equal values. Unlike `==` on the values, `NaN`s
+    /// are considered equal to each other, as are `None`s. Names are ignored.
+    pub fn equals(&self, other: &Self) -> bool
+    where
+        V: PartialEq,
+    {
+        self.0 == other.0
+            && self.1.iter().zip(other.1.iter()).all(|(a, b)| nan_eq(a, b))
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Float,
+{
+    /// Checks if this `Series` has the same labels in the same order as
+    /// another `Series`, with values that differ by at most `tol`. `NaN`s are
+    /// considered equal to each other. Names are ignored.
+    pub fn approx_equals(&self, other: &Self, tol: V) -> bool {
+        self.0 == other.0
+            && self.1.iter().zip(other.1.iter()).all(|(&a, &b)| approx_eq(a, b, tol))
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: Float,
+{
+    /// Checks if this `Series` has the same labels in the same order as
+    /// another `Series`, with values that differ by at most `tol`. `NaN`s are
+    /// considered equal to each other, as are `None`s. Names are ignored.
+    pub fn approx_equals(&self, other: &Self, tol: R) -> bool {
+        self.0 == other.0
+            && self.1.iter().zip(other.1.iter()).all(|(a, b)| match (a, b) {
+                (Some(a), Some(b)) => approx_eq(*a, *b, tol),
+                (None, None) => true,
+                _ => false,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equals() {
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', f64::NAN), ('c', 3.0)]).unwrap();
+
+        let t = Series::from_iter_checked(vec![('a', 1.0), ('b', f64::NAN), ('c', 3.0)]).unwrap();
+        assert!(s.equals(&t));
+
+        let t = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', 3.0)]).unwrap();
+        assert!(!s.equals(&t));
+
+        let t = Series::from_iter_checked(vec![('a', 1.0), ('c', 3.0), ('b', f64::NAN)]).unwrap();
+        assert!(!s.equals(&t));
+
+        let t = Series::from_iter_checked(vec![('a', 1.0), ('b', f64::NAN)]).unwrap();
+        assert!(!s.equals(&t));
+
+        let s = Series::from_iter_checked(vec![('a', Some(f32::NAN)), ('b', None)]).unwrap();
+        let t = Series::from_iter_checked(vec![('a', Some(f32::NAN)), ('b', None)]).unwrap().rename("t");
+        assert!(s.equals(&t));
+
+        let t = Series::from_iter_checked(vec![('a', None), ('b', None)]).unwrap();
+        assert!(!s.equals(&t));
+
+        let s = Series::from_iter_checked(vec![('a', String::from("x"))]).unwrap();
+        assert!(s.equals(&Series::from_iter_checked(vec![('a', String::from("x"))]).unwrap()));
+    }
+
+    #[test]
+    fn approx_equals() {
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', f64::NAN), ('c', f64::INFINITY)]).unwrap();
+
+        let t = Series::from_iter_checked(vec![('a', 1.05), ('b', f64::NAN), ('c', f64::INFINITY)]).unwrap();
+        assert!(s.approx_equals(&t, 0.1));
+        assert!(!s.approx_equals(&t, 0.01));
+
+        let s = Series::from_iter_checked(vec![('a', Some(1.0f32)), ('b', None)]).unwrap();
+
+        let t = Series::from_iter_checked(vec![('a', Some(0.99f32)), ('b', None)]).unwrap();
+        assert!(s.approx_equals(&t, 0.1));
+
+        let t = Series::from_iter_checked(vec![('a', None), ('b', Some(1.0f32))]).unwrap();
+        assert!(!s.approx_equals(&t, 0.1));
+    }
+}
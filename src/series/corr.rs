@@ -0,0 +1,320 @@
+//! Correlation and covariance between `Series`.
+
+use std::cmp::Ordering;
+
+use crate::index::Index;
+use crate::index::Join;
+use crate::traits::Label;
+use crate::traits::Numeric;
+
+use super::Series;
+
+/// Specifies how to compute the correlation between two `Series`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrMethod {
+    /// The Pearson correlation coefficient, measuring linear correlation.
+    Pearson,
+    /// The Spearman rank correlation coefficient, i.e. the Pearson correlation
+    /// between the ranks of the values.
+    Spearman,
+    /// The Kendall rank correlation coefficient (tau-b), based on the number
+    /// of concordant and discordant pairs.
+    Kendall,
+}
+
+/// Aligns two sets of optional values on the labels of their `Index`s, and
+/// returns the pairs of values for labels contained in both. Pairs where
+/// either value is `None` or `NaN` are skipped.
+fn aligned_pairs<L: Label>(
+    l_index: &Index<L>,
+    l_values: &[Option<f64>],
+    r_index: &Index<L>,
+    r_values: &[Option<f64>],
+) -> Vec<(f64, f64)>
+{
+    let (_, left_map, right_map) = l_index.align(r_index, Join::Inner);
+
+    left_map
+        .into_iter()
+        .zip(right_map)
+        .filter_map(|(lp, rp)| Some((l_values[lp?]?, r_values[rp?]?)))
+        .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+        .collect()
+}
+
+/// Pairs each value with the value `lag` positions before it, as if the values
+/// were shifted by `lag` positions and aligned with themselves. Pairs where
+/// either value is `None` or `NaN` are skipped.
+fn lagged_pairs(values: &[Option<f64>], lag: isize) -> Vec<(f64, f64)> {
+    (0..values.len())
+        .filter_map(|p| {
+            let q = (p as isize).checked_sub(lag)?;
+            if q < 0 || q as usize >= values.len() { return None; }
+            Some((values[p]?, values[q as usize]?))
+        })
+        .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+        .collect()
+}
+
+/// Computes the sample covariance of pairs of values, or `None` if there are
+/// fewer than two pairs.
+fn cov_pairs(pairs: &[(f64, f64)]) -> Option<f64> {
+    let n = pairs.len();
+    if n < 2 { return None; }
+
+    let mean_x = pairs.iter().map(|p| p.0).sum::<f64>() / n as f64;
+    let mean_y = pairs.iter().map(|p| p.1).sum::<f64>() / n as f64;
+
+    let co_dev = pairs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum::<f64>();
+
+    Some(co_dev / (n - 1) as f64)
+}
+
+/// Computes the Pearson correlation of pairs of values, or `None` if there
+/// are fewer than two pairs or either side is constant.
+fn pearson_pairs(pairs: &[(f64, f64)]) -> Option<f64> {
+    let n = pairs.len();
+    if n < 2 { return None; }
+
+    let mean_x = pairs.iter().map(|p| p.0).sum::<f64>() / n as f64;
+    let mean_y = pairs.iter().map(|p| p.1).sum::<f64>() / n as f64;
+
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+
+    for (x, y) in pairs {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        sxy += dx * dy;
+        sxx += dx * dx;
+        syy += dy * dy;
+    }
+
+    if sxx == 0.0 || syy == 0.0 { None } else { Some(sxy / (sxx * syy).sqrt()) }
+}
+
+/// Ranks values starting from 1, giving tied values the mean of their ranks.
+fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order = (0..values.len()).collect::<Vec<_>>();
+
+    // All `NaN`s have already been removed, so this comparison is total.
+    order.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap());
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+
+    while start < order.len() {
+        let mut close = start + 1;
+        while close < order.len() && values[order[close]] == values[order[start]] { close += 1; }
+
+        // Ranks are 1-based, so the mean of `start + 1 ..= close` is used.
+        let rank = (start + close + 1) as f64 / 2.0;
+        for &p in &order[start..close] { ranks[p] = rank; }
+
+        start = close;
+    }
+
+    ranks
+}
+
+/// Computes the Spearman correlation of pairs of values.
+fn spearman_pairs(pairs: &[(f64, f64)]) -> Option<f64> {
+    let xs = ranks(&pairs.iter().map(|p| p.0).collect::<Vec<_>>());
+    let ys = ranks(&pairs.iter().map(|p| p.1).collect::<Vec<_>>());
+
+    pearson_pairs(&xs.into_iter().zip(ys).collect::<Vec<_>>())
+}
+
+/// Computes the Kendall tau-b correlation of pairs of values, or `None` if
+/// there are fewer than two pairs or either side is constant.
+fn kendall_pairs(pairs: &[(f64, f64)]) -> Option<f64> {
+    let n = pairs.len();
+    if n < 2 { return None; }
+
+    let (mut concordant, mut discordant) = (0i64, 0i64);
+    let (mut ties_x, mut ties_y) = (0i64, 0i64);
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let ox = pairs[i].0.partial_cmp(&pairs[j].0).unwrap();
+            let oy = pairs[i].1.partial_cmp(&pairs[j].1).unwrap();
+
+            match (ox, oy) {
+                (Ordering::Equal, Ordering::Equal) => { ties_x += 1; ties_y += 1; },
+                (Ordering::Equal, _) => { ties_x += 1; },
+                (_, Ordering::Equal) => { ties_y += 1; },
+                _ if ox == oy => { concordant += 1; },
+                _ => { discordant += 1; },
+            }
+        }
+    }
+
+    let total = (n * (n - 1) / 2) as i64;
+    let denom = ((total - ties_x) as f64 * (total - ties_y) as f64).sqrt();
+
+    if denom == 0.0 { None } else { Some((concordant - discordant) as f64 / denom) }
+}
+
+fn corr_pairs(pairs: &[(f64, f64)], method: CorrMethod) -> Option<f64> {
+    match method {
+        CorrMethod::Pearson => pearson_pairs(pairs),
+        CorrMethod::Spearman => spearman_pairs(pairs),
+        CorrMethod::Kendall => kendall_pairs(pairs),
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Numeric,
+{
+    fn pairs_with(&self, other: &Self) -> Vec<(f64, f64)> {
+        let l_values = self.1.iter().map(|v| Some(v.to_f64())).collect::<Vec<_>>();
+        let r_values = other.1.iter().map(|v| Some(v.to_f64())).collect::<Vec<_>>();
+
+        aligned_pairs(&self.0, &l_values, &other.0, &r_values)
+    }
+
+    /// Computes the correlation between this `Series` and another, aligned on
+    /// their labels. Only labels contained in both are used, and pairs with a
+    /// `NaN` on either side are skipped. Returns `None` if there are fewer
+    /// than two pairs or the correlation is undefined.
+    pub fn corr(&self, other: &Self, method: CorrMethod) -> Option<f64> {
+        corr_pairs(&self.pairs_with(other), method)
+    }
+
+    /// Computes the sample covariance between this `Series` and another,
+    /// aligned on their labels. Only labels contained in both are used, and
+    /// pairs with a `NaN` on either side are skipped. Returns `None` if there
+    /// are fewer than two pairs.
+    pub fn cov(&self, other: &Self) -> Option<f64> {
+        cov_pairs(&self.pairs_with(other))
+    }
+
+    /// Computes the Pearson correlation between this `Series` and itself
+    /// shifted by `lag` positions, as with `shift`. Pairs with a `NaN` on
+    /// either side are skipped. Returns `None` if there are fewer than two
+    /// pairs or the correlation is undefined.
+    pub fn autocorr(&self, lag: isize) -> Option<f64> {
+        let values = self.1.iter().map(|v| Some(v.to_f64())).collect::<Vec<_>>();
+        pearson_pairs(&lagged_pairs(&values, lag))
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: Numeric,
+{
+    fn pairs_with(&self, other: &Self) -> Vec<(f64, f64)> {
+        let l_values = self.1.iter().map(|o| o.map(R::to_f64)).collect::<Vec<_>>();
+        let r_values = other.1.iter().map(|o| o.map(R::to_f64)).collect::<Vec<_>>();
+
+        aligned_pairs(&self.0, &l_values, &other.0, &r_values)
+    }
+
+    /// Computes the correlation between this `Series` and another, aligned on
+    /// their labels. Only labels contained in both are used, and pairs with a
+    /// `None` or `NaN` on either side are skipped. Returns `None` if there are
+    /// fewer than two pairs or the correlation is undefined.
+    pub fn corr(&self, other: &Self, method: CorrMethod) -> Option<f64> {
+        corr_pairs(&self.pairs_with(other), method)
+    }
+
+    /// Computes the sample covariance between this `Series` and another,
+    /// aligned on their labels. Only labels contained in both are used, and
+    /// pairs with a `None` or `NaN` on either side are skipped. Returns `None`
+    /// if there are fewer than two pairs.
+    pub fn cov(&self, other: &Self) -> Option<f64> {
+        cov_pairs(&self.pairs_with(other))
+    }
+
+    /// Computes the Pearson correlation between this `Series` and itself
+    /// shifted by `lag` positions, as with `shift`. Pairs with a `None` or
+    /// `NaN` on either side are skipped. Returns `None` if there are fewer
+    /// than two pairs or the correlation is undefined.
+    pub fn autocorr(&self, lag: isize) -> Option<f64> {
+        let values = self.1.iter().map(|o| o.map(R::to_f64)).collect::<Vec<_>>();
+        pearson_pairs(&lagged_pairs(&values, lag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!((actual - expected).abs() < 1e-12, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn corr_cov() {
+        let x = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4), ('e', 5)]).unwrap();
+        let y = Series::from_iter_checked(vec![('e', 7), ('d', 8), ('c', 3), ('b', 4), ('a', 2)]).unwrap();
+
+        assert_close(x.cov(&y), 3.5);
+        assert_close(x.corr(&y, CorrMethod::Pearson), 3.5 / (2.5f64 * 6.7).sqrt());
+        assert_close(x.corr(&y, CorrMethod::Spearman), 0.8);
+        assert_close(x.corr(&y, CorrMethod::Kendall), 0.6);
+
+        assert_close(x.corr(&x, CorrMethod::Pearson), 1.0);
+
+        let z = Series::from_iter_checked(vec![('a', 1), ('b', 1), ('c', 1)]).unwrap();
+        assert_eq!(x.corr(&z, CorrMethod::Pearson), None);
+        assert_eq!(x.corr(&z, CorrMethod::Kendall), None);
+
+        let w = Series::from_iter_checked(vec![('a', 1), ('z', 2)]).unwrap();
+        assert_eq!(x.cov(&w), None);
+    }
+
+    #[test]
+    fn corr_ties() {
+        let x = Series::from_iter_checked(vec![(0, 1.0), (1, 2.0), (2, 2.0), (3, 3.0)]).unwrap();
+        let y = Series::from_iter_checked(vec![(0, 1.0), (1, 3.0), (2, 2.0), (3, 4.0)]).unwrap();
+
+        // Ranks of `x` are [1, 2.5, 2.5, 4] and ranks of `y` are [1, 3, 2, 4].
+        assert_close(x.corr(&y, CorrMethod::Spearman), 4.5 / (4.5f64 * 5.0).sqrt());
+        assert_close(x.corr(&y, CorrMethod::Kendall), 5.0 / 30.0f64.sqrt());
+    }
+
+    #[test]
+    fn corr_skip_none() {
+        let x = Series::from_iter_checked(vec![
+            ('a', Some(1.0)), ('b', None), ('c', Some(3.0)), ('d', Some(f64::NAN)), ('e', Some(5.0)),
+        ]).unwrap();
+        let y = Series::from_iter_checked(vec![
+            ('a', Some(2.0)), ('b', Some(9.0)), ('c', Some(6.0)), ('d', Some(0.0)), ('e', Some(10.0)),
+        ]).unwrap();
+
+        assert_close(x.cov(&y), 8.0);
+        assert_close(x.corr(&y, CorrMethod::Pearson), 1.0);
+        assert_close(x.corr(&y, CorrMethod::Kendall), 1.0);
+
+        let n = Series::from_iter_checked(vec![('a', None), ('c', Some(1.0))]).unwrap();
+        assert_eq!(x.corr(&n, CorrMethod::Spearman), None);
+    }
+
+    #[test]
+    fn autocorr() {
+        let s = Series::from_iter_checked(vec![(0, 1.0), (1, 2.0), (2, 4.0), (3, 8.0), (4, 16.0)]).unwrap();
+
+        // Pairs are (2, 1), (4, 2), (8, 4), (16, 8), which are perfectly correlated.
+        assert_close(s.autocorr(1), 1.0);
+        assert_close(s.autocorr(-1), 1.0);
+        assert_close(s.autocorr(0), 1.0);
+
+        let s = Series::from_iter_checked(vec![(0, 1), (1, -1), (2, 1), (3, -1), (4, 1)]).unwrap();
+
+        assert_close(s.autocorr(1), -1.0);
+        assert_close(s.autocorr(2), 1.0);
+        assert_eq!(s.autocorr(4), None);
+        assert_eq!(s.autocorr(isize::MIN), None);
+
+        let s = Series::from_iter_checked(vec![
+            (0, Some(1.0)), (1, Some(2.0)), (2, None), (3, Some(4.0)), (4, Some(5.0)), (5, Some(7.0)),
+        ]).unwrap();
+
+        // Pairs are (2, 1), (5, 4), (7, 5).
+        let pairs = [(2.0, 1.0), (5.0, 4.0), (7.0, 5.0)];
+        assert_close(s.autocorr(1), pearson_pairs(&pairs).unwrap());
+    }
+}
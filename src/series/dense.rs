@@ -0,0 +1,226 @@
+//! A possibly-borrowed view of the labels and values of a `Series`.
+
+use std::borrow::Borrow;
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use crate::index::Index;
+use crate::traits::Label;
+use crate::traits::Storable;
+
+use super::error::LengthMismatch;
+use super::iter::Iter;
+use super::Series;
+
+/// A `Series` whose `Index` and values may each be either borrowed or owned.
+/// Borrowed parts are only cloned when this view is mutated or converted into
+/// an owned `Series`.
+#[derive(Debug)]
+pub struct SeriesDense<'a, L: Label, V: Storable>(
+    pub(crate) Cow<'a, Index<L>>,
+    pub(crate) Cow<'a, [V]>,
+);
+
+impl<'a, L, V> SeriesDense<'a, L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    /// Creates a new `SeriesDense` from a borrowed or owned `Index` and
+    /// values. If their lengths differ, a `LengthMismatch` error is returned.
+    pub fn from_values<I, W>(index: I, values: W) -> Result<Self, LengthMismatch<L, V>>
+    where
+        I: Into<Cow<'a, Index<L>>>,
+        W: Into<Cow<'a, [V]>>,
+    {
+        let (index, values) = (index.into(), values.into());
+
+        if index.len() != values.len() {
+            Err(LengthMismatch { index: index.into_owned(), values: values.into_owned() })
+        } else {
+            Ok(Self(index, values))
+        }
+    }
+
+    /// Returns a read-only reference to the `Index` of this `SeriesDense`.
+    pub fn index(&self) -> &Index<L> {
+        &self.0
+    }
+
+    /// Returns a read-only slice of the values of this `SeriesDense`.
+    pub fn values(&self) -> &[V] {
+        &self.1
+    }
+
+    /// Returns the number of label/value pairs in this `SeriesDense`.
+    pub fn len(&self) -> usize {
+        self.1.len()
+    }
+
+    /// Returns `true` if this `SeriesDense` contains no label/value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.1.is_empty()
+    }
+
+    /// Returns `true` if both the `Index` and values are borrowed.
+    pub fn is_borrowed(&self) -> bool {
+        matches!((&self.0, &self.1), (Cow::Borrowed(_), Cow::Borrowed(_)))
+    }
+
+    /// Given a position, returns a read-only reference to its value in the
+    /// `SeriesDense`, if it exists.
+    pub fn iloc(&self, pos: usize) -> Option<&V> {
+        self.1.get(pos)
+    }
+
+    /// Given a label, returns a read-only reference to its value in the
+    /// `SeriesDense`, if it exists.
+    pub fn loc<Q>(&self, label: &Q) -> Option<&V>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.0.index_of(label).and_then(|pos| self.1.get(pos))
+    }
+
+    /// Returns an iterator that yields all label/value pairs in this
+    /// `SeriesDense` in order.
+    pub fn iter(&self) -> Iter<'_, L, V> {
+        Iter::from_parts(&self.0, &self.1)
+    }
+
+    /// Retains only the label/value pairs specified by the predicate.
+    /// The predicate accepts references to a label and a value. The `Index`
+    /// and values are only cloned if any pairs are dropped.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&L, &V) -> bool,
+    {
+        let keep = self.iter().map(|(l, v)| pred(l, v)).collect::<Vec<_>>();
+
+        // Only do work if there are any pairs to drop.
+        if keep.iter().all(|&k| k) { return; }
+
+        let mut p = 0usize;
+        self.0.to_mut().retain(|_| { (keep[p], p += 1).0 });
+
+        let mut p = 0usize;
+        self.1.to_mut().retain(|_| { (keep[p], p += 1).0 });
+    }
+
+    /// Consumes this `SeriesDense` and creates an owned `Series`, cloning the
+    /// `Index` and values if they are borrowed.
+    pub fn into_owned(self) -> Series<L, V> {
+        Series::new_inner(self.0.into_owned(), self.1.into_owned())
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    /// Returns a `SeriesDense` view that borrows the `Index` and values of
+    /// this `Series` without copying them.
+    pub fn as_dense_view(&self) -> SeriesDense<'_, L, V> {
+        SeriesDense(Cow::Borrowed(&self.0), Cow::Borrowed(&self.1))
+    }
+}
+
+impl<L, V> From<Series<L, V>> for SeriesDense<'static, L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    fn from(series: Series<L, V>) -> Self {
+        let (index, values) = series.into_index_values();
+        SeriesDense(Cow::Owned(index), Cow::Owned(values))
+    }
+}
+
+impl<'a, L, V> From<SeriesDense<'a, L, V>> for Series<L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    fn from(dense: SeriesDense<'a, L, V>) -> Self {
+        dense.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::testing::fixtures;
+
+    #[test]
+    fn as_dense_view() {
+        let s = fixtures::ints();
+        let view = s.as_dense_view();
+
+        assert!(view.is_borrowed());
+        assert_eq!(view.len(), 4);
+        assert!(std::ptr::eq(view.values(), s.values()));
+        assert!(std::ptr::eq(view.index(), s.index()));
+
+        assert_eq!(view.iloc(2), Some(&3));
+        assert_eq!(view.iloc(4), None);
+        assert_eq!(view.loc(&'d'), Some(&4));
+        assert_eq!(view.loc(&'z'), None);
+
+        let pairs = view.iter().collect::<Vec<_>>();
+        assert_eq!(pairs, vec![(&'a', &1), (&'b', &2), (&'c', &3), (&'d', &4)]);
+    }
+
+    #[test]
+    fn from_values() {
+        let index = Index::from_iter("abc".chars());
+        let values = vec![1, 2, 3];
+
+        let view = SeriesDense::from_values(&index, &values[..]).unwrap();
+        assert!(view.is_borrowed());
+        assert_eq!(view.loc(&'b'), Some(&2));
+
+        let view = SeriesDense::from_values(index.clone(), values.clone()).unwrap();
+        assert!(!view.is_borrowed());
+
+        let err = SeriesDense::from_values(&index, &values[..2]).unwrap_err();
+        assert_eq!(err.index, index);
+        assert_eq!(err.values, vec![1, 2]);
+    }
+
+    #[test]
+    fn retain() {
+        let s = fixtures::ints();
+
+        let mut view = s.as_dense_view();
+        view.retain(|_, v| *v > 0);
+        assert!(view.is_borrowed());
+
+        view.retain(|l, v| *l != 'a' && v % 2 == 0);
+        assert!(!view.is_borrowed());
+
+        let (index, values) = view.into_owned().into_index_values();
+        assert_eq!(index, Index::from_iter("bd".chars()));
+        assert_eq!(values, vec![2, 4]);
+
+        // The original `Series` is unchanged.
+        assert_eq!(s.values(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn into_owned() {
+        let s = fixtures::ints();
+
+        let (index, values) = Series::from(s.as_dense_view()).into_index_values();
+        assert_eq!(index, Index::from_iter("abcd".chars()));
+        assert_eq!(values, vec![1, 2, 3, 4]);
+
+        let view = SeriesDense::from(fixtures::ints());
+        assert!(!view.is_borrowed());
+        assert_eq!(view.into_owned().into_values(), vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,245 @@
+//! Summary statistics for use with `Series`.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::iter::FromIterator;
+
+use crate::traits::Label;
+use crate::traits::Numeric;
+
+use super::stats::Interpolation;
+use super::Series;
+
+/// Summary statistics of the values in a numeric `Series`, as produced by
+/// `Series::describe`. `None`s and `NaN`s are not included. Statistics that
+/// are undefined for the number of values are `None`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericSummary {
+    pub count: usize,
+    pub mean: Option<f64>,
+    pub std: Option<f64>,
+    pub min: Option<f64>,
+    pub q25: Option<f64>,
+    pub q50: Option<f64>,
+    pub q75: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl NumericSummary {
+    fn from_values(values: Vec<f64>) -> Self {
+        let values = values.into_iter().filter(|v| !v.is_nan()).collect::<Vec<_>>();
+        let s = Series::from_iter((0..values.len()).zip(values));
+
+        Self {
+            count: s.len(),
+            mean: s.mean(),
+            std: s.std(1),
+            min: s.min(),
+            q25: s.quantile(0.25, Interpolation::Linear),
+            q50: s.quantile(0.5, Interpolation::Linear),
+            q75: s.quantile(0.75, Interpolation::Linear),
+            max: s.max(),
+        }
+    }
+
+    fn fields(&self) -> [(&'static str, Option<f64>); 8] {
+        [
+            ("count", Some(self.count as f64)),
+            ("mean", self.mean),
+            ("std", self.std),
+            ("min", self.min),
+            ("25%", self.q25),
+            ("50%", self.q50),
+            ("75%", self.q75),
+            ("max", self.max),
+        ]
+    }
+
+    /// Converts this summary into a `Series` labeled by statistic name.
+    pub fn to_series(&self) -> Series<String, Option<f64>> {
+        Series::from_iter(self.fields().iter().map(|&(name, v)| (name.to_string(), v)))
+    }
+}
+
+impl Display for NumericSummary {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        for (name, v) in self.fields().iter() {
+            match v {
+                Some(v) => writeln!(f, "{:<5}  {}", name, v)?,
+                None => writeln!(f, "{:<5}  None", name)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Summary statistics of the values in a string `Series`, as produced by
+/// `Series::describe`. `None`s are not included. If there are ties for the
+/// most frequent value, `top` is the first one seen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrSummary {
+    pub count: usize,
+    pub unique: usize,
+    pub top: Option<String>,
+    pub freq: usize,
+}
+
+impl StrSummary {
+    fn from_values<'a, I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = &'a String>,
+    {
+        let mut counts: HashMap<&String, (usize, usize)> = HashMap::new();
+        let mut count = 0usize;
+
+        for (p, value) in values.into_iter().enumerate() {
+            counts.entry(value).or_insert((0, p)).0 += 1;
+            count += 1;
+        }
+
+        // Ties are broken by the earliest first-seen position.
+        let top =
+            counts
+            .iter()
+            .max_by(|(_, (ca, pa)), (_, (cb, pb))| ca.cmp(cb).then(pb.cmp(pa)))
+        ;
+
+        Self {
+            count,
+            unique: counts.len(),
+            top: top.map(|(v, _)| (*v).clone()),
+            freq: top.map_or(0, |(_, (c, _))| *c),
+        }
+    }
+
+    /// Converts this summary into a `Series` labeled by statistic name, with
+    /// each statistic formatted as a string.
+    pub fn to_series(&self) -> Series<String, Option<String>> {
+        Series::from_iter(vec![
+            (String::from("count"), Some(self.count.to_string())),
+            (String::from("unique"), Some(self.unique.to_string())),
+            (String::from("top"), self.top.clone()),
+            (String::from("freq"), Some(self.freq.to_string())),
+        ])
+    }
+}
+
+impl Display for StrSummary {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        writeln!(f, "count   {}", self.count)?;
+        writeln!(f, "unique  {}", self.unique)?;
+        writeln!(f, "top     {}", self.top.as_deref().unwrap_or("None"))?;
+        writeln!(f, "freq    {}", self.freq)
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Numeric,
+{
+    /// Returns summary statistics of the values in this `Series`, skipping
+    /// `NaN`s.
+    pub fn describe(&self) -> NumericSummary {
+        NumericSummary::from_values(self.1.iter().map(|v| v.to_f64()).collect())
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: Numeric,
+{
+    /// Returns summary statistics of the non-`None` values in this `Series`,
+    /// skipping `NaN`s.
+    pub fn describe(&self) -> NumericSummary {
+        NumericSummary::from_values(self.1.iter().flatten().map(|v| v.to_f64()).collect())
+    }
+}
+
+impl<L> Series<L, String>
+where
+    L: Label,
+{
+    /// Returns summary statistics of the values in this `Series`.
+    pub fn describe(&self) -> StrSummary {
+        StrSummary::from_values(self.1.iter())
+    }
+}
+
+impl<L> Series<L, Option<String>>
+where
+    L: Label,
+{
+    /// Returns summary statistics of the non-`None` values in this `Series`.
+    pub fn describe(&self) -> StrSummary {
+        StrSummary::from_values(self.1.iter().flatten())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_numeric() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4), ('e', 10)]).unwrap();
+        let summary = s.describe();
+
+        assert_eq!(summary, NumericSummary {
+            count: 5,
+            mean: Some(4.0),
+            std: Some(12.5f64.sqrt()),
+            min: Some(1.0),
+            q25: Some(2.0),
+            q50: Some(3.0),
+            q75: Some(4.0),
+            max: Some(10.0),
+        });
+
+        let (index, values) = summary.to_series().into_index_values();
+        assert_eq!(index.iter().map(String::as_str).collect::<Vec<_>>(), ["count", "mean", "std", "min", "25%", "50%", "75%", "max"]);
+        assert_eq!(values[0], Some(5.0));
+        assert_eq!(values[7], Some(10.0));
+
+        let s = Series::from_iter_checked(vec![('a', Some(2.0)), ('b', None), ('c', Some(f64::NAN))]).unwrap();
+        let summary = s.describe();
+
+        assert_eq!(summary.count, 1);
+        assert_eq!(summary.mean, Some(2.0));
+        assert_eq!(summary.std, None);
+        assert_eq!(summary.q75, Some(2.0));
+
+        let summary = Series::<char, f32>::new().describe();
+        assert_eq!(summary.count, 0);
+        assert_eq!(summary.min, None);
+        assert_eq!(
+            summary.to_string(),
+            "count  0\nmean   None\nstd    None\nmin    None\n25%    None\n50%    None\n75%    None\nmax    None\n",
+        );
+    }
+
+    #[test]
+    fn describe_str() {
+        let s = Series::from_iter_checked(vec![
+            ('a', String::from("y")), ('b', String::from("x")), ('c', String::from("x")), ('d', String::from("y")),
+        ]).unwrap();
+        let summary = s.describe();
+
+        assert_eq!(summary, StrSummary { count: 4, unique: 2, top: Some(String::from("y")), freq: 2 });
+        assert_eq!(summary.to_string(), "count   4\nunique  2\ntop     y\nfreq    2\n");
+        assert_eq!(
+            summary.to_series().into_values(),
+            vec![Some(String::from("4")), Some(String::from("2")), Some(String::from("y")), Some(String::from("2"))],
+        );
+
+        let s = Series::from_iter_checked(vec![('a', None), ('b', Some(String::from("z")))]).unwrap();
+        assert_eq!(s.describe(), StrSummary { count: 1, unique: 1, top: Some(String::from("z")), freq: 1 });
+
+        let summary = Series::<char, String>::new().describe();
+        assert_eq!(summary, StrSummary { count: 0, unique: 0, top: None, freq: 0 });
+    }
+}
@@ -0,0 +1,127 @@
+//! Pretty-printing for `Series`.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use crate::traits::Label;
+use crate::traits::RawType;
+use crate::traits::Storable;
+
+use super::Series;
+
+/// The maximum number of rows to display before truncating the middle rows.
+pub(crate) const MAX_ROWS: usize = 20;
+
+/// The number of rows to display at each end of a truncated `Series` or
+/// `Frame`.
+pub(crate) const END_ROWS: usize = 5;
+
+/// Returns the positions of the rows to display out of `len` rows, with `None`
+/// marking where the middle rows are truncated.
+pub(crate) fn shown_rows(len: usize) -> Vec<Option<usize>> {
+    if len > MAX_ROWS {
+        (0..END_ROWS).map(Some)
+            .chain(Some(None))
+            .chain(((len - END_ROWS)..len).map(Some))
+            .collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+/// Formats a value using its `fmt_value` implementation.
+struct ValueDisplay<'a, V: Storable>(&'a V);
+
+impl<'a, V: Storable> Display for ValueDisplay<'a, V> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        self.0.fmt_value(f)
+    }
+}
+
+/// Formats a label using its `fmt_value` implementation.
+pub(crate) struct LabelDisplay<'a, L: Label>(pub(crate) &'a L);
+
+impl<'a, L: Label> Display for LabelDisplay<'a, L> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        RawType::fmt_value(self.0, f)
+    }
+}
+
+/// Displays a `Series` as two aligned columns of labels and values, followed
+/// by a footer with the name, dtype, and length. Long `Series` only show
+/// their first and last few rows.
+impl<L, V> Display for Series<L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let len = self.len();
+
+        let positions = shown_rows(len);
+
+        let rows =
+            positions
+            .into_iter()
+            .map(|opt_p| match opt_p {
+                Some(p) => (
+                    LabelDisplay(self.0.iloc(p).unwrap()).to_string(),
+                    ValueDisplay(&self.1[p]).to_string(),
+                ),
+                None => (String::from("..."), String::from("...")),
+            })
+            .collect::<Vec<_>>()
+        ;
+
+        let label_width = rows.iter().map(|(l, _)| l.chars().count()).max().unwrap_or(0);
+        let value_width = rows.iter().map(|(_, v)| v.chars().count()).max().unwrap_or(0);
+
+        for (label, value) in rows {
+            writeln!(f, "{:<lw$}    {:>vw$}", label, value, lw = label_width, vw = value_width)?;
+        }
+
+        if let Some(name) = self.name() {
+            write!(f, "name: {}, ", name)?;
+        }
+
+        write!(f, "dtype: {}, length: {}", V::dtype_name(), len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', -22), ('c', 333)]).unwrap();
+        assert_eq!(s.to_string(), "a      1\nb    -22\nc    333\ndtype: I32, length: 3");
+
+        let s = Series::from_iter_checked(vec![
+            (String::from("x"), Some(1.5)), (String::from("long"), None),
+        ]).unwrap().rename("vals");
+        assert_eq!(s.to_string(), "x        1.5\nlong    None\nname: vals, dtype: Option<F64>, length: 2");
+
+        let s = Series::from_iter_checked(vec![(1u8, vec![1, 2]), (2, vec![])]).unwrap();
+        assert_eq!(s.to_string(), "1    [1, 2]\n2        []\ndtype: List<I32>, length: 2");
+
+        let s = Series::<char, bool>::new();
+        assert_eq!(s.to_string(), "dtype: Bool, length: 0");
+    }
+
+    #[test]
+    fn display_truncated() {
+        let s = Series::from_iter_checked((0..100).map(|i| (i, i * 2))).unwrap();
+        let lines = s.to_string().lines().map(String::from).collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2 * END_ROWS + 2);
+        assert_eq!(lines[0], "0        0");
+        assert_eq!(lines[END_ROWS], "...    ...");
+        assert_eq!(lines[2 * END_ROWS], "99     198");
+        assert_eq!(lines[2 * END_ROWS + 1], "dtype: I32, length: 100");
+
+        let s = Series::from_iter_checked((0..MAX_ROWS).map(|i| (i, i))).unwrap();
+        assert_eq!(s.to_string().lines().count(), MAX_ROWS + 1);
+    }
+}
@@ -7,6 +7,7 @@ use std::error::Error;
 use crate::index::Index;
 use crate::traits::Storable;
 use crate::traits::Label;
+use crate::types::DType;
 
 #[derive(Debug)]
 pub struct LengthMismatch<L: Label, V: Storable> {
@@ -46,15 +47,124 @@ impl<L: Label> Error for DuplicateIndexLabel<L> {
 }
 
 #[derive(Debug)]
-pub struct OverlappingIndex;
+pub struct OverlappingIndex<L: Label> {
+    pub labels: Vec<L>,
+}
+
+impl<L: Label> Display for OverlappingIndex<L> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "found overlapping index labels: {:?}", self.labels)
+    }
+}
+
+impl<L: Label> Error for OverlappingIndex<L> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct CastFailed<L: Label> {
+    pub label: L,
+    pub from: DType,
+    pub to: DType,
+}
+
+impl<L: Label> Display for CastFailed<L> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "could not cast value at label {:?} from {:?} to {:?}", self.label, self.from, self.to)
+    }
+}
+
+impl<L: Label> Error for CastFailed<L> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct MissingLabels<L: Label> {
+    pub labels: Vec<L>,
+}
+
+impl<L: Label> Display for MissingLabels<L> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "index labels not found: {:?}", self.labels)
+    }
+}
+
+impl<L: Label> Error for MissingLabels<L> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct OutOfBounds {
+    pub positions: Vec<usize>,
+    pub len: usize,
+}
+
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "positions out of bounds for length {}: {:?}", self.len, self.positions)
+    }
+}
+
+impl Error for OutOfBounds {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidPermutation {
+    pub permutation: Vec<usize>,
+    pub len: usize,
+}
+
+impl Display for InvalidPermutation {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "not a permutation of positions for length {}: {:?}", self.len, self.permutation)
+    }
+}
+
+impl Error for InvalidPermutation {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct UnsortedIndex<L: Label> {
+    pub label: L,
+}
+
+impl<L: Label> Display for UnsortedIndex<L> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "index is not sorted in ascending order at label: {:?}", self.label)
+    }
+}
+
+impl<L: Label> Error for UnsortedIndex<L> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct MisalignedIndex<L: Label> {
+    pub missing: Vec<L>,
+    pub extra: Vec<L>,
+}
 
-impl Display for OverlappingIndex {
+impl<L: Label> Display for MisalignedIndex<L> {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(f, "overlapping index")
+        write!(f, "misaligned index labels: missing {:?}, extra {:?}", self.missing, self.extra)
     }
 }
 
-impl Error for OverlappingIndex {
+impl<L: Label> Error for MisalignedIndex<L> {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         None
     }
@@ -0,0 +1,234 @@
+//! Filling of missing values for use with `Series`.
+
+use std::iter::FromIterator;
+
+use crate::index::Index;
+use crate::traits::Float;
+use crate::traits::Label;
+use crate::traits::Numeric;
+use crate::traits::RawType;
+
+use super::Series;
+use super::UnsortedIndex;
+
+/// Specifies how to fill a missing value from its neighboring values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillMethod {
+    /// Linearly interpolate between the previous and next values.
+    Linear,
+    /// Use the nearer of the previous and next values, preferring the previous
+    /// value if equidistant.
+    Nearest,
+    /// Use the previous value.
+    Pad,
+}
+
+/// Fills the interior `None`s of a slice of values, using the given x-values
+/// to measure the distances between them. `None`s before the first value and
+/// after the last value are left as-is.
+fn interpolate_slice<R>(xs: &[f64], values: &[Option<R>], method: FillMethod) -> Vec<Option<R>>
+where
+    R: Float,
+{
+    let mut filled = values.to_vec();
+    let mut prev: Option<usize> = None;
+
+    for (j, value) in values.iter().enumerate() {
+        if value.is_none() { continue; }
+
+        if let Some(i) = prev {
+            let (vi, vj) = (values[i].unwrap(), values[j].unwrap());
+            let span = xs[j] - xs[i];
+
+            for k in (i + 1)..j {
+                let t = (xs[k] - xs[i]) / span;
+
+                filled[k] = Some(match method {
+                    FillMethod::Linear => vi + (vj - vi) * R::from_f64(t),
+                    FillMethod::Nearest => if t <= 0.5 { vi } else { vj },
+                    FillMethod::Pad => vi,
+                });
+            }
+        }
+
+        prev = Some(j);
+    }
+
+    filled
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: Float,
+{
+    /// Fills the interior missing values of this `Series` using the given
+    /// x-values, and returns the filled pairs as a new dense `Series`. Both
+    /// `None`s and `NaN`s are missing. Missing values before the first value and
+    /// after the last value cannot be filled, so their pairs are dropped.
+    fn interpolate_with(&self, xs: &[f64], method: FillMethod) -> Series<L, R> {
+        let values =
+            self.1
+            .iter()
+            .map(|v| v.filter(|v| !v.to_f64().is_nan()))
+            .collect::<Vec<_>>()
+        ;
+
+        let (labels, values): (Vec<_>, Vec<_>) =
+            self.0
+            .iter()
+            .zip(interpolate_slice(xs, &values, method))
+            .filter_map(|(l, v)| v.map(|v| (l.clone(), v)))
+            .unzip()
+        ;
+
+        Series::new_inner(Index::from_iter(labels), values).named(self.2.clone())
+    }
+
+    /// Fills the interior missing values in this `Series` from their
+    /// neighboring values, treating the values as evenly spaced, and returns a
+    /// new dense `Series`. Both `None`s and `NaN`s are missing. Missing values
+    /// before the first value and after the last value cannot be filled, so
+    /// their pairs are dropped.
+    pub fn interpolate(&self, method: FillMethod) -> Series<L, R> {
+        let xs = (0..self.1.len()).map(|p| p as f64).collect::<Vec<_>>();
+
+        self.interpolate_with(&xs, method)
+    }
+
+    /// Fills the interior missing values in this `Series` from their
+    /// neighboring values, using the numeric labels to measure the distances
+    /// between them, and returns a new dense `Series`. Missing values are
+    /// handled as in `interpolate`. If the labels are not sorted in ascending
+    /// order, an `UnsortedIndex` error is returned with the first label that
+    /// is out of order.
+    pub fn interpolate_by_index(&self, method: FillMethod) -> Result<Series<L, R>, UnsortedIndex<L>>
+    where
+        L: Numeric,
+    {
+        let xs = self.0.iter().map(|l| l.to_f64()).collect::<Vec<_>>();
+
+        if let Some(p) = xs.windows(2).position(|w| w[0] > w[1]) {
+            return Err(UnsortedIndex { label: *self.0.iloc(p + 1).unwrap() });
+        }
+
+        Ok(self.interpolate_with(&xs, method))
+    }
+}
+
+/// Propagates each non-`None` value forward over up to `limit` following
+/// `None`s, or over all of them if `limit` is `None`.
+fn fill_forward<'a, R, I>(values: I, limit: Option<usize>) -> Vec<Option<R>>
+where
+    R: RawType + 'a,
+    I: Iterator<Item = &'a Option<R>>,
+{
+    let mut last: Option<&R> = None;
+    let mut run = 0usize;
+
+    values
+        .map(|value| match value {
+            Some(v) => {
+                last = Some(v);
+                run = 0;
+                Some(v.clone())
+            },
+            None => {
+                run += 1;
+                if limit.is_none_or(|lim| run <= lim) { last.cloned() } else { None }
+            },
+        })
+        .collect()
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: RawType,
+{
+    /// Fills each `None` in this `Series` with the last preceding non-`None`
+    /// value. If `limit` is given, at most that many consecutive `None`s are
+    /// filled after each value. The `Index` is preserved.
+    pub fn ffill(&self, limit: Option<usize>) -> Self {
+        Series::new_inner(self.0.clone(), fill_forward(self.1.iter(), limit)).named(self.2.clone())
+    }
+
+    /// Fills each `None` in this `Series` with the next following non-`None`
+    /// value. If `limit` is given, at most that many consecutive `None`s are
+    /// filled before each value. The `Index` is preserved.
+    pub fn bfill(&self, limit: Option<usize>) -> Self {
+        let mut values = fill_forward(self.1.iter().rev(), limit);
+        values.reverse();
+
+        Series::new_inner(self.0.clone(), values).named(self.2.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate() {
+        let s = Series::from_iter_checked(vec![
+            ('a', None), ('b', Some(1.0)), ('c', None), ('d', Some(f64::NAN)), ('e', Some(4.0)), ('f', None),
+        ]).unwrap();
+
+        let (index, values) = s.interpolate(FillMethod::Linear).into_index_values();
+
+        assert_eq!(index, Index::from_iter("bcde".chars()));
+        assert_eq!(values, vec![1.0, 2.0, 3.0, 4.0]);
+
+        assert_eq!(s.interpolate(FillMethod::Nearest).into_values(), vec![1.0, 1.0, 4.0, 4.0]);
+        assert_eq!(s.interpolate(FillMethod::Pad).into_values(), vec![1.0, 1.0, 1.0, 4.0]);
+
+        let s = Series::from_iter_checked(vec![('a', None), ('b', Some(f64::NAN))]).unwrap();
+
+        assert!(s.interpolate(FillMethod::Linear).is_empty());
+    }
+
+    #[test]
+    fn interpolate_by_index() {
+        let s = Series::from_iter_checked(vec![
+            (0, Some(0.0f32)), (1, None), (4, None), (10, Some(5.0)),
+        ]).unwrap();
+
+        assert_eq!(
+            s.interpolate_by_index(FillMethod::Linear).unwrap().into_values(),
+            vec![0.0, 0.5, 2.0, 5.0],
+        );
+        assert_eq!(
+            s.interpolate_by_index(FillMethod::Nearest).unwrap().into_values(),
+            vec![0.0, 0.0, 0.0, 5.0],
+        );
+
+        let s = Series::from_iter_checked(vec![(0, Some(0.0)), (4, None), (1, None), (10, Some(5.0))]).unwrap();
+
+        assert_eq!(s.interpolate_by_index(FillMethod::Linear).unwrap_err().label, 1);
+    }
+
+    #[test]
+    fn ffill_bfill() {
+        let s = Series::from_iter_checked(vec![
+            ('a', None), ('b', Some('x')), ('c', None), ('d', None), ('e', None), ('f', Some('y')), ('g', None),
+        ]).unwrap();
+
+        let (index, values) = s.ffill(None).into_index_values();
+
+        assert_eq!(index, Index::from_iter("abcdefg".chars()));
+        assert_eq!(values, vec![None, Some('x'), Some('x'), Some('x'), Some('x'), Some('y'), Some('y')]);
+
+        assert_eq!(
+            s.ffill(Some(2)).into_values(),
+            vec![None, Some('x'), Some('x'), Some('x'), None, Some('y'), Some('y')],
+        );
+        assert_eq!(
+            s.bfill(None).into_values(),
+            vec![Some('x'), Some('x'), Some('y'), Some('y'), Some('y'), Some('y'), None],
+        );
+        assert_eq!(
+            s.bfill(Some(1)).into_values(),
+            vec![Some('x'), Some('x'), None, None, Some('y'), Some('y'), None],
+        );
+    }
+}
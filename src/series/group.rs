@@ -0,0 +1,225 @@
+//! Grouping of label/value pairs for use with `Series`.
+
+use std::iter::FromIterator;
+
+use crate::index::Index;
+use crate::traits::Label;
+use crate::traits::Numeric;
+use crate::traits::Storable;
+
+use super::Series;
+
+/// A grouping of the label/value pairs of a `Series` by key, created by
+/// `Series::group_by` or `Series::group_by_series`. Groups are ordered by the
+/// first occurrence of their key, and pairs within each group keep their
+/// original order.
+pub struct GroupBy<'a, K: Label, L: Label, V: Storable> {
+    series: &'a Series<L, V>,
+    keys: Index<K>,
+    groups: Vec<Vec<usize>>,
+}
+
+impl<'a, K, L, V> GroupBy<'a, K, L, V>
+where
+    K: Label,
+    L: Label,
+    V: Storable,
+{
+    /// Creates a new `GroupBy` from an optional key code for each position in
+    /// the `Series`. Positions without a code are excluded from all groups.
+    fn from_codes<I>(series: &'a Series<L, V>, keys: Index<K>, codes: I) -> Self
+    where
+        I: IntoIterator<Item = Option<usize>>,
+    {
+        let mut groups = vec![Vec::new(); keys.len()];
+
+        for (pos, opt_code) in codes.into_iter().enumerate() {
+            if let Some(code) = opt_code { groups[code].push(pos); }
+        }
+
+        Self { series, keys, groups }
+    }
+
+    /// Returns the unique keys of this `GroupBy`, in group order.
+    pub fn keys(&self) -> &Index<K> {
+        &self.keys
+    }
+
+    /// Returns the number of groups.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if there are no groups.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the positions in the original `Series` that belong to each
+    /// group, in group order.
+    pub fn positions(&self) -> &[Vec<usize>] {
+        &self.groups
+    }
+
+    fn sub_series(&self, positions: &[usize]) -> Series<L, V> {
+        let index = Index::from_iter(
+            positions.iter().map(|&p| self.series.0.iloc(p).unwrap().clone())
+        );
+        let values = positions.iter().map(|&p| self.series.1[p].clone()).collect();
+
+        Series::new_inner(index, values)
+    }
+
+    /// Returns the sub-`Series` of the group with the given key, if present.
+    pub fn get(&self, key: &K) -> Option<Series<L, V>> {
+        self.keys.index_of(key).map(|code| self.sub_series(&self.groups[code]))
+    }
+
+    /// Returns an iterator that yields each key along with its sub-`Series`,
+    /// in group order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, Series<L, V>)> + '_ {
+        self.keys
+            .iter()
+            .zip(self.groups.iter())
+            .map(move |(k, positions)| (k, self.sub_series(positions)))
+    }
+
+    /// Applies an aggregation function to each sub-`Series`, and produces a
+    /// new `Series` indexed by group key.
+    pub fn agg<F, T>(&self, mut agg_func: F) -> Series<K, T>
+    where
+        F: FnMut(&Series<L, V>) -> T,
+        T: Storable,
+    {
+        let values = self.groups.iter().map(|p| agg_func(&self.sub_series(p))).collect();
+
+        Series::new_inner(self.keys.clone(), values)
+    }
+
+    /// Counts the number of label/value pairs in each group.
+    pub fn count(&self) -> Series<K, usize> {
+        let values = self.groups.iter().map(Vec::len).collect();
+
+        Series::new_inner(self.keys.clone(), values)
+    }
+}
+
+impl<'a, K, L, V> GroupBy<'a, K, L, V>
+where
+    K: Label,
+    L: Label,
+    V: Numeric,
+{
+    /// Computes the sum of the values in each group.
+    pub fn sum(&self) -> Series<K, V> {
+        self.agg(|s| s.sum())
+    }
+
+    /// Computes the arithmetic mean of the values in each group.
+    pub fn mean(&self) -> Series<K, Option<f64>> {
+        self.agg(|s| s.mean())
+    }
+}
+
+impl<'a, K, L, R> GroupBy<'a, K, L, Option<R>>
+where
+    K: Label,
+    L: Label,
+    R: Numeric,
+{
+    /// Computes the sum of the non-`None` values in each group.
+    pub fn sum(&self) -> Series<K, R> {
+        self.agg(|s| s.sum())
+    }
+
+    /// Computes the arithmetic mean of the non-`None` values in each group.
+    pub fn mean(&self) -> Series<K, Option<f64>> {
+        self.agg(|s| s.mean())
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Storable,
+{
+    /// Groups the label/value pairs of this `Series` by the key produced by
+    /// the given function for each pair.
+    pub fn group_by<K, F>(&self, mut key_func: F) -> GroupBy<'_, K, L, V>
+    where
+        K: Label,
+        F: FnMut(&L, &V) -> K,
+    {
+        let (codes, keys) = Index::factorize_iter(self.iter().map(|(l, v)| key_func(l, v)));
+
+        GroupBy::from_codes(self, keys, codes.into_iter().map(Some))
+    }
+
+    /// Groups the label/value pairs of this `Series` by the values of another
+    /// `Series`, aligned on labels. Labels not found in the other `Series` are
+    /// excluded from all groups.
+    pub fn group_by_series<K>(&self, keys: &Series<L, K>) -> GroupBy<'_, K, L, V>
+    where
+        K: Label + Storable,
+    {
+        let opt_keys = self.0.iter().map(|l| keys.loc(l).cloned()).collect::<Vec<_>>();
+        let (codes, unique_keys) = Index::factorize_iter(opt_keys.iter().flatten().cloned());
+
+        let mut codes = codes.into_iter();
+        let opt_codes = opt_keys.iter().map(|k| k.as_ref().and_then(|_| codes.next()));
+
+        GroupBy::from_codes(self, unique_keys, opt_codes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_by() {
+        let s = Series::from_iter_checked(vec![
+            (1, 10), (2, 20), (3, 30), (4, 40), (5, 50), (6, 60), (7, 70),
+        ]).unwrap();
+
+        let g = s.group_by(|l, _| l % 3);
+
+        assert_eq!(g.len(), 3);
+        assert_eq!(g.keys(), &Index::from_iter(vec![1, 2, 0]));
+        assert_eq!(g.positions(), &[vec![0, 3, 6], vec![1, 4], vec![2, 5]]);
+
+        let (index, values) = g.sum().into_index_values();
+        assert_eq!(index, Index::from_iter(vec![1, 2, 0]));
+        assert_eq!(values, vec![120, 70, 90]);
+
+        assert_eq!(g.mean().into_values(), vec![Some(40.0), Some(35.0), Some(45.0)]);
+        assert_eq!(g.count().into_values(), vec![3, 2, 2]);
+        assert_eq!(g.agg(|sub| sub.max()).into_values(), vec![Some(70), Some(50), Some(60)]);
+
+        let (index, values) = g.get(&2).unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter(vec![2, 5]));
+        assert_eq!(values, vec![20, 50]);
+        assert!(g.get(&3).is_none());
+
+        let groups = g.iter().map(|(k, sub)| (*k, sub.into_values())).collect::<Vec<_>>();
+        assert_eq!(groups, vec![(1, vec![10, 40, 70]), (2, vec![20, 50]), (0, vec![30, 60])]);
+    }
+
+    #[test]
+    fn group_by_series() {
+        let s = Series::from_iter_checked(vec![
+            ('a', Some(1.0)), ('b', Some(2.0)), ('c', None), ('d', Some(4.0)), ('e', Some(5.0)),
+        ]).unwrap();
+        let keys = Series::from_iter_checked(vec![
+            ('e', 'x'), ('a', 'y'), ('b', 'x'), ('c', 'y'), ('z', 'w'),
+        ]).unwrap();
+
+        let g = s.group_by_series(&keys);
+
+        assert_eq!(g.keys(), &Index::from_iter("yx".chars()));
+        assert_eq!(g.positions(), &[vec![0, 2], vec![1, 4]]);
+        assert_eq!(g.sum().into_values(), vec![1.0, 7.0]);
+        assert_eq!(g.mean().into_values(), vec![Some(1.0), Some(3.5)]);
+        assert_eq!(g.count().into_values(), vec![2, 2]);
+    }
+}
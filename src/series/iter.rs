@@ -8,6 +8,7 @@ use super::Series;
 
 use crate::traits::Storable;
 use crate::traits::Label;
+use crate::index::Index;
 use crate::index::iter::Iter as IndexIter;
 use crate::index::iter::IntoIter as IndexIntoIter;
 
@@ -21,7 +22,11 @@ where
     V: Storable,
 {
     pub(crate) fn new(series: &'a Series<L, V>) -> Self {
-        Self(series.0.iter().zip(series.1.iter()))
+        Self::from_parts(&series.0, &series.1)
+    }
+
+    pub(crate) fn from_parts(index: &'a Index<L>, values: &'a [V]) -> Self {
+        Self(index.iter().zip(values.iter()))
     }
 }
 
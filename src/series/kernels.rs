@@ -0,0 +1,142 @@
+//! Aggregation kernels over contiguous slices of numeric values.
+//!
+//! Each kernel keeps `LANES` independent accumulators and processes the slice
+//! in fixed-size chunks, so that there are no dependencies between adjacent
+//! elements. This lets the compiler auto-vectorize the inner loops, which it
+//! cannot do for a plain iterator fold over floats, since reordering float
+//! additions changes the result.
+
+use std::cmp::Ordering;
+
+use crate::traits::Numeric;
+
+/// The number of independent accumulators used by each kernel.
+const LANES: usize = 8;
+
+/// Returns the value if it is comparable with itself, or else zero, so that
+/// values like `NaN` are skipped when summing.
+fn or_zero<N: Numeric>(n: N) -> N {
+    if n.partial_cmp(&n).is_some() { n } else { N::zero() }
+}
+
+/// Sums a slice of values, skipping values that are not comparable with
+/// themselves (e.g. `NaN`). Only floats are summed in lanes, since integer
+/// lanes could overflow on partial sums that a sequential sum never reaches.
+pub(crate) fn sum<N: Numeric>(values: &[N]) -> N {
+    if !N::dtype().is_float() {
+        return values.iter().fold(N::zero(), |t, &n| t + n);
+    }
+
+    let chunks = values.chunks_exact(LANES);
+    let rest = chunks.remainder();
+
+    let mut acc = [N::zero(); LANES];
+    for chunk in chunks {
+        for i in 0..LANES { acc[i] = acc[i] + or_zero(chunk[i]); }
+    }
+
+    let total = acc.iter().fold(N::zero(), |t, &a| t + a);
+    rest.iter().fold(total, |t, &n| t + or_zero(n))
+}
+
+/// Sums a slice of values after converting each to an `f64`, skipping values
+/// that are `NaN`. Returns the sum along with the number of values summed.
+pub(crate) fn sum_count_f64<N: Numeric>(values: &[N]) -> (f64, usize) {
+    let chunks = values.chunks_exact(LANES);
+    let rest = chunks.remainder();
+
+    let mut acc = [0.0f64; LANES];
+    let mut counts = [0usize; LANES];
+    for chunk in chunks {
+        for i in 0..LANES {
+            let v = chunk[i].to_f64();
+            let present = !v.is_nan();
+            acc[i] += if present { v } else { 0.0 };
+            counts[i] += present as usize;
+        }
+    }
+
+    let init = (acc.iter().sum::<f64>(), counts.iter().sum::<usize>());
+    rest.iter().map(|n| n.to_f64()).filter(|v| !v.is_nan()).fold(init, |(t, c), v| (t + v, c + 1))
+}
+
+/// Computes the arithmetic mean of a slice of values, skipping values that are
+/// `NaN`, or `None` if there are no other values.
+pub(crate) fn mean<N: Numeric>(values: &[N]) -> Option<f64> {
+    let (total, count) = sum_count_f64(values);
+    if count == 0 { None } else { Some(total / count as f64) }
+}
+
+/// Finds the most extreme value in the direction of the given `Ordering`.
+/// Values that are not comparable with themselves (e.g. `NaN`) are skipped.
+pub(crate) fn extreme<N: Numeric>(values: &[N], target: Ordering) -> Option<N> {
+    // Seed every lane with the first comparable value. After that, values that
+    // are not comparable never win a comparison, and so are skipped.
+    let start = values.iter().position(|n| n.partial_cmp(n).is_some())?;
+    let values = &values[start..];
+    let seed = values[0];
+
+    let pick = |a: N, n: N| if n.partial_cmp(&a) == Some(target) { n } else { a };
+
+    let chunks = values.chunks_exact(LANES);
+    let rest = chunks.remainder();
+
+    let mut acc = [seed; LANES];
+    for chunk in chunks {
+        for i in 0..LANES { acc[i] = pick(acc[i], chunk[i]); }
+    }
+
+    let best = acc.iter().fold(seed, |a, &n| pick(a, n));
+    Some(rest.iter().fold(best, |a, &n| pick(a, n)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kernels() {
+        let ints = (1..=100).collect::<Vec<i64>>();
+
+        assert_eq!(sum(&ints), 5050);
+
+        let mut bytes = vec![0i8; 2 * LANES];
+        for chunk in bytes.chunks_mut(LANES) {
+            chunk[0] = i8::MAX;
+            chunk[1] = i8::MIN;
+        }
+
+        assert_eq!(sum(&bytes), -2);
+        assert_eq!(sum_count_f64(&ints), (5050.0, 100));
+        assert_eq!(mean(&ints), Some(50.5));
+        assert_eq!(extreme(&ints, Ordering::Less), Some(1));
+        assert_eq!(extreme(&ints, Ordering::Greater), Some(100));
+
+        for len in 0..(3 * LANES) {
+            let floats = (0..len).map(|i| ((i * 7) % 11) as f32 - 5.0).collect::<Vec<_>>();
+
+            assert_eq!(sum(&floats), floats.iter().sum::<f32>());
+            assert_eq!(
+                extreme(&floats, Ordering::Less),
+                floats.iter().copied().fold(None, |m: Option<f32>, n| Some(m.map_or(n, |m| m.min(n)))),
+            );
+            assert_eq!(
+                extreme(&floats, Ordering::Greater),
+                floats.iter().copied().fold(None, |m: Option<f32>, n| Some(m.map_or(n, |m| m.max(n)))),
+            );
+        }
+
+        let mut floats = vec![f64::NAN; 20];
+        floats[3] = 2.0;
+        floats[17] = -1.0;
+
+        assert_eq!(sum(&floats), 1.0);
+        assert_eq!(mean(&floats), Some(0.5));
+        assert_eq!(extreme(&floats, Ordering::Less), Some(-1.0));
+        assert_eq!(extreme(&floats, Ordering::Greater), Some(2.0));
+        assert_eq!(extreme(&[f64::NAN; 9], Ordering::Less), None);
+        assert_eq!(extreme::<u8>(&[], Ordering::Less), None);
+        assert_eq!(mean::<u8>(&[]), None);
+        assert_eq!(mean(&[f32::NAN; 9]), None);
+    }
+}
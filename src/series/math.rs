@@ -0,0 +1,225 @@
+//! Element-wise math methods for use with numeric `Series`.
+
+use crate::traits::Float;
+use crate::traits::Integer;
+use crate::traits::Label;
+use crate::traits::Numeric;
+use crate::traits::Signed;
+
+use super::Series;
+
+/// Rounds a float to the given number of decimal digits.
+fn round_digits<F: Float>(v: F, ndigits: i32) -> F {
+    let scale = F::from_f64(10.0).powi(ndigits);
+    (v * scale).round() / scale
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Numeric,
+{
+    /// Computes the absolute value of each value, preserving the `Index`.
+    pub fn abs(self) -> Self
+    where
+        V: Signed,
+    {
+        self.map(V::abs)
+    }
+
+    /// Rounds each value to the given number of decimal digits, preserving the
+    /// `Index`. A negative `ndigits` rounds to the left of the decimal point.
+    pub fn round(self, ndigits: i32) -> Self
+    where
+        V: Float,
+    {
+        self.map(|v| round_digits(v, ndigits))
+    }
+
+    /// Raises each value to an unsigned integer power, preserving the `Index`.
+    pub fn pow(self, n: u32) -> Self
+    where
+        V: Integer,
+    {
+        self.map(|v| v.pow(n))
+    }
+
+    /// Raises each value to an integer power, preserving the `Index`.
+    pub fn powi(self, n: i32) -> Self
+    where
+        V: Float,
+    {
+        self.map(|v| v.powi(n))
+    }
+
+    /// Raises each value to a floating-point power, preserving the `Index`.
+    pub fn powf(self, n: V) -> Self
+    where
+        V: Float,
+    {
+        self.map(|v| v.powf(n))
+    }
+
+    /// Computes the natural logarithm of each value, preserving the `Index`.
+    pub fn ln(self) -> Self
+    where
+        V: Float,
+    {
+        self.map(V::ln)
+    }
+
+    /// Computes `e^v` for each value `v`, preserving the `Index`.
+    pub fn exp(self) -> Self
+    where
+        V: Float,
+    {
+        self.map(V::exp)
+    }
+
+    /// Computes the square root of each value, preserving the `Index`.
+    /// Negative values produce `NaN`.
+    pub fn sqrt(self) -> Self
+    where
+        V: Float,
+    {
+        self.map(V::sqrt)
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: Numeric,
+{
+    /// Computes the absolute value of each non-`None` value, preserving the
+    /// `Index`.
+    pub fn abs(self) -> Self
+    where
+        R: Signed,
+    {
+        self.map(|o| o.map(R::abs))
+    }
+
+    /// Rounds each non-`None` value to the given number of decimal digits,
+    /// preserving the `Index`. A negative `ndigits` rounds to the left of the
+    /// decimal point.
+    pub fn round(self, ndigits: i32) -> Self
+    where
+        R: Float,
+    {
+        self.map(|o| o.map(|v| round_digits(v, ndigits)))
+    }
+
+    /// Raises each non-`None` value to an unsigned integer power, preserving
+    /// the `Index`.
+    pub fn pow(self, n: u32) -> Self
+    where
+        R: Integer,
+    {
+        self.map(|o| o.map(|v| v.pow(n)))
+    }
+
+    /// Raises each non-`None` value to an integer power, preserving the
+    /// `Index`.
+    pub fn powi(self, n: i32) -> Self
+    where
+        R: Float,
+    {
+        self.map(|o| o.map(|v| v.powi(n)))
+    }
+
+    /// Raises each non-`None` value to a floating-point power, preserving the
+    /// `Index`.
+    pub fn powf(self, n: R) -> Self
+    where
+        R: Float,
+    {
+        self.map(|o| o.map(|v| v.powf(n)))
+    }
+
+    /// Computes the natural logarithm of each non-`None` value, preserving the
+    /// `Index`.
+    pub fn ln(self) -> Self
+    where
+        R: Float,
+    {
+        self.map(|o| o.map(R::ln))
+    }
+
+    /// Computes `e^v` for each non-`None` value `v`, preserving the `Index`.
+    pub fn exp(self) -> Self
+    where
+        R: Float,
+    {
+        self.map(|o| o.map(R::exp))
+    }
+
+    /// Computes the square root of each non-`None` value, preserving the
+    /// `Index`. Negative values produce `NaN`.
+    pub fn sqrt(self) -> Self
+    where
+        R: Float,
+    {
+        self.map(|o| o.map(R::sqrt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+
+    #[test]
+    fn float_math() {
+        let s = Series::from_iter_checked(vec![('a', -1.25), ('b', 4.0), ('c', 123.456)]).unwrap();
+
+        let (index, values) = s.abs().into_index_values();
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![1.25, 4.0, 123.456]);
+
+        let s = Series::from_iter_checked(vec![('a', -1.25), ('b', 4.0), ('c', 123.456)]).unwrap();
+        assert_eq!(s.round(1).into_values(), vec![-1.3, 4.0, 123.5]);
+
+        let s = Series::from_iter_checked(vec![('a', -1.25), ('b', 4.0), ('c', 123.456)]).unwrap();
+        assert_eq!(s.round(-1).into_values(), vec![-0.0, 0.0, 120.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 2.0f32), ('b', 4.0)]).unwrap();
+        assert_eq!(s.powi(2).into_values(), vec![4.0, 16.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 2.0f32), ('b', 4.0)]).unwrap();
+        assert_eq!(s.powf(0.5).into_values(), vec![2.0f32.sqrt(), 2.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 9.0)]).unwrap();
+        assert_eq!(s.sqrt().into_values(), vec![1.0, 3.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 0.0), ('b', 1.0)]).unwrap();
+        assert_eq!(s.exp().ln().into_values(), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn float_math_option() {
+        let s = Series::from_iter_checked(vec![('a', Some(-4.0)), ('b', None), ('c', Some(9.0))]).unwrap();
+
+        let (index, values) = s.abs().sqrt().into_index_values();
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![Some(2.0), None, Some(3.0)]);
+
+        let s = Series::from_iter_checked(vec![('a', Some(1.55)), ('b', None)]).unwrap();
+        assert_eq!(s.round(1).powi(2).into_values(), vec![Some(1.6f64 * 1.6), None]);
+    }
+
+    #[test]
+    fn int_math() {
+        let s = Series::from_iter_checked(vec![('a', -3), ('b', 0), ('c', 2)]).unwrap();
+        assert_eq!(s.abs().pow(3).into_values(), vec![27, 0, 8]);
+
+        let s = Series::from_iter_checked(vec![('a', 3u8), ('b', 2)]).unwrap();
+        assert_eq!(s.pow(2).into_values(), vec![9, 4]);
+
+        let s = Series::from_iter_checked(vec![('a', Some(-3i64)), ('b', None)]).unwrap();
+        assert_eq!(s.abs().pow(2).into_values(), vec![Some(9), None]);
+    }
+}
@@ -1,31 +1,111 @@
 
+pub mod builder;
+pub mod cast;
+pub mod compare;
+pub mod corr;
+pub mod dense;
+pub mod describe;
+pub(crate) mod display;
 pub mod error;
+pub mod fill;
+pub mod group;
 pub mod iter;
+mod kernels;
+pub mod math;
+pub mod ops;
+#[cfg(feature = "rayon")]
+mod par_iter;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+pub mod sparse;
+pub mod stats;
+pub mod strings;
 pub mod values;
+pub mod window;
 
 use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::hash::Hash;
 use std::iter::FromIterator;
+use std::mem::size_of;
+use std::ops::RangeBounds;
 
 use indexmap::IndexMap;
+#[cfg(feature = "rand")]
+use rand::Rng;
 
 use crate::index::Index;
+use crate::index::Join;
+use crate::traits::KeyHash;
 use crate::traits::Storable;
 use crate::traits::Label;
 use crate::traits::RawType;
-
+use crate::types::HashKey;
+
+pub use self::builder::SeriesBuilder;
+pub use self::corr::CorrMethod;
+pub use self::dense::SeriesDense;
+pub use self::describe::NumericSummary;
+pub use self::describe::StrSummary;
+pub use self::error::CastFailed;
 pub use self::error::DuplicateIndexLabel;
+pub use self::error::InvalidPermutation;
 pub use self::error::LengthMismatch;
+pub use self::error::MisalignedIndex;
+pub use self::error::MissingLabels;
+pub use self::error::OutOfBounds;
 pub use self::error::OverlappingIndex;
+pub use self::error::UnsortedIndex;
+pub use self::fill::FillMethod;
+pub use self::group::GroupBy;
+pub use self::sparse::SeriesSparse;
 pub use self::iter::Iter;
 pub use self::iter::IterMut;
 pub use self::iter::IntoIter;
+pub use self::stats::Interpolation;
+pub use self::strings::StrMethods;
+pub use self::window::Decay;
+pub use self::window::Ewm;
+pub use self::window::Expanding;
+pub use self::window::Rolling;
+
+/// Specifies which occurrences of a repeated value count as duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    /// All occurrences except the first are duplicates.
+    First,
+    /// All occurrences except the last are duplicates.
+    Last,
+    /// All occurrences are duplicates, if the value is repeated at all.
+    All,
+}
+
+/// Two `Series` sharing a combined `Index`, as returned by `Series::align` and
+/// `Series::merge_sorted`.
+pub type Aligned<L, R, W> = (Series<L, Option<R>>, Series<L, Option<W>>);
+
+/// Specifies how to handle repeated labels when creating a `Series` from an
+/// iterable of label/value pairs. A repeated label always keeps the position
+/// of its first occurrence.
+pub enum DuplicatePolicy<V> {
+    /// Return a `DuplicateIndexLabel` error.
+    Error,
+    /// Keep the first value seen for the label.
+    KeepFirst,
+    /// Keep the last value seen for the label.
+    KeepLast,
+    /// Combine the value seen so far for the label with the new value using
+    /// the given function, e.g. to sum repeated observations.
+    Aggregate(Box<dyn FnMut(V, V) -> V>),
+}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Series<L: Label, V: Storable>(
     pub(crate) Index<L>,
     pub(crate) Vec<V>,
+    pub(crate) Option<String>,
 );
 
 impl<L, V> Series<L, V>
@@ -43,11 +123,17 @@ where
     }
 
     fn new_inner(index: Index<L>, values: Vec<V>) -> Self {
-        let new = Self(index, values);
+        let new = Self(index, values, None);
         new.assert_len();
         new
     }
 
+    /// Sets the name of this `Series`, for carrying it over from a source.
+    fn named(mut self, name: Option<String>) -> Self {
+        self.2 = name;
+        self
+    }
+
     /// Creates a new `Series` from an iterable of index label/value pairs.
     /// If duplicated index labels are encountered, a `DuplicateIndexLabel`
     /// error is returned.
@@ -83,10 +169,21 @@ where
         Ok(Self::new_inner(index, values))
     }
 
+    /// Creates a new `Series` from an iterable of index label/value pairs,
+    /// handling repeated labels according to the given `DuplicatePolicy`.
+    pub fn from_iter_with_policy<I>(iter: I, policy: DuplicatePolicy<V>) -> Result<Self, DuplicateIndexLabel<L>>
+    where
+        I: IntoIterator<Item = (L, V)>,
+    {
+        let mut builder = SeriesBuilder::new().policy(policy);
+        builder.extend(iter);
+        builder.finish()
+    }
+
     pub fn from_values(index: Index<L>, values: Vec<V>) -> Result<Self, LengthMismatch<L, V>> {
         // Check if the lengths of the index and values are the same.
         if index.len() != values.len() {
-            return Err(LengthMismatch { index, values });
+            Err(LengthMismatch { index, values })
         } else {
             Ok(Self::new_inner(index, values))
         }
@@ -97,6 +194,21 @@ where
         &self.0
     }
 
+    /// Returns the name of this `Series`, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.2.as_deref()
+    }
+
+    /// Sets the name of this `Series`, or removes it if `None`.
+    pub fn set_name(&mut self, name: Option<String>) {
+        self.2 = name;
+    }
+
+    /// Consumes this `Series` and returns it with the given name.
+    pub fn rename<S: Into<String>>(self, name: S) -> Self {
+        self.named(Some(name.into()))
+    }
+
     /// Returns a read-only slice of the values in this `Series`.
     pub fn values(&self) -> &[V] {
         &self.1
@@ -128,6 +240,27 @@ where
         self.1.clear();
     }
 
+    /// Returns the number of label/value pairs in this `Series`.
+    pub fn len(&self) -> usize {
+        self.assert_len();
+        self.1.len()
+    }
+
+    /// Returns an estimate of the number of bytes used to store the `Index`
+    /// and values of this `Series`. If `deep` is `true`, the heap contents of
+    /// the labels and values (e.g. of `String`s) are also included.
+    pub fn memory_usage(&self, deep: bool) -> usize {
+        self.0.memory_usage(deep) + self.values_memory_usage(deep)
+    }
+
+    /// Returns an estimate of the number of bytes used to store the values of
+    /// this `Series`, excluding its `Index`.
+    pub(crate) fn values_memory_usage(&self, deep: bool) -> usize {
+        let values = self.1.capacity() * size_of::<V>();
+
+        if deep { values + self.1.iter().map(V::heap_size).sum::<usize>() } else { values }
+    }
+
     /// Returns `true` if this `Series` contains no label/value pairs.
     pub fn is_empty(&self) -> bool {
         self.assert_len();
@@ -162,7 +295,7 @@ where
         L: Borrow<Q>,
         Q: Hash + Eq,
     {
-        self.0.index_of(&label).and_then(move |pos| self.1.get(pos))
+        self.0.index_of(label).and_then(move |pos| self.1.get(pos))
     }
 
     /// Given a label, returns a mutable reference to its value in the
@@ -172,18 +305,141 @@ where
         L: Borrow<Q>,
         Q: Hash + Eq,
     {
-        self.0.index_of(&label).and_then(move |pos| self.1.get_mut(pos))
+        self.0.index_of(label).and_then(move |pos| self.1.get_mut(pos))
+    }
+
+    /// Returns a new `Series` containing the label/value pairs within the
+    /// given range of positions, or `None` if the range is out of bounds.
+    pub fn slice<R>(&self, range: R) -> Option<Self>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, close) = self.0.range_to_nodules(range)?;
+        let close = close.max(start);
+
+        let index = Index::from_iter(self.0.iter().skip(start).take(close - start).cloned());
+        let values = self.1[start..close].to_vec();
+
+        Some(Self::new_inner(index, values).named(self.2.clone()))
+    }
+
+    /// Returns a new `Series` containing the label/value pairs at the given
+    /// positions, in that order. Returns `None` if any position is out of
+    /// bounds or repeated, since labels in an `Index` must be unique.
+    fn select_positions(&self, positions: &[usize]) -> Option<Self> {
+        let mut seen = HashSet::with_capacity(positions.len());
+
+        if !positions.iter().all(|&p| p < self.len() && seen.insert(p)) { return None; }
+
+        let index = Index::from_iter(positions.iter().map(|&p| self.0.iloc(p).unwrap().clone()));
+        let values = positions.iter().map(|&p| self.1[p].clone()).collect();
+
+        Some(Self::new_inner(index, values).named(self.2.clone()))
+    }
+
+    /// Returns a new `Series` containing the label/value pairs at the given
+    /// positions, in that order. Returns `None` if any position is out of
+    /// bounds or repeated.
+    pub fn iloc_multi<'a, I>(&self, pos_iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a usize>,
+    {
+        let positions = pos_iter.into_iter().copied().collect::<Vec<_>>();
+        self.select_positions(&positions)
+    }
+
+    /// Returns a new `Series` containing the label/value pairs at the given
+    /// positions, in that order. Returns `None` if any position is out of
+    /// bounds or repeated. Equivalent to `iloc_multi`.
+    pub fn take(&self, positions: &[usize]) -> Option<Self> {
+        self.select_positions(positions)
+    }
+
+    /// Returns a new `Series` containing the label/value pairs within the
+    /// given range of positions, or `None` if the range is out of bounds.
+    /// Equivalent to `slice`.
+    pub fn iloc_range<R>(&self, range: R) -> Option<Self>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.slice(range)
+    }
+
+    /// Returns a new `Series` containing the label/value pairs with the given
+    /// labels, in that order. Returns `None` if any label is missing or
+    /// repeated.
+    pub fn loc_multi<'a, I, Q>(&self, labels: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Q>,
+        L: Borrow<Q>,
+        Q: 'a + Hash + Eq + ?Sized,
+    {
+        let positions =
+            labels
+            .into_iter()
+            .map(|lbl| self.0.index_of(lbl))
+            .collect::<Option<Vec<_>>>()?
+        ;
+
+        self.select_positions(&positions)
+    }
+
+    /// Returns a new `Series` containing the label/value pairs within the
+    /// given range of labels, or `None` if an endpoint of the range is not
+    /// contained in the `Index`.
+    pub fn loc_range<'a, R, Q>(&self, range: R) -> Option<Self>
+    where
+        R: RangeBounds<&'a Q>,
+        L: Borrow<Q>,
+        Q: 'a + Hash + Eq + ?Sized,
+    {
+        self.slice(self.0.label_range_to_pos_range(range)?)
+    }
+
+    /// Returns a new `Series` containing the label/value pairs whose
+    /// corresponding boolean is `true`. Returns `None` if the number of
+    /// booleans does not match the length of this `Series`.
+    pub fn bloc<I, A>(&self, bools: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = A>,
+        I::IntoIter: ExactSizeIterator,
+        A: Borrow<bool>,
+    {
+        let bools = bools.into_iter();
+
+        if bools.len() != self.len() { return None; }
+
+        let positions =
+            bools
+            .enumerate()
+            .filter_map(|(p, b)| if *b.borrow() { Some(p) } else { None })
+            .collect::<Vec<_>>()
+        ;
+
+        self.select_positions(&positions)
+    }
+
+    /// Returns a new `Series` containing the first `n` label/value pairs, or
+    /// all of them if there are fewer than `n`.
+    pub fn head(&self, n: usize) -> Self {
+        self.slice(..n.min(self.len())).unwrap()
+    }
+
+    /// Returns a new `Series` containing the last `n` label/value pairs, or
+    /// all of them if there are fewer than `n`.
+    pub fn tail(&self, n: usize) -> Self {
+        self.slice(self.len() - n.min(self.len())..).unwrap()
     }
 
     /// Returns an iterator that yields all label/value pairs in this `Series`
     /// in order.
-    pub fn iter(&self) -> Iter<L, V> {
+    pub fn iter(&self) -> Iter<'_, L, V> {
         Iter::new(self)
     }
 
     /// Returns an iterator that yields all label/value pairs in this `Series`
     /// in order, with mutable references to the values.
-    pub fn iter_mut(&mut self) -> IterMut<L, V> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, L, V> {
         IterMut::new(self)
     }
 
@@ -197,7 +453,7 @@ where
             self
             .iter()
             .enumerate()
-            .filter(|(_, (l, v))| pred(l, v))
+            .filter(|(_, (l, v))| !pred(l, v))
             .map(|(p, _)| p)
             .collect::<HashSet<_>>()
         ;
@@ -233,6 +489,314 @@ where
         self.retain(|_, v| pred(v));
     }
 
+    /// Removes the label/value pairs specified by the predicate, and returns
+    /// them in order. The predicate accepts references to a label and a value.
+    pub fn drain_filter<F>(&mut self, mut pred: F) -> Vec<(L, V)>
+    where
+        F: FnMut(&L, &V) -> bool,
+    {
+        let index = std::mem::take(&mut self.0);
+        let values = std::mem::take(&mut self.1);
+
+        let mut drained = Vec::new();
+
+        for (label, value) in index.into_iter().zip(values) {
+            if pred(&label, &value) {
+                drained.push((label, value));
+            } else {
+                self.0.push(label);
+                self.1.push(value);
+            }
+        }
+
+        // Assert that the index and value lengths are the same.
+        self.assert_len();
+
+        drained
+    }
+
+    /// Appends a label/value pair to the end of this `Series`. If the label is
+    /// already present, a `DuplicateIndexLabel` error is returned and this
+    /// `Series` is unchanged.
+    pub fn push(&mut self, label: L, value: V) -> Result<(), DuplicateIndexLabel<L>> {
+        if self.0.contains(&label) {
+            return Err(DuplicateIndexLabel { label });
+        }
+
+        self.0.push(label);
+        self.1.push(value);
+
+        Ok(())
+    }
+
+    /// Inserts a label/value pair at the given position, shifting all
+    /// following pairs up by one. If the label is already present, a
+    /// `DuplicateIndexLabel` error is returned and this `Series` is unchanged.
+    ///
+    /// Panics if `pos > len`.
+    pub fn insert_at(&mut self, pos: usize, label: L, value: V) -> Result<(), DuplicateIndexLabel<L>> {
+        if self.0.contains(&label) {
+            return Err(DuplicateIndexLabel { label });
+        }
+
+        self.0.insert_at(pos, label);
+        self.1.insert(pos, value);
+
+        Ok(())
+    }
+
+    /// Consumes this `Series` and splits it into two at the given position.
+    /// The first `Series` contains the pairs in `[0, pos)`, and the second
+    /// contains the pairs in `[pos, len)`. Both keep the name of this `Series`.
+    ///
+    /// Panics if `pos > len`.
+    pub fn split_at(mut self, pos: usize) -> (Self, Self) {
+        let tail = self.split_off(pos);
+        (self, tail)
+    }
+
+    /// Splits this `Series` into two at the given position, keeping the pairs
+    /// in `[0, pos)` and returning a new `Series` with the pairs in
+    /// `[pos, len)`, which keeps the name of this `Series`.
+    ///
+    /// Panics if `pos > len`.
+    pub fn split_off(&mut self, pos: usize) -> Self {
+        let index = self.0.split_off(pos);
+        let values = self.1.split_off(pos);
+
+        Self::new_inner(index, values).named(self.2.clone())
+    }
+
+    /// Removes the pair with the given label, and returns its value, or `None`
+    /// if the label is not found.
+    pub fn pop<Q>(&mut self, label: &Q) -> Option<V>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let pos = self.0.index_of(label)?;
+
+        self.0.remove_at(pos);
+        Some(self.1.remove(pos))
+    }
+
+    /// Removes and returns the label/value pair at the given position in O(1)
+    /// time, by swapping it with the last pair. This does not preserve the
+    /// order of the remaining pairs. Returns `None` if the position is out of
+    /// bounds.
+    pub fn swap_remove(&mut self, pos: usize) -> Option<(L, V)> {
+        let label = self.0.swap_remove_at(pos)?;
+        let value = self.1.swap_remove(pos);
+
+        Some((label, value))
+    }
+
+    /// Removes the pair with the given label, and returns its value. If the
+    /// label is not found, a `MissingLabels` error is returned.
+    pub fn drop<Q>(&mut self, label: &Q) -> Result<V, MissingLabels<L>>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = L>,
+    {
+        let pos = self.0.index_of(label).ok_or_else(|| MissingLabels { labels: vec![label.to_owned()] })?;
+
+        self.0.remove_at(pos);
+        Ok(self.1.remove(pos))
+    }
+
+    /// Removes the pairs with the given labels, and returns their values in
+    /// the order they appeared in this `Series`. If any labels are not found,
+    /// a `MissingLabels` error listing them is returned and nothing is removed.
+    pub fn drop_multi<'a, I>(&mut self, labels: I) -> Result<Vec<V>, MissingLabels<L>>
+    where
+        I: IntoIterator<Item = &'a L>,
+        L: 'a,
+    {
+        let mut positions = Vec::new();
+        let mut missing = Vec::new();
+
+        for label in labels {
+            match self.0.index_of(label) {
+                Some(pos) => { positions.push(pos); },
+                None => { missing.push(label.clone()); },
+            }
+        }
+
+        if !missing.is_empty() {
+            return Err(MissingLabels { labels: missing });
+        }
+
+        Ok(self.drop_positions_unchecked(positions.into_iter().collect()))
+    }
+
+    /// Removes the pairs at the given positions, and returns their values in
+    /// the order they appeared in this `Series`. If any positions are out of
+    /// bounds, an `OutOfBounds` error listing them is returned and nothing is
+    /// removed.
+    pub fn drop_positions<I>(&mut self, positions: I) -> Result<Vec<V>, OutOfBounds>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let len = self.len();
+        let (valid, invalid): (Vec<_>, Vec<_>) = positions.into_iter().partition(|&p| p < len);
+
+        if !invalid.is_empty() {
+            return Err(OutOfBounds { positions: invalid, len });
+        }
+
+        Ok(self.drop_positions_unchecked(valid.into_iter().collect()))
+    }
+
+    fn drop_positions_unchecked(&mut self, pos_to_drop: HashSet<usize>) -> Vec<V> {
+        if pos_to_drop.is_empty() { return Vec::new(); }
+
+        let mut p = 0usize;
+        self.0.retain(|_| { (!pos_to_drop.contains(&p), p += 1).0 });
+
+        let mut dropped = Vec::with_capacity(pos_to_drop.len());
+        let mut kept = Vec::with_capacity(self.0.len());
+
+        for (p, value) in std::mem::take(&mut self.1).into_iter().enumerate() {
+            if pos_to_drop.contains(&p) { dropped.push(value); }
+            else { kept.push(value); }
+        }
+
+        self.1 = kept;
+        self.assert_len();
+
+        dropped
+    }
+
+    /// Randomly selects `n` label/value pairs from this `Series`, returning them
+    /// in the order they were selected. If `replace` is `true`, the same pair
+    /// may be selected more than once. Returns `None` if `n` is greater than
+    /// the length of this `Series` without replacement, or if `n` is non-zero
+    /// and this `Series` is empty with replacement.
+    #[cfg(feature = "rand")]
+    pub fn sample<R>(&self, n: usize, replace: bool, rng: &mut R) -> Option<Vec<(L, V)>>
+    where
+        R: Rng + ?Sized,
+    {
+        let positions = self.0.sample_positions(n, replace, rng)?;
+
+        Some(positions.into_iter().map(|p| (self.0.iloc(p).unwrap().clone(), self.1[p].clone())).collect())
+    }
+
+    /// Randomly selects a fraction of the label/value pairs from this `Series`
+    /// without replacement, returning them as a new `Series`. See
+    /// `Index::sample_frac` for details.
+    #[cfg(feature = "rand")]
+    pub fn sample_frac<R>(&self, frac: f64, rng: &mut R) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+    {
+        let positions = self.0.sample_positions(self.0.frac_len(frac)?, false, rng)?;
+
+        self.select_positions(&positions)
+    }
+
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// are duplicates of other values according to the `Keep` mode. Values are
+    /// compared as keys, so for floats, `0.0` and `-0.0` are duplicates, as
+    /// are all `NaN`s.
+    pub fn duplicated(&self, keep: Keep) -> Series<L, bool>
+    where
+        V: KeyHash,
+    {
+        let mut counts = HashMap::<HashKey<'_, V>, usize>::with_capacity(self.1.len());
+        for v in &self.1 { *counts.entry(HashKey(v)).or_insert(0) += 1; }
+
+        let mut seen = HashMap::<HashKey<'_, V>, usize>::with_capacity(counts.len());
+
+        let flags =
+            self.1
+            .iter()
+            .map(|v| {
+                let count = counts[&HashKey(v)];
+                let nth = seen.entry(HashKey(v)).or_insert(0);
+                *nth += 1;
+
+                match keep {
+                    Keep::First => *nth > 1,
+                    Keep::Last => *nth < count,
+                    Keep::All => count > 1,
+                }
+            })
+            .collect()
+        ;
+
+        Series::new_inner(self.0.clone(), flags)
+    }
+
+    /// Removes the label/value pairs whose values are duplicates of other
+    /// values according to the `Keep` mode. See `duplicated` for how values
+    /// are compared.
+    pub fn drop_duplicate_values(&mut self, keep: Keep)
+    where
+        V: KeyHash,
+    {
+        let flags = self.duplicated(keep).into_values();
+        let pos_to_drop = flags.into_iter().enumerate().filter(|(_, d)| *d).map(|(p, _)| p).collect();
+
+        self.drop_positions_unchecked(pos_to_drop);
+    }
+
+    /// Checks that an `Index` has the same set of labels as the `Index` of this
+    /// `Series`, in any order.
+    fn check_aligned(&self, other: &Index<L>) -> Result<(), MisalignedIndex<L>> {
+        let missing = self.0.difference(other).cloned().collect::<Vec<_>>();
+        let extra = other.difference(&self.0).cloned().collect::<Vec<_>>();
+
+        if !missing.is_empty() || !extra.is_empty() {
+            return Err(MisalignedIndex { missing, extra });
+        }
+
+        Ok(())
+    }
+
+    /// Looks up the flag in a boolean `Series` for each label in this `Series`.
+    /// If the two `Series` do not have the same set of labels, a
+    /// `MisalignedIndex` error is returned.
+    fn aligned_flags(&self, cond: &Series<L, bool>) -> Result<Vec<bool>, MisalignedIndex<L>> {
+        self.check_aligned(&cond.0)?;
+
+        Ok(self.0.iter().map(|l| *cond.loc(l).unwrap()).collect())
+    }
+
+    /// Keeps the values where the aligned boolean `Series` is `true`, and
+    /// replaces the others with the given value. If the two `Series` do not
+    /// have the same set of labels, a `MisalignedIndex` error is returned.
+    pub fn where_mask(&self, cond: &Series<L, bool>, other: V) -> Result<Self, MisalignedIndex<L>> {
+        let flags = self.aligned_flags(cond)?;
+
+        let values =
+            self.1
+            .iter()
+            .zip(flags)
+            .map(|(v, keep)| if keep { v.clone() } else { other.clone() })
+            .collect()
+        ;
+
+        Ok(Self::new_inner(self.0.clone(), values).named(self.2.clone()))
+    }
+
+    /// Replaces the values where the aligned boolean `Series` is `true` with
+    /// the given value, and keeps the others. If the two `Series` do not have
+    /// the same set of labels, a `MisalignedIndex` error is returned.
+    pub fn mask(&self, cond: &Series<L, bool>, other: V) -> Result<Self, MisalignedIndex<L>> {
+        let flags = self.aligned_flags(cond)?;
+
+        let values =
+            self.1
+            .iter()
+            .zip(flags)
+            .map(|(v, replace)| if replace { other.clone() } else { v.clone() })
+            .collect()
+        ;
+
+        Ok(Self::new_inner(self.0.clone(), values).named(self.2.clone()))
+    }
+
     /// Applies a function to each value in this `Series`, and produces a new
     /// `Series` with transformed values.
     pub fn map<F, C>(self, map_func: F) -> Series<L, C>
@@ -240,7 +804,7 @@ where
         F: FnMut(V) -> C,
         C: Storable,
     {
-        let (index, values) = (self.0, self.1);
+        let (index, values, name) = (self.0, self.1, self.2);
 
         let mapped_values =
             values
@@ -249,82 +813,617 @@ where
             .collect()
         ;
 
-        Series::new_inner(index, mapped_values)
+        Series::new_inner(index, mapped_values).named(name)
     }
 
-    pub fn concat_checked(self, other: Self) -> Result<Self, OverlappingIndex> {
-        Ok(self)
+    /// Applies a function to each label/value pair in this `Series`, and
+    /// produces a new `Series` with transformed values. The function accepts a
+    /// reference to a label and a value.
+    pub fn map_with_label<F, C>(self, mut map_func: F) -> Series<L, C>
+    where
+        F: FnMut(&L, V) -> C,
+        C: Storable,
+    {
+        let (index, values, name) = (self.0, self.1, self.2);
+
+        let mapped_values =
+            index
+            .iter()
+            .zip(values)
+            .map(|(l, v)| map_func(l, v))
+            .collect()
+        ;
+
+        Series::new_inner(index, mapped_values).named(name)
     }
-}
 
-impl<L: Label, R: RawType + Storable> Series<L, Option<R>> {
-    fn fill_handler<F>(self, fill_func: F) -> Series<L, R>
+    /// Aligns this `Series` with another on their labels according to a `Join`
+    /// mode, and combines the values of each label in the result using the
+    /// given function. The function receives `None` for a side that does not
+    /// contain the label, which can only happen for non-inner joins.
+    pub fn zip_with<W, C, F>(&self, other: &Series<L, W>, how: Join, mut zip_func: F) -> Series<L, C>
     where
-        F: FnMut(Option<R>) -> R,
+        W: Storable,
+        C: Storable,
+        F: FnMut(Option<&V>, Option<&W>) -> C,
     {
-        let (index, values) = (self.0, self.1);
+        let (index, left_map, right_map) = self.0.align(&other.0, how);
 
-        // NOTE: This should preserve the number and order of values!
-        let filled_values =
-            values
+        let values =
+            left_map
             .into_iter()
-            .map(fill_func)
+            .zip(right_map)
+            .map(|(lp, rp)| zip_func(lp.map(|p| &self.1[p]), rp.map(|p| &other.1[p])))
             .collect()
         ;
 
-        Series::new_inner(index, filled_values)
+        Series::new_inner(index, values)
     }
 
-    /// Consumes a `Series` containing `Option` values, fills `None`s with the
-    /// given value, and returns a new `Series` without `None`s.
-    pub fn fill_none(self, value: R) -> Series<L, R> {
-        self.fill_handler(|v| v.unwrap_or_else(|| value.clone()))
+    /// Applies a function to a reference to each value in this `Series`, and
+    /// produces a new `Series` with transformed values, leaving this `Series`
+    /// unchanged.
+    pub fn apply<F, C>(&self, mut apply_func: F) -> Series<L, C>
+    where
+        F: FnMut(&V) -> C,
+        C: Storable,
+    {
+        self.apply_with_label(|_, v| apply_func(v))
     }
 
-    /// Consumes a `Series` containing `Option` values, fills `None`s with the
-    /// result of the given function, and returns a new `Series` without `None`s.
-    pub fn fill_none_with<F>(self, mut func: F) -> Series<L, R>
+    /// Applies a function to references to each label/value pair in this
+    /// `Series`, and produces a new `Series` with transformed values, leaving
+    /// this `Series` unchanged.
+    pub fn apply_with_label<F, C>(&self, mut apply_func: F) -> Series<L, C>
     where
-        F: FnMut() -> R,
+        F: FnMut(&L, &V) -> C,
+        C: Storable,
     {
-        self.fill_handler(|v| v.unwrap_or_else(|| func()))
+        let applied_values =
+            self
+            .iter()
+            .map(|(l, v)| apply_func(l, v))
+            .collect()
+        ;
+
+        Series::new_inner(self.0.clone(), applied_values).named(self.2.clone())
     }
 
-    /// Consumes a `Series` containing `Option` values, drops the label/value
-    /// pairs with a value of `None`, and returns a new `Series` without `None`s.
-    pub fn drop_none(self) -> Series<L, R> {
-        // NOTE: The `Index` may not need to be modified if no values end up
-        //       getting dropped. The values will always need to be modified.
-        let mut index = self.0;
-        let values = self.1;
+    /// Moves the values in this `Series` forward (for positive `periods`) or
+    /// backward (for negative `periods`) relative to the `Index`, filling the
+    /// vacated positions with the given value. The `Index` is preserved.
+    pub fn shift_fill(self, periods: isize, fill: V) -> Self {
+        let (index, mut values, name) = (self.0, self.1, self.2);
+        let len = values.len();
+        let p = periods.unsigned_abs().min(len);
+
+        if periods >= 0 {
+            values.truncate(len - p);
+            values.splice(0..0, std::iter::repeat_n(fill, p));
+        }
+        else {
+            values.drain(0..p);
+            values.extend(std::iter::repeat_n(fill, p));
+        }
 
-        let mut pos_to_drop = HashSet::new();
-        let mut raw_values = Vec::<R>::with_capacity(values.len());
+        Self::new_inner(index, values).named(name)
+    }
 
-        for (pos, value) in values.into_iter().enumerate() {
-            match value {
-                Some(rv) => { raw_values.push(rv); },
-                None => { pos_to_drop.insert(pos); }
-            }
+    /// Appends the label/value pairs of another `Series` to the end of this
+    /// `Series`. If any labels are contained in both, an `OverlappingIndex`
+    /// error listing them is returned.
+    pub fn concat_checked(self, other: Self) -> Result<Self, OverlappingIndex<L>> {
+        let overlapping = self.0.intersection(&other.0).cloned().collect::<Vec<_>>();
+
+        if !overlapping.is_empty() {
+            return Err(OverlappingIndex { labels: overlapping });
         }
 
-        // Only mutate the `Index` if there are any elements to drop.
-        if !pos_to_drop.is_empty() {
-            let mut p = 0usize;
-            index.retain(|_| { (!pos_to_drop.contains(&p), p += 1).0 });
+        let (mut index, mut values) = (self.0, self.1);
+
+        index.extend(other.0);
+        values.extend(other.1);
+
+        Ok(Self::new_inner(index, values))
+    }
+
+    /// Appends the label/value pairs of another `Series` to the end of this
+    /// `Series`. For labels contained in both, the pair from this `Series` is
+    /// kept.
+    pub fn concat_keep_first(self, other: Self) -> Self {
+        let (mut index, mut values) = (self.0, self.1);
+
+        for (label, value) in other {
+            if index.push(label) {
+                values.push(value);
+            }
         }
 
-        Series::new_inner(index, raw_values)
+        Self::new_inner(index, values)
     }
-}
 
-impl<L, V> Default for Series<L, V>
+    /// Appends the label/value pairs of another `Series` to the end of this
+    /// `Series`. For labels contained in both, the value from the other
+    /// `Series` is kept, at the position of the label in this `Series`.
+    pub fn concat_keep_last(self, other: Self) -> Self {
+        let (mut index, mut values) = (self.0, self.1);
+
+        for (label, value) in other {
+            match index.index_of(&label) {
+                Some(pos) => { values[pos] = value; },
+                None => {
+                    index.push(label);
+                    values.push(value);
+                },
+            }
+        }
+
+        Self::new_inner(index, values)
+    }
+
+    /// Appends the label/value pairs of another `Series` to the end of this
+    /// `Series`. For labels contained in both, the label from the other
+    /// `Series` is replaced with the result of the given function, e.g. to add
+    /// a suffix. If a replaced label still collides with an existing label, a
+    /// `DuplicateIndexLabel` error is returned.
+    pub fn concat_relabel<F>(self, other: Self, mut relabel: F) -> Result<Self, DuplicateIndexLabel<L>>
+    where
+        F: FnMut(&L) -> L,
+    {
+        let overlapping =
+            self.0.intersection(&other.0).cloned().collect::<HashSet<_>>();
+
+        let (mut index, mut values) = (self.0, self.1);
+
+        for (label, value) in other {
+            let label = if overlapping.contains(&label) { relabel(&label) } else { label };
+
+            if index.contains(&label) {
+                return Err(DuplicateIndexLabel { label });
+            }
+
+            index.push(label);
+            values.push(value);
+        }
+
+        Ok(Self::new_inner(index, values))
+    }
+
+    /// Reorders the label/value pairs of this `Series` so that the pair at
+    /// `positions[i]` ends up at position `i`. The positions must be a
+    /// permutation of `0..len`.
+    fn reorder(self, positions: &[usize]) -> Self {
+        let (index, values, name) = (self.0, self.1, self.2);
+
+        let mut labels = Vec::from(index).into_iter().map(Some).collect::<Vec<_>>();
+        let mut values = values.into_iter().map(Some).collect::<Vec<_>>();
+
+        let index = Index::from_iter(positions.iter().map(|&p| labels[p].take().unwrap()));
+        let values = positions.iter().map(|&p| values[p].take().unwrap()).collect();
+
+        Self::new_inner(index, values).named(name)
+    }
+
+    /// Reorders the label/value pairs of this `Series` in-place, so that the
+    /// pair at `permutation[i]` ends up at position `i`, as returned by
+    /// `Index::arg_sort`. If `permutation` is not a permutation of `0..len`,
+    /// an `InvalidPermutation` error is returned and nothing is reordered.
+    pub fn apply_permutation(&mut self, permutation: &[usize]) -> Result<(), InvalidPermutation> {
+        let len = self.len();
+        let mut seen = vec![false; len];
+
+        let is_valid =
+            permutation.len() == len
+            && permutation.iter().all(|&p| p < len && !std::mem::replace(&mut seen[p], true))
+        ;
+
+        if !is_valid {
+            return Err(InvalidPermutation { permutation: permutation.to_vec(), len });
+        }
+
+        *self = std::mem::take(self).reorder(permutation);
+
+        Ok(())
+    }
+
+    /// Reverses the order of the label/value pairs of this `Series` in-place.
+    pub fn reverse(&mut self) {
+        self.0.reverse();
+        self.1.reverse();
+    }
+
+    /// Sorts the label/value pairs of this `Series` by value using a custom
+    /// comparison function. The sort is stable, and labels stay paired with
+    /// their values.
+    pub fn sort_values_by<F>(self, mut compare: F) -> Self
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        let mut positions = (0..self.1.len()).collect::<Vec<_>>();
+        positions.sort_by(|&a, &b| compare(&self.1[a], &self.1[b]));
+
+        self.reorder(&positions)
+    }
+
+    /// Sorts the label/value pairs of this `Series` by value, in ascending or
+    /// descending order. The sort is stable, and values that are not
+    /// comparable with themselves (e.g. NaN) are always placed last.
+    pub fn sort_values(self, ascending: bool) -> Self
+    where
+        V: PartialOrd,
+    {
+        self.sort_values_by(|a, b| {
+            match (a.partial_cmp(a).is_some(), b.partial_cmp(b).is_some()) {
+                (true, true) => {
+                    let ord = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+                    if ascending { ord } else { ord.reverse() }
+                },
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => Ordering::Equal,
+            }
+        })
+    }
+
+    /// Sorts the label/value pairs of this `Series` in-place by value, in
+    /// ascending or descending order. See `Series::sort_values`.
+    pub fn sort_values_in_place(&mut self, ascending: bool)
+    where
+        V: PartialOrd,
+    {
+        *self = std::mem::take(self).sort_values(ascending);
+    }
+
+    /// Sorts the label/value pairs of this `Series` by label using `Ord::cmp`.
+    pub fn sort_by_index(self) -> Self
+    where
+        L: Ord,
+    {
+        let positions = self.0.arg_sort();
+        self.reorder(&positions)
+    }
+
+    /// Sorts the label/value pairs of this `Series` in-place by label using
+    /// `Ord::cmp`.
+    pub fn sort_by_index_in_place(&mut self)
+    where
+        L: Ord,
+    {
+        *self = std::mem::take(self).sort_by_index();
+    }
+}
+
+/// Concatenates an iterable of `Series` in order into a single `Series`. If any
+/// labels are contained in more than one `Series`, an `OverlappingIndex` error
+/// listing them is returned.
+pub fn concat<L, V, I>(iter: I) -> Result<Series<L, V>, OverlappingIndex<L>>
+where
+    L: Label,
+    V: Storable,
+    I: IntoIterator<Item = Series<L, V>>,
+{
+    let mut index = Index::new();
+    let mut values = Vec::new();
+    let mut overlapping = Vec::new();
+
+    for series in iter {
+        for (label, value) in series {
+            if index.contains(&label) { overlapping.push(label); }
+            else {
+                index.push(label);
+                values.push(value);
+            }
+        }
+    }
+
+    if !overlapping.is_empty() {
+        return Err(OverlappingIndex { labels: overlapping });
+    }
+
+    Ok(Series::new_inner(index, values))
+}
+
+impl<L: Label, R: RawType> Series<L, R> {
+    /// Moves the values in this `Series` forward (for positive `periods`) or
+    /// backward (for negative `periods`) relative to the `Index`, filling the
+    /// vacated positions with `None`. The `Index` is preserved.
+    pub fn shift(self, periods: isize) -> Series<L, Option<R>> {
+        self.map(Some).shift_fill(periods, None)
+    }
+
+    /// Aligns this `Series` with another on their labels according to a `Join`
+    /// mode, returning two new `Series` that share the combined `Index`. Labels
+    /// not contained in one of the inputs are given a value of `None` in the
+    /// corresponding output.
+    pub fn align<W>(&self, other: &Series<L, W>, how: Join) -> (Series<L, Option<R>>, Series<L, Option<W>>)
+    where
+        W: RawType,
+    {
+        let (index, left_map, right_map) = self.0.align(&other.0, how);
+
+        let left_values = left_map.into_iter().map(|lp| lp.map(|p| self.1[p].clone())).collect();
+        let right_values = right_map.into_iter().map(|rp| rp.map(|p| other.1[p].clone())).collect();
+
+        (Series::new_inner(index.clone(), left_values), Series::new_inner(index, right_values))
+    }
+
+    /// Like `align`, but for two `Series` whose `Index`s are both sorted,
+    /// using a linear merge of the labels instead of looking them up. The
+    /// combined `Index` is also sorted. Returns `None` if either `Index` is
+    /// not sorted.
+    pub fn merge_sorted<W>(&self, other: &Series<L, W>, how: Join) -> Option<Aligned<L, R, W>>
+    where
+        W: RawType,
+    {
+        let (index, left_map, right_map) = self.0.merge_sorted(&other.0, how)?;
+
+        let left_values = left_map.into_iter().map(|lp| lp.map(|p| self.1[p].clone())).collect();
+        let right_values = right_map.into_iter().map(|rp| rp.map(|p| other.1[p].clone())).collect();
+
+        Some((Series::new_inner(index.clone(), left_values), Series::new_inner(index, right_values)))
+    }
+}
+
+impl<L: Label, R: RawType + PartialOrd> Series<L, R> {
+    /// Bounds each value in this `Series` to lie within `[lower, upper]`,
+    /// preserving the `Index`. Values that are not comparable with the bounds
+    /// (e.g. `NaN`) are left unchanged.
+    pub fn clip(self, lower: R, upper: R) -> Self {
+        self.clip_lower(lower).clip_upper(upper)
+    }
+
+    /// Bounds each value in this `Series` to be no less than `lower`,
+    /// preserving the `Index`.
+    pub fn clip_lower(self, lower: R) -> Self {
+        self.map(|v| if v < lower { lower.clone() } else { v })
+    }
+
+    /// Bounds each value in this `Series` to be no greater than `upper`,
+    /// preserving the `Index`.
+    pub fn clip_upper(self, upper: R) -> Self {
+        self.map(|v| if v > upper { upper.clone() } else { v })
+    }
+
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// lie between `low` and `high`. If `inclusive` is `true`, the bounds
+    /// themselves are included.
+    pub fn between(&self, low: &R, high: &R, inclusive: bool) -> Series<L, bool> {
+        self.apply(|v| in_range(v, low, high, inclusive))
+    }
+}
+
+impl<L: Label, R: RawType + PartialEq> Series<L, R> {
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// are contained in a collection of candidate values.
+    pub fn isin<I>(&self, candidates: I) -> Series<L, bool>
+    where
+        R: Hash + Eq,
+        I: IntoIterator<Item = R>,
+    {
+        self.apply(membership(candidates))
+    }
+
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// are equal to any value in a slice of candidate values. Unlike
+    /// `Series::isin`, this works for values that are not hashable, such as
+    /// floats, by scanning the slice for each value.
+    pub fn isin_slice(&self, candidates: &[R]) -> Series<L, bool> {
+        self.apply(|v| candidates.contains(v))
+    }
+}
+
+impl<L: Label, R: RawType + PartialOrd> Series<L, Option<R>> {
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// lie between `low` and `high`. If `inclusive` is `true`, the bounds
+    /// themselves are included. `None` values are never between the bounds.
+    pub fn between(&self, low: &R, high: &R, inclusive: bool) -> Series<L, bool> {
+        self.apply(|o| o.as_ref().is_some_and(|v| in_range(v, low, high, inclusive)))
+    }
+}
+
+impl<L: Label, R: RawType + PartialEq> Series<L, Option<R>> {
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// are contained in a collection of candidate values. `None` values are
+    /// never contained.
+    pub fn isin<I>(&self, candidates: I) -> Series<L, bool>
+    where
+        R: Hash + Eq,
+        I: IntoIterator<Item = R>,
+    {
+        let contains = membership(candidates);
+        self.apply(|o| o.as_ref().is_some_and(&contains))
+    }
+
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// are equal to any value in a slice of candidate values. `None` values are
+    /// never contained.
+    pub fn isin_slice(&self, candidates: &[R]) -> Series<L, bool> {
+        self.apply(|o| o.as_ref().is_some_and(|v| candidates.contains(v)))
+    }
+}
+
+/// Candidate collections with more values than this are hashed for `isin`.
+const ISIN_HASH_THRESHOLD: usize = 16;
+
+/// Builds a membership test over a collection of candidate values, using a
+/// linear scan for small collections and a hash set for large ones.
+fn membership<R, I>(candidates: I) -> impl Fn(&R) -> bool
+where
+    R: Hash + Eq,
+    I: IntoIterator<Item = R>,
+{
+    let candidates = candidates.into_iter().collect::<Vec<_>>();
+    let lookup: Result<HashSet<R>, Vec<R>> =
+        if candidates.len() > ISIN_HASH_THRESHOLD { Ok(candidates.into_iter().collect()) }
+        else { Err(candidates) }
+    ;
+
+    move |v| match &lookup {
+        Ok(set) => set.contains(v),
+        Err(vec) => vec.contains(v),
+    }
+}
+
+/// Checks if a value lies between two bounds, optionally including them.
+fn in_range<R: PartialOrd>(v: &R, low: &R, high: &R, inclusive: bool) -> bool {
+    if inclusive { low <= v && v <= high } else { low < v && v < high }
+}
+
+impl<L: Label, R: RawType + Storable> Series<L, Option<R>> {
+    fn fill_handler<F>(self, fill_func: F) -> Series<L, R>
+    where
+        F: FnMut(Option<R>) -> R,
+    {
+        let (index, values, name) = (self.0, self.1, self.2);
+
+        // NOTE: This should preserve the number and order of values!
+        let filled_values =
+            values
+            .into_iter()
+            .map(fill_func)
+            .collect()
+        ;
+
+        Series::new_inner(index, filled_values).named(name)
+    }
+
+    /// Consumes a `Series` containing `Option` values, fills `None`s with the
+    /// given value, and returns a new `Series` without `None`s.
+    pub fn fill_none(self, value: R) -> Series<L, R> {
+        self.fill_handler(|v| v.unwrap_or_else(|| value.clone()))
+    }
+
+    /// Consumes a `Series` containing `Option` values, fills `None`s with the
+    /// result of the given function, and returns a new `Series` without `None`s.
+    pub fn fill_none_with<F>(self, mut func: F) -> Series<L, R>
+    where
+        F: FnMut() -> R,
+    {
+        self.fill_handler(|v| v.unwrap_or_else(&mut func))
+    }
+
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// are `None`.
+    pub fn isna(&self) -> Series<L, bool> {
+        Series::new_inner(self.0.clone(), self.1.iter().map(Option::is_none).collect())
+    }
+
+    /// Returns a boolean `Series` with the same `Index`, marking which values
+    /// are not `None`.
+    pub fn notna(&self) -> Series<L, bool> {
+        Series::new_inner(self.0.clone(), self.1.iter().map(Option::is_some).collect())
+    }
+
+    /// Returns the number of `None` values in this `Series`.
+    pub fn count_none(&self) -> usize {
+        self.1.iter().filter(|v| v.is_none()).count()
+    }
+
+    /// Returns the number of non-`None` values in this `Series`.
+    pub fn count_some(&self) -> usize {
+        self.1.iter().filter(|v| v.is_some()).count()
+    }
+
+    /// Returns `true` if this `Series` contains any `None` values.
+    pub fn has_none(&self) -> bool {
+        self.1.iter().any(Option::is_none)
+    }
+
+    /// Returns the labels of the `None` values in this `Series`, in order.
+    pub fn none_labels(&self) -> Vec<&L> {
+        self.iter().filter(|(_, v)| v.is_none()).map(|(l, _)| l).collect()
+    }
+
+    /// Consumes a `Series` containing `Option` values, drops the label/value
+    /// pairs with a value of `None`, and returns a new `Series` without `None`s.
+    pub fn drop_none(self) -> Series<L, R> {
+        // NOTE: The `Index` may not need to be modified if no values end up
+        //       getting dropped. The values will always need to be modified.
+        let mut index = self.0;
+        let values = self.1;
+        let name = self.2;
+
+        let mut pos_to_drop = HashSet::new();
+        let mut raw_values = Vec::<R>::with_capacity(values.len());
+
+        for (pos, value) in values.into_iter().enumerate() {
+            match value {
+                Some(rv) => { raw_values.push(rv); },
+                None => { pos_to_drop.insert(pos); }
+            }
+        }
+
+        // Only mutate the `Index` if there are any elements to drop.
+        if !pos_to_drop.is_empty() {
+            let mut p = 0usize;
+            index.retain(|_| { (!pos_to_drop.contains(&p), p += 1).0 });
+        }
+
+        Series::new_inner(index, raw_values).named(name)
+    }
+}
+
+impl<L: Label, R: RawType> Series<L, Vec<R>> {
+    /// Consumes a `Series` containing list values, and flattens it into a
+    /// `Series` with one label/value pair per list element. Each new label is
+    /// produced by calling the given function with the original label and the
+    /// ordinal of the element within its list, e.g. to build a label of the
+    /// form `"label.ordinal"`. Empty lists produce no pairs. If a new label is
+    /// repeated, a `DuplicateIndexLabel` error is returned.
+    pub fn explode<F, K>(self, mut relabel: F) -> Result<Series<K, R>, DuplicateIndexLabel<K>>
+    where
+        F: FnMut(&L, usize) -> K,
+        K: Label,
+    {
+        let (index, values, name) = (self.0, self.1, self.2);
+
+        let len = values.iter().map(Vec::len).sum();
+        let mut new_index = Index::with_capacity(len);
+        let mut new_values = Vec::with_capacity(len);
+
+        for (label, list) in index.iter().zip(values) {
+            for (ordinal, value) in list.into_iter().enumerate() {
+                let new_label = relabel(label, ordinal);
+
+                if new_index.contains(&new_label) {
+                    return Err(DuplicateIndexLabel { label: new_label });
+                }
+
+                new_index.push(new_label);
+                new_values.push(value);
+            }
+        }
+
+        Ok(Series::new_inner(new_index, new_values).named(name))
+    }
+}
+
+/// Two `Series` are equal if they have the same label/value pairs in the same
+/// order. Names are ignored. Note that as with `Vec`, `NaN` values are never
+/// equal, see `Series::equals` for a comparison that treats them as equal.
+impl<L, V> PartialEq for Series<L, V>
+where
+    L: Label,
+    V: Storable + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+impl<L, V> Eq for Series<L, V>
+where
+    L: Label,
+    V: Storable + Eq,
+{}
+
+impl<L, V> Default for Series<L, V>
 where
     L: Label,
     V: Storable,
 {
     fn default() -> Self {
-        Self(Index::default(), Vec::default())
+        Self(Index::default(), Vec::default(), None)
     }
 }
 
@@ -372,6 +1471,772 @@ mod tests {
 
     use std::iter::FromIterator;
 
+    #[test]
+    fn concat_checked() {
+        let a = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c')]).unwrap();
+        let b = Series::from_iter_checked(vec![(3, 'd'), (4, 'e')]).unwrap();
+
+        let (index, values) = a.concat_checked(b).unwrap().into_index_values();
+
+        assert_eq!(index, Index::from_iter(0..=4));
+        assert_eq!(values, vec!['a', 'b', 'c', 'd', 'e']);
+
+        let a = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c')]).unwrap();
+        let b = Series::from_iter_checked(vec![(2, 'x'), (3, 'd'), (0, 'y')]).unwrap();
+
+        let err = a.concat_checked(b).unwrap_err();
+
+        assert_eq!(err.labels, vec![0, 2]);
+    }
+
+    #[test]
+    fn concat_policies() {
+        let a = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c')]).unwrap();
+        let b = Series::from_iter_checked(vec![(2, 'x'), (3, 'd'), (0, 'y')]).unwrap();
+
+        let (index, values) = Series::concat_keep_first(a, b).into_index_values();
+
+        assert_eq!(index, Index::from_iter(&[0, 1, 2, 3]));
+        assert_eq!(values, vec!['a', 'b', 'c', 'd']);
+
+        let a = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c')]).unwrap();
+        let b = Series::from_iter_checked(vec![(2, 'x'), (3, 'd'), (0, 'y')]).unwrap();
+
+        let (index, values) = Series::concat_keep_last(a, b).into_index_values();
+
+        assert_eq!(index, Index::from_iter(&[0, 1, 2, 3]));
+        assert_eq!(values, vec!['y', 'b', 'x', 'd']);
+
+        let a = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c')]).unwrap();
+        let b = Series::from_iter_checked(vec![(2, 'x'), (3, 'd'), (0, 'y')]).unwrap();
+
+        let (index, values) = Series::concat_relabel(a, b, |l| l + 10).unwrap().into_index_values();
+
+        assert_eq!(index, Index::from_iter(&[0, 1, 2, 12, 3, 10]));
+        assert_eq!(values, vec!['a', 'b', 'c', 'x', 'd', 'y']);
+
+        let a = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c')]).unwrap();
+        let b = Series::from_iter_checked(vec![(2, 'x')]).unwrap();
+
+        let err = Series::concat_relabel(a, b, |l| l - 1).unwrap_err();
+
+        assert_eq!(err.label, 1);
+    }
+
+    #[test]
+    fn map_with_label() {
+        let s = Series::from_iter_checked(vec![(1, 10), (2, 20), (3, 30)]).unwrap();
+
+        let (index, values) = s.map_with_label(|l, v| l * v).into_index_values();
+
+        assert_eq!(index, Index::from_iter(1..=3));
+        assert_eq!(values, vec![10, 40, 90]);
+    }
+
+    #[test]
+    fn apply() {
+        let s = Series::from_iter_checked(vec![(1, 10), (2, 20), (3, 30)]).unwrap();
+
+        let (index, values) = s.apply(|v| v / 10).into_index_values();
+
+        assert_eq!(index, Index::from_iter(1..=3));
+        assert_eq!(values, vec![1, 2, 3]);
+
+        let (index, values) = s.apply_with_label(|l, v| v - l).into_index_values();
+
+        assert_eq!(index, Index::from_iter(1..=3));
+        assert_eq!(values, vec![9, 18, 27]);
+
+        assert_eq!(s.values(), &[10, 20, 30]);
+    }
+
+    #[test]
+    fn zip_with() {
+        let a = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3)]).unwrap();
+        let b = Series::from_iter_checked(vec![('c', 1.5), ('d', 2.5), ('a', 0.5)]).unwrap();
+
+        let (index, values) = a.zip_with(&b, Join::Inner, |x, y| {
+            *x.unwrap() as f64 * y.unwrap()
+        }).into_index_values();
+
+        assert_eq!(index, Index::from_iter("ac".chars()));
+        assert_eq!(values, vec![0.5, 4.5]);
+
+        let (index, values) = a.zip_with(&b, Join::Outer, |x, y| {
+            x.copied().unwrap_or(0) as f64 + y.copied().unwrap_or(10.0)
+        }).into_index_values();
+
+        assert_eq!(index, Index::from_iter("abcd".chars()));
+        assert_eq!(values, vec![1.5, 12.0, 4.5, 2.5]);
+
+        let (index, values) = a.zip_with(&b, Join::Right, |x, _| x.is_some()).into_index_values();
+
+        assert_eq!(index, Index::from_iter("cda".chars()));
+        assert_eq!(values, vec![true, false, true]);
+    }
+
+    #[test]
+    fn align() {
+        let a = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3)]).unwrap();
+        let b = Series::from_iter_checked(vec![('c', 'x'), ('d', 'y')]).unwrap();
+
+        let (left, right) = a.align(&b, Join::Outer);
+        let (left_index, left_values) = left.into_index_values();
+        let (right_index, right_values) = right.into_index_values();
+
+        assert_eq!(left_index, Index::from_iter("abcd".chars()));
+        assert_eq!(right_index, left_index);
+        assert_eq!(left_values, vec![Some(1), Some(2), Some(3), None]);
+        assert_eq!(right_values, vec![None, None, Some('x'), Some('y')]);
+
+        let (left, right) = a.align(&b, Join::Inner);
+
+        assert_eq!(left.index(), &Index::from_iter("c".chars()));
+        assert_eq!(left.into_values(), vec![Some(3)]);
+        assert_eq!(right.into_values(), vec![Some('x')]);
+    }
+
+    #[test]
+    fn merge_sorted() {
+        let a = Series::from_iter_checked(vec![(1, 'a'), (3, 'b'), (5, 'c')]).unwrap();
+        let b = Series::from_iter_checked(vec![(2, 20.0), (3, 30.0), (6, 60.0)]).unwrap();
+
+        let (left, right) = a.merge_sorted(&b, Join::Outer).unwrap();
+        let (left_index, left_values) = left.into_index_values();
+
+        assert_eq!(left_index, Index::from_iter(vec![1, 2, 3, 5, 6]));
+        assert_eq!(right.index(), &left_index);
+        assert_eq!(left_values, vec![Some('a'), None, Some('b'), Some('c'), None]);
+        assert_eq!(right.into_values(), vec![None, Some(20.0), Some(30.0), None, Some(60.0)]);
+
+        let (left, right) = a.merge_sorted(&b, Join::Inner).unwrap();
+        assert_eq!(left.into_values(), vec![Some('b')]);
+        assert_eq!(right.into_values(), vec![Some(30.0)]);
+
+        let c = Series::from_iter_checked(vec![(2, 0), (1, 0)]).unwrap();
+        assert!(a.merge_sorted(&c, Join::Outer).is_none());
+    }
+
+    #[test]
+    fn push_insert_pop() {
+        let mut s = Series::new();
+
+        s.push('a', 1).unwrap();
+        s.push('c', 3).unwrap();
+        assert_eq!(s.push('a', 9).unwrap_err().label, 'a');
+
+        s.insert_at(1, 'b', 2).unwrap();
+        s.insert_at(0, 'z', 0).unwrap();
+        assert_eq!(s.insert_at(4, 'c', 9).unwrap_err().label, 'c');
+
+        assert_eq!(s.pop(&'c'), Some(3));
+        assert_eq!(s.pop(&'c'), None);
+
+        let (index, values) = s.into_index_values();
+
+        assert_eq!(index, Index::from_iter("zab".chars()));
+        assert_eq!(values, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap();
+
+        assert_eq!(s.swap_remove(0), Some(('a', 1)));
+        assert_eq!(s.swap_remove(2), Some(('c', 3)));
+        assert_eq!(s.swap_remove(2), None);
+
+        let (index, values) = s.into_index_values();
+
+        assert_eq!(index, Index::from_iter("db".chars()));
+        assert_eq!(values, vec![4, 2]);
+    }
+
+    #[test]
+    fn drop() {
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap();
+
+        assert_eq!(s.drop(&'b').unwrap(), 2);
+        assert_eq!(s.drop(&'z').unwrap_err().labels, vec!['z']);
+
+        let (index, values) = s.into_index_values();
+
+        assert_eq!(index, Index::from_iter("acd".chars()));
+        assert_eq!(values, vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn drop_multi() {
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap();
+
+        assert_eq!(s.drop_multi(&['x', 'a', 'y']).unwrap_err().labels, vec!['x', 'y']);
+        assert_eq!(s.len(), 4);
+
+        assert_eq!(s.drop_multi(&['d', 'a']).unwrap(), vec![1, 4]);
+
+        let (index, values) = s.into_index_values();
+
+        assert_eq!(index, Index::from_iter("bc".chars()));
+        assert_eq!(values, vec![2, 3]);
+    }
+
+    #[test]
+    fn drop_positions() {
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap();
+
+        let err = s.drop_positions(vec![1, 4, 9]).unwrap_err();
+
+        assert_eq!(err.positions, vec![4, 9]);
+        assert_eq!(err.len, 4);
+        assert_eq!(s.len(), 4);
+
+        assert_eq!(s.drop_positions(vec![2, 0, 2]).unwrap(), vec![1, 3]);
+
+        let (index, values) = s.into_index_values();
+
+        assert_eq!(index, Index::from_iter("bd".chars()));
+        assert_eq!(values, vec![2, 4]);
+    }
+
+    #[test]
+    fn isna_notna() {
+        let s = Series::from_iter_checked(vec![('a', Some(1)), ('b', None), ('c', Some(3))]).unwrap();
+
+        let (index, values) = s.isna().into_index_values();
+
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![false, true, false]);
+
+        assert_eq!(s.notna().into_values(), vec![true, false, true]);
+    }
+
+    #[test]
+    fn null_stats() {
+        let s = Series::from_iter_checked(vec![('a', Some(1)), ('b', None), ('c', Some(3)), ('d', None)]).unwrap();
+
+        assert_eq!(s.count_none(), 2);
+        assert_eq!(s.count_some(), 2);
+        assert!(s.has_none());
+        assert_eq!(s.none_labels(), vec![&'b', &'d']);
+
+        let s = Series::from_iter_checked(vec![('a', Some(1))]).unwrap();
+
+        assert_eq!(s.count_none(), 0);
+        assert!(!s.has_none());
+        assert!(s.none_labels().is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn sample() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let s = Series::from_iter(vec![(1, 10), (2, 20), (3, 30), (4, 40), (5, 50), (6, 60)]);
+
+        let produced = s.sample(4, false, &mut rng).unwrap();
+        assert_eq!(produced.len(), 4);
+        assert!(produced.iter().all(|(l, v)| *v == l * 10));
+        assert_eq!(produced.iter().map(|(l, _)| l).collect::<HashSet<_>>().len(), 4);
+
+        assert!(s.sample(7, false, &mut rng).is_none());
+
+        let produced = s.sample(20, true, &mut rng).unwrap();
+        assert_eq!(produced.len(), 20);
+        assert!(produced.iter().all(|(l, v)| *v == l * 10));
+
+        let produced = s.sample_frac(0.5, &mut rng).unwrap();
+        assert_eq!(produced.len(), 3);
+        assert!(produced.iter().all(|(l, v)| *v == l * 10));
+
+        let empty: Series<i32, i32> = Series::from_iter(vec![]);
+        assert_eq!(empty.sample(0, true, &mut rng), Some(vec![]));
+        assert!(empty.sample(1, true, &mut rng).is_none());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn head_tail_slice() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap();
+
+        let (index, values) = s.head(2).into_index_values();
+        assert_eq!(index, Index::from_iter("ab".chars()));
+        assert_eq!(values, vec![1, 2]);
+
+        let (index, values) = s.tail(3).into_index_values();
+        assert_eq!(index, Index::from_iter("bcd".chars()));
+        assert_eq!(values, vec![2, 3, 4]);
+
+        assert_eq!(s.head(10).into_values(), vec![1, 2, 3, 4]);
+        assert_eq!(s.tail(10).into_values(), vec![1, 2, 3, 4]);
+        assert!(s.head(0).is_empty());
+
+        let (index, values) = s.slice(1..=2).unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("bc".chars()));
+        assert_eq!(values, vec![2, 3]);
+
+        assert!(s.slice(3..1).unwrap().is_empty());
+        assert!(s.slice(2..5).is_none());
+    }
+
+    #[test]
+    fn name() {
+        let mut s = Series::from_iter_checked(vec![('a', Some(1)), ('b', None), ('c', Some(3))]).unwrap();
+        assert_eq!(s.name(), None);
+
+        s.set_name(Some(String::from("x")));
+        assert_eq!(s.name(), Some("x"));
+
+        let s = s.rename("y");
+        assert_eq!(s.name(), Some("y"));
+
+        assert_eq!(s.slice(..2).unwrap().name(), Some("y"));
+        assert_eq!(s.apply(|v| v.is_some()).name(), Some("y"));
+
+        let s = s.drop_none();
+        assert_eq!(s.name(), Some("y"));
+
+        let s = s.map(|v| v * 2).sort_values(false);
+        assert_eq!(s.name(), Some("y"));
+        assert_eq!(s.into_values(), vec![6, 2]);
+
+        let s = Series::from_iter_checked(vec![('a', Some(1.0)), ('b', None), ('c', Some(3.0))]).unwrap().rename("f");
+        let cond = Series::from_iter_checked(vec![('a', true), ('b', false), ('c', true)]).unwrap();
+
+        assert_eq!(s.where_mask(&cond, None).unwrap().name(), Some("f"));
+        assert_eq!(s.mask(&cond, None).unwrap().name(), Some("f"));
+        assert_eq!(s.ffill(None).name(), Some("f"));
+        assert_eq!(s.bfill(None).name(), Some("f"));
+        assert_eq!(s.interpolate(FillMethod::Linear).name(), Some("f"));
+        assert_eq!(s.clone().shift_fill(1, Some(0.0)).name(), Some("f"));
+        assert_eq!(s.drop_none().shift(1).name(), Some("f"));
+
+        let mut s = Series::from_iter_checked(vec![('a', 1)]).unwrap().rename("z");
+        s.set_name(None);
+        assert_eq!(s.name(), None);
+    }
+
+    #[test]
+    fn memory_usage() {
+        let s = Series::from_iter_checked(vec![
+            (1u32, Some(String::from("abc"))), (2, None), (3, Some(String::from("defg"))),
+        ]).unwrap();
+
+        let index_usage = s.index().memory_usage(false);
+        let values_usage = std::mem::size_of_val(s.values());
+
+        assert_eq!(s.memory_usage(false), index_usage + values_usage);
+        assert_eq!(s.memory_usage(true), index_usage + values_usage + 7);
+
+        let s = Series::from_iter_checked(vec![(1u32, vec![1u64, 2])]).unwrap();
+        assert_eq!(s.memory_usage(true) - s.memory_usage(false), 16);
+    }
+
+    #[test]
+    fn explode() {
+        let s = Series::from_iter_checked(vec![
+            ('a', vec![1, 2]), ('b', vec![]), ('c', vec![3]),
+        ]).unwrap().rename("x");
+
+        let exploded = s.explode(|l, i| format!("{}.{}", l, i)).unwrap();
+        assert_eq!(exploded.name(), Some("x"));
+
+        let (index, values) = exploded.into_index_values();
+        assert_eq!(index, Index::from_iter(vec!["a.0", "a.1", "c.0"].into_iter().map(String::from)));
+        assert_eq!(values, vec![1, 2, 3]);
+
+        let s = Series::from_iter_checked(vec![('a', vec![1, 2]), ('b', vec![3])]).unwrap();
+        let err = s.explode(|l, _| *l).unwrap_err();
+        assert_eq!(err.label, 'a');
+    }
+
+    #[test]
+    fn clone_eq() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2)]).unwrap().rename("x");
+        let mut t = s.clone();
+
+        assert_eq!(s, t);
+        assert_eq!(t.name(), Some("x"));
+
+        *t.loc_mut(&'a').unwrap() = 10;
+        assert_ne!(s, t);
+        assert_eq!(s.values(), &[1, 2]);
+
+        let t = Series::from_iter_checked(vec![('b', 2), ('a', 1)]).unwrap();
+        assert_ne!(s, t);
+
+        let t = Series::from_iter_checked(vec![('a', 1), ('b', 2)]).unwrap();
+        assert_eq!(s, t);
+
+        let s = Series::from_iter_checked(vec![('a', f64::NAN)]).unwrap();
+        assert_ne!(s, s.clone());
+        assert!(s.equals(&s.clone()));
+    }
+
+    #[test]
+    fn from_iter_with_policy() {
+        let pairs = vec![('a', 1), ('b', 2), ('a', 3), ('c', 4), ('a', 5)];
+
+        let err = Series::from_iter_with_policy(pairs.clone(), DuplicatePolicy::Error).unwrap_err();
+        assert_eq!(err.label, 'a');
+
+        let (index, values) =
+            Series::from_iter_with_policy(pairs.clone(), DuplicatePolicy::KeepFirst).unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![1, 2, 4]);
+
+        let s = Series::from_iter_with_policy(pairs.clone(), DuplicatePolicy::KeepLast).unwrap();
+        assert_eq!(s.into_values(), vec![5, 2, 4]);
+
+        let s = Series::from_iter_with_policy(pairs.clone(), DuplicatePolicy::Aggregate(Box::new(|a, b| a + b))).unwrap();
+        assert_eq!(s.into_values(), vec![9, 2, 4]);
+
+        let s = Series::from_iter_with_policy(vec![('x', 1)], DuplicatePolicy::Error).unwrap();
+        assert_eq!(s.into_values(), vec![1]);
+    }
+
+    #[test]
+    fn split() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap().rename("x");
+
+        let (head, tail) = s.clone().split_at(1);
+        assert_eq!(head.name(), Some("x"));
+        assert_eq!(tail.name(), Some("x"));
+
+        let (index, values) = head.into_index_values();
+        assert_eq!(index, Index::from_iter("a".chars()));
+        assert_eq!(values, vec![1]);
+
+        let (index, values) = tail.into_index_values();
+        assert_eq!(index, Index::from_iter("bcd".chars()));
+        assert_eq!(values, vec![2, 3, 4]);
+
+        let mut head = s;
+        let tail = head.split_off(4);
+        assert!(tail.is_empty());
+        assert_eq!(head.len(), 4);
+
+        let tail = head.split_off(0);
+        assert!(head.is_empty());
+        assert_eq!(tail.into_values(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn permutations() {
+        let s = Series::from_iter_checked(vec![('c', 1), ('a', 2), ('d', 3), ('b', 4)]).unwrap();
+
+        let (index, values) = s.take(&[3, 1]).unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("ba".chars()));
+        assert_eq!(values, vec![4, 2]);
+
+        assert!(s.take(&[0, 4]).is_none());
+        assert!(s.take(&[1, 1]).is_none());
+
+        let mut t = s.clone().rename("x");
+        t.apply_permutation(&s.index().arg_sort()).unwrap();
+        assert_eq!(t.name(), Some("x"));
+
+        let (index, values) = t.clone().into_index_values();
+        assert_eq!(index, Index::from_iter("abcd".chars()));
+        assert_eq!(values, vec![2, 4, 1, 3]);
+
+        let err = t.apply_permutation(&[0, 1, 1, 2]).unwrap_err();
+        assert_eq!(err.permutation, vec![0, 1, 1, 2]);
+        assert_eq!(err.len, 4);
+        assert!(t.apply_permutation(&[0, 1, 2]).is_err());
+        assert!(t.apply_permutation(&[0, 1, 2, 4]).is_err());
+        assert_eq!(t.values(), &[2, 4, 1, 3]);
+
+        t.reverse();
+
+        let (index, values) = t.into_index_values();
+        assert_eq!(index, Index::from_iter("dcba".chars()));
+        assert_eq!(values, vec![3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn drain_filter() {
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', -2), ('c', 3), ('d', -4)]).unwrap().rename("x");
+
+        let drained = s.drain_filter(|_, v| *v < 0);
+        assert_eq!(drained, vec![('b', -2), ('d', -4)]);
+        assert_eq!(s.name(), Some("x"));
+
+        let (index, values) = s.clone().into_index_values();
+        assert_eq!(index, Index::from_iter("ac".chars()));
+        assert_eq!(values, vec![1, 3]);
+
+        assert!(s.drain_filter(|l, _| *l == 'z').is_empty());
+        assert_eq!(s.len(), 2);
+    }
+
+    #[test]
+    fn selection() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap();
+
+        let (index, values) = s.iloc_multi(&[3, 0]).unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("da".chars()));
+        assert_eq!(values, vec![4, 1]);
+
+        assert!(s.iloc_multi(&[0, 4]).is_none());
+        assert!(s.iloc_multi(&[1, 1]).is_none());
+
+        let (index, values) = s.iloc_range(..2).unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("ab".chars()));
+        assert_eq!(values, vec![1, 2]);
+
+        let (index, values) = s.loc_multi(&['c', 'b']).unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("cb".chars()));
+        assert_eq!(values, vec![3, 2]);
+
+        assert!(s.loc_multi(&['c', 'z']).is_none());
+        assert!(s.loc_multi(&['c', 'c']).is_none());
+
+        let (index, values) = s.loc_range(&'b'..=&'d').unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("bcd".chars()));
+        assert_eq!(values, vec![2, 3, 4]);
+
+        assert_eq!(s.loc_range(&'b'..&'d').unwrap().into_values(), vec![2, 3]);
+        assert!(s.loc_range(&'b'..&'z').is_none());
+
+        let (index, values) = s.bloc(vec![true, false, false, true]).unwrap().into_index_values();
+        assert_eq!(index, Index::from_iter("ad".chars()));
+        assert_eq!(values, vec![1, 4]);
+
+        assert!(s.bloc([true, false].iter()).is_none());
+    }
+
+    #[test]
+    fn retain() {
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap();
+
+        s.retain(|l, v| *l != 'a' && v % 2 == 0);
+
+        assert_eq!(s.index(), &Index::from_iter("bd".chars()));
+        assert_eq!(s.values(), &[2, 4]);
+
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap();
+
+        s.retain_labels(|l| *l > 'b');
+        assert_eq!(s.values(), &[3, 4]);
+
+        s.retain_values(|v| *v < 4);
+        assert_eq!(s.index(), &Index::from_iter("c".chars()));
+    }
+
+    #[test]
+    fn duplicated() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 1), ('d', 3), ('e', 1), ('f', 2)]).unwrap();
+
+        let (index, values) = s.duplicated(Keep::First).into_index_values();
+
+        assert_eq!(index, Index::from_iter("abcdef".chars()));
+        assert_eq!(values, vec![false, false, true, false, true, true]);
+
+        assert_eq!(s.duplicated(Keep::Last).into_values(), vec![true, true, true, false, false, false]);
+        assert_eq!(s.duplicated(Keep::All).into_values(), vec![true, true, true, false, true, true]);
+
+        let s = Series::from_iter_checked(vec![('a', 0.0), ('b', f64::NAN), ('c', -0.0), ('d', -f64::NAN), ('e', 1.5)]).unwrap();
+
+        assert_eq!(s.duplicated(Keep::First).into_values(), vec![false, false, true, true, false]);
+    }
+
+    #[test]
+    fn drop_duplicate_values() {
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 1), ('d', 3), ('e', 1), ('f', 2)]).unwrap();
+
+        s.drop_duplicate_values(Keep::Last);
+
+        let (index, values) = s.into_index_values();
+
+        assert_eq!(index, Index::from_iter("def".chars()));
+        assert_eq!(values, vec![3, 1, 2]);
+
+        let mut s = Series::from_iter_checked(vec![('a', None), ('b', Some(2)), ('c', None)]).unwrap();
+
+        s.drop_duplicate_values(Keep::All);
+
+        assert_eq!(s.into_values(), vec![Some(2)]);
+    }
+
+    #[test]
+    fn where_mask() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3)]).unwrap();
+        let cond = Series::from_iter_checked(vec![('c', true), ('a', false), ('b', true)]).unwrap();
+
+        let (index, values) = s.where_mask(&cond, 0).unwrap().into_index_values();
+
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![0, 2, 3]);
+
+        assert_eq!(s.mask(&cond, 0).unwrap().into_values(), vec![1, 0, 0]);
+
+        let cond = Series::from_iter_checked(vec![('a', true), ('b', true), ('d', true)]).unwrap();
+        let err = s.where_mask(&cond, 0).unwrap_err();
+
+        assert_eq!(err.missing, vec!['c']);
+        assert_eq!(err.extra, vec!['d']);
+    }
+
+    #[test]
+    fn sort_values() {
+        let s = Series::from_iter_checked(vec![
+            ('a', 3.0), ('b', f64::NAN), ('c', 1.0), ('d', 3.0), ('e', 2.0),
+        ]).unwrap();
+
+        let (index, values) = s.sort_values(true).into_index_values();
+
+        assert_eq!(index, Index::from_iter("ceadb".chars()));
+        assert_eq!(&values[..4], &[1.0, 2.0, 3.0, 3.0]);
+        assert!(values[4].is_nan());
+
+        let mut s = Series::from_iter_checked(vec![
+            ('a', 3), ('b', 5), ('c', 1), ('d', 3), ('e', 2),
+        ]).unwrap();
+
+        s.sort_values_in_place(false);
+
+        let (index, values) = s.into_index_values();
+
+        assert_eq!(index, Index::from_iter("badec".chars()));
+        assert_eq!(values, vec![5, 3, 3, 2, 1]);
+    }
+
+    #[test]
+    fn sort_by_index() {
+        let s = Series::from_iter_checked(vec![(3, 'a'), (1, 'b'), (4, 'c'), (2, 'd')]).unwrap();
+
+        let (index, values) = s.sort_by_index().into_index_values();
+
+        assert_eq!(index, Index::from_iter(1..=4));
+        assert_eq!(values, vec!['b', 'd', 'a', 'c']);
+    }
+
+    #[test]
+    fn clip() {
+        let s = Series::from_iter_checked(vec![
+            ('a', -3.0), ('b', f64::NAN), ('c', 1.5), ('d', 7.0),
+        ]).unwrap();
+
+        let (index, values) = s.clip(0.0, 5.0).into_index_values();
+
+        assert_eq!(index, Index::from_iter("abcd".chars()));
+        assert_eq!(values[0], 0.0);
+        assert!(values[1].is_nan());
+        assert_eq!(&values[2..], &[1.5, 5.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 'q'), ('b', 'c'), ('c', 'x')]).unwrap();
+
+        assert_eq!(s.clip_lower('d').into_values(), vec!['q', 'd', 'x']);
+
+        let s = Series::from_iter_checked(vec![('a', 'q'), ('b', 'c'), ('c', 'x')]).unwrap();
+
+        assert_eq!(s.clip_upper('r').into_values(), vec!['q', 'c', 'r']);
+    }
+
+    #[test]
+    fn between() {
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', f64::NAN), ('d', 3.0)]).unwrap();
+
+        let (index, values) = s.between(&1.0, &3.0, true).into_index_values();
+
+        assert_eq!(index, Index::from_iter("abcd".chars()));
+        assert_eq!(values, vec![true, true, false, true]);
+
+        assert_eq!(s.between(&1.0, &3.0, false).into_values(), vec![false, true, false, false]);
+
+        let s = Series::from_iter_checked(vec![('a', Some('m')), ('b', None), ('c', Some('z'))]).unwrap();
+
+        assert_eq!(s.between(&'a', &'n', true).into_values(), vec![true, false, false]);
+    }
+
+    #[test]
+    fn isin() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 40)]).unwrap();
+
+        let (index, values) = s.isin(vec![3, 1, 7]).into_index_values();
+
+        assert_eq!(index, Index::from_iter("abcd".chars()));
+        assert_eq!(values, vec![true, false, true, false]);
+
+        assert_eq!(s.isin(20..50).into_values(), vec![false, false, false, true]);
+
+        let s = Series::from_iter_checked(vec![('a', Some(1)), ('b', None), ('c', Some(3))]).unwrap();
+
+        assert_eq!(s.isin(0..100).into_values(), vec![true, false, true]);
+        assert_eq!(s.isin(Some(3)).into_values(), vec![false, false, true]);
+
+        let s = Series::from_iter_checked(vec![('a', 0.5), ('b', 1.5)]).unwrap();
+
+        assert_eq!(s.isin_slice(&[1.5, 2.5]).into_values(), vec![false, true]);
+    }
+
+    #[test]
+    fn concat_all() {
+        let series = vec![
+            Series::from_iter_checked(vec![(0, 'a'), (1, 'b')]).unwrap(),
+            Series::from_iter_checked(vec![(2, 'c')]).unwrap(),
+            Series::new(),
+            Series::from_iter_checked(vec![(3, 'd'), (4, 'e')]).unwrap(),
+        ];
+
+        let (index, values) = concat(series).unwrap().into_index_values();
+
+        assert_eq!(index, Index::from_iter(0..=4));
+        assert_eq!(values, vec!['a', 'b', 'c', 'd', 'e']);
+
+        let series = vec![
+            Series::from_iter_checked(vec![(0, 'a'), (1, 'b')]).unwrap(),
+            Series::from_iter_checked(vec![(1, 'c'), (2, 'd')]).unwrap(),
+            Series::from_iter_checked(vec![(2, 'e'), (0, 'f')]).unwrap(),
+        ];
+
+        let err = concat(series).unwrap_err();
+
+        assert_eq!(err.labels, vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn shift() {
+        let s = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]).unwrap();
+
+        let (index, values) = s.shift(1).into_index_values();
+
+        assert_eq!(index, Index::from_iter(0..=3));
+        assert_eq!(values, vec![None, Some('a'), Some('b'), Some('c')]);
+
+        let s = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]).unwrap();
+
+        assert_eq!(s.shift(-2).into_values(), vec![Some('c'), Some('d'), None, None]);
+
+        let s = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]).unwrap();
+
+        assert_eq!(s.shift(0).into_values(), vec![Some('a'), Some('b'), Some('c'), Some('d')]);
+
+        let s = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]).unwrap();
+
+        assert_eq!(s.shift(42).into_values(), vec![None, None, None, None]);
+    }
+
+    #[test]
+    fn shift_fill() {
+        let s = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]).unwrap();
+
+        let (index, values) = s.shift_fill(2, 'x').into_index_values();
+
+        assert_eq!(index, Index::from_iter(0..=3));
+        assert_eq!(values, vec!['x', 'x', 'a', 'b']);
+
+        let s = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]).unwrap();
+
+        assert_eq!(s.shift_fill(-1, 'x').into_values(), vec!['b', 'c', 'd', 'x']);
+
+        let s = Series::from_iter_checked(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]).unwrap();
+
+        assert_eq!(s.shift_fill(-42, 'x').into_values(), vec!['x', 'x', 'x', 'x']);
+    }
+
     #[test]
     fn fill_none() {
         let s = Series::from_iter_checked(vec![
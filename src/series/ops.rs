@@ -0,0 +1,309 @@
+//! Arithmetic operators for use with `Series`.
+
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Sub;
+
+use crate::index::Join;
+use crate::traits::Integer;
+use crate::traits::Label;
+use crate::traits::RawType;
+
+use super::Series;
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: RawType,
+{
+    /// Aligns two `Series` on an outer join of their labels, and combines the
+    /// values of each label contained in both using the given function. Labels
+    /// contained in only one of the `Series` are given a value of `None`.
+    fn combine_aligned<F>(&self, other: &Self, mut func: F) -> Series<L, Option<V>>
+    where
+        F: FnMut(V, V) -> V,
+    {
+        self.zip_with(other, Join::Outer, |lv, rv| match (lv, rv) {
+            (Some(lv), Some(rv)) => Some(func(lv.clone(), rv.clone())),
+            _ => None,
+        })
+    }
+
+    /// Adds a scalar to each value in this `Series`, preserving the `Index`.
+    pub fn add_scalar(self, rhs: V) -> Self
+    where
+        V: Add<Output = V>,
+    {
+        self.map(|v| v + rhs.clone())
+    }
+
+    /// Subtracts a scalar from each value in this `Series`, preserving the
+    /// `Index`.
+    pub fn sub_scalar(self, rhs: V) -> Self
+    where
+        V: Sub<Output = V>,
+    {
+        self.map(|v| v - rhs.clone())
+    }
+
+    /// Multiplies each value in this `Series` by a scalar, preserving the
+    /// `Index`.
+    pub fn mul_scalar(self, rhs: V) -> Self
+    where
+        V: Mul<Output = V>,
+    {
+        self.map(|v| v * rhs.clone())
+    }
+
+    /// Divides each value in this `Series` by a scalar, preserving the `Index`.
+    pub fn div_scalar(self, rhs: V) -> Self
+    where
+        V: Div<Output = V>,
+    {
+        self.map(|v| v / rhs.clone())
+    }
+}
+
+/// Helper macro to implement a label-aligned binary operator for `Series`.
+macro_rules! impl_aligned_op {
+    ($op_trait:ident, $op_func:ident) => {
+        impl<'a, L, V> $op_trait<&'a Series<L, V>> for &'a Series<L, V>
+        where
+            L: Label,
+            V: RawType + $op_trait<Output = V>,
+        {
+            type Output = Series<L, Option<V>>;
+
+            fn $op_func(self, rhs: &'a Series<L, V>) -> Self::Output {
+                self.combine_aligned(rhs, $op_trait::$op_func)
+            }
+        }
+
+        impl<L, V> $op_trait<Series<L, V>> for Series<L, V>
+        where
+            L: Label,
+            V: RawType + $op_trait<Output = V>,
+        {
+            type Output = Series<L, Option<V>>;
+
+            fn $op_func(self, rhs: Series<L, V>) -> Self::Output {
+                $op_trait::$op_func(&self, &rhs)
+            }
+        }
+    };
+}
+
+/// Helper macro to implement a scalar broadcast operator for `Series`.
+macro_rules! impl_scalar_op {
+    ($op_trait:ident, $op_func:ident, $scalar_func:ident) => {
+        impl<L, V> $op_trait<V> for Series<L, V>
+        where
+            L: Label,
+            V: RawType + $op_trait<Output = V>,
+        {
+            type Output = Series<L, V>;
+
+            fn $op_func(self, rhs: V) -> Self::Output {
+                self.$scalar_func(rhs)
+            }
+        }
+    };
+}
+
+/// Helper macro to implement an overflow-aware integer operation for `Series`,
+/// both label-aligned and as a scalar broadcast.
+macro_rules! impl_overflow_op {
+    ($op_func:ident, $scalar_func:ident, $output:ty, $aligned_doc:expr, $scalar_doc:expr) => {
+        impl<L, V> Series<L, V>
+        where
+            L: Label,
+            V: Integer,
+        {
+            #[doc = $aligned_doc]
+            pub fn $op_func(&self, other: &Self) -> Series<L, Option<V>> {
+                self.zip_with(other, Join::Outer, |lv, rv| match (lv, rv) {
+                    (Some(&lv), Some(&rv)) => Option::from(lv.$op_func(rv)),
+                    _ => None,
+                })
+            }
+
+            #[doc = $scalar_doc]
+            pub fn $scalar_func(self, rhs: V) -> Series<L, $output> {
+                self.map(|v| v.$op_func(rhs))
+            }
+        }
+    };
+}
+
+impl_overflow_op!(
+    checked_add, checked_add_scalar, Option<V>,
+    "Adds two `Series` aligned on an outer join of their labels. Labels not \
+    found in both `Series`, or whose sum overflows, are given a value of `None`.",
+    "Adds a scalar to each value, preserving the `Index`. Values that overflow \
+    become `None`."
+);
+impl_overflow_op!(
+    checked_sub, checked_sub_scalar, Option<V>,
+    "Subtracts two `Series` aligned on an outer join of their labels. Labels \
+    not found in both `Series`, or whose difference overflows, are given a \
+    value of `None`.",
+    "Subtracts a scalar from each value, preserving the `Index`. Values that \
+    overflow become `None`."
+);
+impl_overflow_op!(
+    checked_mul, checked_mul_scalar, Option<V>,
+    "Multiplies two `Series` aligned on an outer join of their labels. Labels \
+    not found in both `Series`, or whose product overflows, are given a value \
+    of `None`.",
+    "Multiplies each value by a scalar, preserving the `Index`. Values that \
+    overflow become `None`."
+);
+impl_overflow_op!(
+    wrapping_add, wrapping_add_scalar, V,
+    "Adds two `Series` aligned on an outer join of their labels, wrapping on \
+    overflow. Labels not found in both `Series` are given a value of `None`.",
+    "Adds a scalar to each value, wrapping on overflow, preserving the `Index`."
+);
+impl_overflow_op!(
+    wrapping_sub, wrapping_sub_scalar, V,
+    "Subtracts two `Series` aligned on an outer join of their labels, wrapping \
+    on overflow. Labels not found in both `Series` are given a value of `None`.",
+    "Subtracts a scalar from each value, wrapping on overflow, preserving the \
+    `Index`."
+);
+impl_overflow_op!(
+    wrapping_mul, wrapping_mul_scalar, V,
+    "Multiplies two `Series` aligned on an outer join of their labels, \
+    wrapping on overflow. Labels not found in both `Series` are given a value \
+    of `None`.",
+    "Multiplies each value by a scalar, wrapping on overflow, preserving the \
+    `Index`."
+);
+impl_overflow_op!(
+    saturating_add, saturating_add_scalar, V,
+    "Adds two `Series` aligned on an outer join of their labels, saturating on \
+    overflow. Labels not found in both `Series` are given a value of `None`.",
+    "Adds a scalar to each value, saturating on overflow, preserving the \
+    `Index`."
+);
+impl_overflow_op!(
+    saturating_sub, saturating_sub_scalar, V,
+    "Subtracts two `Series` aligned on an outer join of their labels, \
+    saturating on overflow. Labels not found in both `Series` are given a \
+    value of `None`.",
+    "Subtracts a scalar from each value, saturating on overflow, preserving \
+    the `Index`."
+);
+impl_overflow_op!(
+    saturating_mul, saturating_mul_scalar, V,
+    "Multiplies two `Series` aligned on an outer join of their labels, \
+    saturating on overflow. Labels not found in both `Series` are given a \
+    value of `None`.",
+    "Multiplies each value by a scalar, saturating on overflow, preserving the \
+    `Index`."
+);
+
+// Label-aligned operators produce `None` for labels not found in both `Series`.
+impl_aligned_op!(Add, add);
+impl_aligned_op!(Sub, sub);
+impl_aligned_op!(Mul, mul);
+impl_aligned_op!(Div, div);
+
+impl_scalar_op!(Add, add, add_scalar);
+impl_scalar_op!(Sub, sub, sub_scalar);
+impl_scalar_op!(Mul, mul, mul_scalar);
+impl_scalar_op!(Div, div, div_scalar);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+
+    #[test]
+    fn aligned_ops() {
+        let a = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3)]).unwrap();
+        let b = Series::from_iter_checked(vec![('c', 10), ('d', 20), ('a', 30)]).unwrap();
+
+        let expected_index = Index::from_iter("abcd".chars());
+
+        let (index, values) = (&a + &b).into_index_values();
+        assert_eq!(index, expected_index);
+        assert_eq!(values, vec![Some(31), None, Some(13), None]);
+
+        let (index, values) = (&a - &b).into_index_values();
+        assert_eq!(index, expected_index);
+        assert_eq!(values, vec![Some(-29), None, Some(-7), None]);
+
+        let (index, values) = (&a * &b).into_index_values();
+        assert_eq!(index, expected_index);
+        assert_eq!(values, vec![Some(30), None, Some(30), None]);
+
+        let (index, values) = (&b / &a).into_index_values();
+        assert_eq!(index, Index::from_iter("cdab".chars()));
+        assert_eq!(values, vec![Some(3), None, Some(30), None]);
+
+        let (index, values) = (a + b).into_index_values();
+        assert_eq!(index, expected_index);
+        assert_eq!(values, vec![Some(31), None, Some(13), None]);
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', 4.0)]).unwrap();
+        let expected_index = Index::from_iter("abc".chars());
+
+        let (index, values) = s.add_scalar(1.0).into_index_values();
+        assert_eq!(index, expected_index);
+        assert_eq!(values, vec![2.0, 3.0, 5.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', 4.0)]).unwrap();
+
+        assert_eq!((s + 1.0).into_values(), vec![2.0, 3.0, 5.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', 4.0)]).unwrap();
+
+        assert_eq!((s - 1.0).into_values(), vec![0.0, 1.0, 3.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', 4.0)]).unwrap();
+
+        assert_eq!((s * 2.0).into_values(), vec![2.0, 4.0, 8.0]);
+
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', 4.0)]).unwrap();
+
+        assert_eq!((s / 2.0).into_values(), vec![0.5, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn overflow_ops() {
+        let a = Series::from_iter_checked(vec![('a', 100i8), ('b', -100), ('c', 1)]).unwrap();
+        let b = Series::from_iter_checked(vec![('c', 2i8), ('a', 100), ('b', 100), ('d', 3)]).unwrap();
+
+        assert_eq!(a.checked_add(&b).into_values(), vec![None, Some(0), Some(3), None]);
+        assert_eq!(a.checked_sub(&b).into_values(), vec![Some(0), None, Some(-1), None]);
+        assert_eq!(a.checked_mul(&b).into_values(), vec![None, None, Some(2), None]);
+
+        assert_eq!(a.wrapping_add(&b).into_values(), vec![Some(-56), Some(0), Some(3), None]);
+        assert_eq!(a.wrapping_sub(&b).into_values(), vec![Some(0), Some(56), Some(-1), None]);
+        assert_eq!(a.saturating_add(&b).into_values(), vec![Some(127), Some(0), Some(3), None]);
+        assert_eq!(a.saturating_sub(&b).into_values(), vec![Some(0), Some(-128), Some(-1), None]);
+        assert_eq!(a.saturating_mul(&b).into_values(), vec![Some(127), Some(-128), Some(2), None]);
+
+        let (index, values) = a.clone().checked_add_scalar(28).into_index_values();
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![None, Some(-72), Some(29)]);
+
+        assert_eq!(a.clone().checked_mul_scalar(2).into_values(), vec![None, None, Some(2)]);
+        assert_eq!(a.clone().wrapping_mul_scalar(2).into_values(), vec![-56, 56, 2]);
+        assert_eq!(a.clone().saturating_add_scalar(28).into_values(), vec![127, -72, 29]);
+        assert_eq!(a.saturating_sub_scalar(29).into_values(), vec![71, -128, -28]);
+
+        let u = Series::from_iter_checked(vec![('a', 1u32), ('b', 5)]).unwrap();
+        assert_eq!(u.clone().checked_sub_scalar(2).into_values(), vec![None, Some(3)]);
+        assert_eq!(u.clone().wrapping_sub_scalar(2).into_values(), vec![u32::MAX, 3]);
+        assert_eq!(u.saturating_sub_scalar(2).into_values(), vec![0, 3]);
+    }
+}
@@ -0,0 +1,108 @@
+//! Parallel iteration over `Series` via `rayon`.
+
+use rayon::iter::IndexedParallelIterator;
+use rayon::iter::IntoParallelIterator;
+use rayon::iter::IntoParallelRefIterator;
+use rayon::iter::IntoParallelRefMutIterator;
+use rayon::iter::ParallelIterator;
+
+use crate::traits::Label;
+use crate::traits::Storable;
+
+use super::Series;
+
+impl<L, V> Series<L, V>
+where
+    L: Label + Sync,
+    V: Storable,
+{
+    /// Parallel version of `iter`.
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (&L, &V)>
+    where
+        V: Sync,
+    {
+        self.0.par_iter().zip(self.1.par_iter())
+    }
+
+    /// Returns a parallel iterator over references to the values in this
+    /// `Series`, in order.
+    pub fn par_values(&self) -> impl IndexedParallelIterator<Item = &V>
+    where
+        V: Sync,
+    {
+        self.1.par_iter()
+    }
+
+    /// Returns a parallel iterator over mutable references to the values in
+    /// this `Series`, in order.
+    pub fn par_values_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut V> {
+        self.1.par_iter_mut()
+    }
+
+    /// Parallel version of `map`. The function is called on the values from
+    /// multiple threads, in no particular order.
+    pub fn par_map<F, C>(self, map_func: F) -> Series<L, C>
+    where
+        F: Fn(V) -> C + Sync + Send,
+        C: Storable,
+    {
+        let (index, values, name) = (self.0, self.1, self.2);
+
+        let mapped_values =
+            values
+            .into_par_iter()
+            .map(map_func)
+            .collect()
+        ;
+
+        Series::new_inner(index, mapped_values).named(name)
+    }
+
+    /// Parallel version of `apply`. The function is called on the values from
+    /// multiple threads, in no particular order.
+    pub fn par_apply<F, C>(&self, apply_func: F) -> Series<L, C>
+    where
+        V: Sync,
+        F: Fn(&V) -> C + Sync + Send,
+        C: Storable,
+    {
+        let applied_values = self.1.par_iter().map(apply_func).collect();
+
+        Series::new_inner(self.0.clone(), applied_values).named(self.2.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+
+    #[test]
+    fn par_iter() {
+        let s = Series::from_iter_checked((0..1000).map(|i| (i, i as u64))).unwrap();
+
+        let pairs = s.par_iter().map(|(&l, &v)| (l, v)).collect::<Vec<_>>();
+        assert_eq!(pairs, s.iter().map(|(&l, &v)| (l, v)).collect::<Vec<_>>());
+
+        assert_eq!(s.par_values().sum::<u64>(), 999 * 1000 / 2);
+    }
+
+    #[test]
+    fn par_map() {
+        let mut s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3)]).unwrap().rename("x");
+
+        s.par_values_mut().for_each(|v| *v *= 10);
+        assert_eq!(s.values(), &[10, 20, 30]);
+
+        let t = s.par_apply(|v| v + 1);
+        assert_eq!(t.name(), Some("x"));
+        assert_eq!(t.into_values(), vec![11, 21, 31]);
+
+        let (index, values) = s.par_map(|v| v.to_string()).into_index_values();
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec!["10", "20", "30"]);
+    }
+}
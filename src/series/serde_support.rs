@@ -0,0 +1,248 @@
+//! Serialization and deserialization of `Series` via `serde`.
+//!
+//! By default, a `Series` is serialized as a map from labels to values. To
+//! instead use parallel arrays of labels and values, use the `arrays` module
+//! with `#[serde(with = "rustable::series::serde_support::arrays")]`.
+
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::marker::PhantomData;
+
+use serde::de::Deserialize;
+use serde::de::Deserializer;
+use serde::de::Error as DeError;
+use serde::de::MapAccess;
+use serde::de::Visitor;
+use serde::ser::Serialize;
+use serde::ser::SerializeMap;
+use serde::ser::Serializer;
+
+use crate::index::Index;
+use crate::traits::Label;
+use crate::traits::Storable;
+
+use super::DuplicateIndexLabel;
+use super::Series;
+
+/// Serializes a `Series` as a map from labels to values, in order.
+impl<L, V> Serialize for Series<L, V>
+where
+    L: Label + Serialize,
+    V: Storable + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (label, value) in self.iter() { map.serialize_entry(label, value)?; }
+        map.end()
+    }
+}
+
+struct SeriesVisitor<L, V>(PhantomData<(L, V)>);
+
+impl<'de, L, V> Visitor<'de> for SeriesVisitor<L, V>
+where
+    L: Label + Deserialize<'de>,
+    V: Storable + Deserialize<'de>,
+{
+    type Value = Series<L, V>;
+
+    fn expecting(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "a map from unique index labels to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let capacity = map.size_hint().unwrap_or(0);
+        let (mut index, mut values) = (Index::with_capacity(capacity), Vec::with_capacity(capacity));
+
+        while let Some((label, value)) = map.next_entry::<L, V>()? {
+            if index.contains(&label) {
+                return Err(A::Error::custom(DuplicateIndexLabel { label }));
+            }
+
+            index.push(label);
+            values.push(value);
+        }
+
+        Ok(Series::new_inner(index, values))
+    }
+}
+
+/// Deserializes a `Series` from a map from labels to values. If a label is
+/// repeated, an error is returned.
+impl<'de, L, V> Deserialize<'de> for Series<L, V>
+where
+    L: Label + Deserialize<'de>,
+    V: Storable + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(SeriesVisitor(PhantomData))
+    }
+}
+
+/// Serializes and deserializes a `Series` as a struct with parallel `index`
+/// and `values` arrays, for use with `#[serde(with = "...")]`.
+pub mod arrays {
+    use serde::de::Deserialize;
+    use serde::de::Deserializer;
+    use serde::de::Error as DeError;
+    use serde::ser::Serialize;
+    use serde::ser::SerializeStruct;
+    use serde::ser::Serializer;
+
+    use crate::traits::Label;
+    use crate::traits::Storable;
+
+    use super::Series;
+
+    const FIELDS: &[&str] = &["index", "values"];
+
+    /// Serializes a `Series` as a struct with `index` and `values` arrays.
+    pub fn serialize<L, V, S>(series: &Series<L, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        L: Label + Serialize,
+        V: Storable + Serialize,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Series", FIELDS.len())?;
+        state.serialize_field("index", &series.0)?;
+        state.serialize_field("values", &series.1)?;
+        state.end()
+    }
+
+    /// Deserializes a `Series` from a struct with `index` and `values`
+    /// arrays. If a label is repeated or the arrays have different lengths, an
+    /// error is returned.
+    pub fn deserialize<'de, L, V, D>(deserializer: D) -> Result<Series<L, V>, D::Error>
+    where
+        L: Label + Deserialize<'de>,
+        V: Storable + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let visitor = visitor::ArraysVisitor::<L, V>::new();
+        let (index, values) = deserializer.deserialize_struct("Series", FIELDS, visitor)?;
+
+        Series::from_values(index, values).map_err(D::Error::custom)
+    }
+
+    mod visitor {
+        use std::fmt::Formatter;
+        use std::fmt::Result as FmtResult;
+        use std::marker::PhantomData;
+
+        use serde::de::Deserialize;
+        use serde::de::Error as DeError;
+        use serde::de::IgnoredAny;
+        use serde::de::MapAccess;
+        use serde::de::SeqAccess;
+        use serde::de::Visitor;
+
+        use crate::index::Index;
+        use crate::traits::Label;
+
+        pub(super) struct ArraysVisitor<L, V>(PhantomData<(L, V)>);
+
+        impl<L, V> ArraysVisitor<L, V> {
+            pub(super) fn new() -> Self {
+                Self(PhantomData)
+            }
+        }
+
+        impl<'de, L, V> Visitor<'de> for ArraysVisitor<L, V>
+        where
+            L: Label + Deserialize<'de>,
+            V: Deserialize<'de>,
+        {
+            type Value = (Index<L>, Vec<V>);
+
+            fn expecting(&self, f: &mut Formatter) -> FmtResult {
+                write!(f, "a struct with index and values arrays")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let index = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let values = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(1, &self))?;
+                Ok((index, values))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let (mut index, mut values) = (None, None);
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "index" if index.is_some() => { return Err(A::Error::duplicate_field("index")); },
+                        "values" if values.is_some() => { return Err(A::Error::duplicate_field("values")); },
+                        "index" => { index = Some(map.next_value()?); },
+                        "values" => { values = Some(map.next_value()?); },
+                        _ => { map.next_value::<IgnoredAny>()?; },
+                    }
+                }
+
+                let index = index.ok_or_else(|| A::Error::missing_field("index"))?;
+                let values = values.ok_or_else(|| A::Error::missing_field("values"))?;
+
+                Ok((index, values))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_round_trip() {
+        let s = Series::from_iter_checked(vec![('b', 1), ('a', 2)]).unwrap();
+        let json = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(json, r#"{"b":1,"a":2}"#);
+
+        let (index, values) = serde_json::from_str::<Series<char, i32>>(&json).unwrap().into_index_values();
+        assert_eq!(index, s.0);
+        assert_eq!(values, vec![1, 2]);
+
+        let err = serde_json::from_str::<Series<char, i32>>(r#"{"a":1,"a":2}"#).unwrap_err();
+        assert!(err.to_string().contains("duplicate"));
+
+        let s = serde_json::from_str::<Series<u32, Option<f64>>>(r#"{"3":null,"1":1.5}"#).unwrap();
+        assert_eq!(s.into_values(), vec![None, Some(1.5)]);
+    }
+
+    #[test]
+    fn arrays_round_trip() {
+        #[derive(Debug)]
+        struct Wrapper(Series<char, i32>);
+
+        impl Serialize for Wrapper {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                arrays::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Wrapper {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                arrays::deserialize(deserializer).map(Wrapper)
+            }
+        }
+
+        let s = Series::from_iter_checked(vec![('b', 1), ('a', 2)]).unwrap();
+        let json = serde_json::to_string(&Wrapper(s)).unwrap();
+
+        assert_eq!(json, r#"{"index":["b","a"],"values":[1,2]}"#);
+
+        let s = serde_json::from_str::<Wrapper>(&json).unwrap().0;
+        assert_eq!(s.into_values(), vec![1, 2]);
+
+        let s = serde_json::from_str::<Wrapper>(r#"[["x"],[7]]"#).unwrap().0;
+        assert_eq!(s.into_values(), vec![7]);
+
+        let err = serde_json::from_str::<Wrapper>(r#"{"index":["a","b"],"values":[1]}"#).unwrap_err();
+        assert!(err.to_string().contains("length mismatch"));
+
+        let err = serde_json::from_str::<Wrapper>(r#"{"index":["a","a"],"values":[1,2]}"#).unwrap_err();
+        assert!(err.to_string().contains("duplicate"));
+
+        let err = serde_json::from_str::<Wrapper>(r#"{"index":["a"]}"#).unwrap_err();
+        assert!(err.to_string().contains("values"));
+    }
+}
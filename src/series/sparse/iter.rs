@@ -0,0 +1,65 @@
+use std::iter::Enumerate;
+use std::iter::Peekable;
+use std::iter::Zip;
+use std::slice::Iter as SliceIter;
+
+use super::SeriesSparse;
+
+use crate::traits::Label;
+use crate::traits::RawType;
+use crate::index::iter::Iter as IndexIter;
+
+pub struct Iter<'a, L: Label, R: RawType> {
+    labels: Enumerate<IndexIter<'a, L>>,
+    present: Peekable<Zip<SliceIter<'a, usize>, SliceIter<'a, R>>>,
+    fill: Option<&'a R>,
+}
+
+impl<'a, L, R> Iter<'a, L, R>
+where
+    L: Label,
+    R: RawType,
+{
+    pub(crate) fn new(sparse: &'a SeriesSparse<L, R>) -> Self {
+        Self {
+            labels: sparse.0.iter().enumerate(),
+            present: sparse.1.iter().zip(sparse.2.iter()).peekable(),
+            fill: sparse.3.as_ref(),
+        }
+    }
+}
+
+impl<'a, L, R> Iterator for Iter<'a, L, R>
+where
+    L: Label,
+    R: RawType,
+{
+    type Item = (&'a L, Option<&'a R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (pos, label) = self.labels.next()?;
+
+        // Stored positions are in ascending order, so only the next stored
+        // position needs to be checked.
+        let value = match self.present.peek() {
+            Some((&p, _)) if p == pos => self.present.next().map(|(_, v)| v),
+            _ => self.fill,
+        };
+
+        Some((label, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.labels.size_hint()
+    }
+}
+
+impl<'a, L, R> ExactSizeIterator for Iter<'a, L, R>
+where
+    L: Label,
+    R: RawType,
+{
+    fn len(&self) -> usize {
+        self.labels.len()
+    }
+}
@@ -0,0 +1,340 @@
+//! A sparse representation of a `Series` with mostly missing values.
+
+pub mod iter;
+
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use crate::index::Index;
+use crate::traits::Label;
+use crate::traits::RawType;
+
+use super::Series;
+
+pub use self::iter::Iter;
+
+/// A `Series` that only stores its present values, along with their positions
+/// in the `Index`. Useful for large series where most values are missing.
+/// Missing values read as the fill value, if one is set, or as `None`.
+#[derive(Debug)]
+pub struct SeriesSparse<L: Label, R: RawType>(
+    pub(crate) Index<L>,
+    pub(crate) Vec<usize>,
+    pub(crate) Vec<R>,
+    pub(crate) Option<R>,
+);
+
+impl<L, R> SeriesSparse<L, R>
+where
+    L: Label,
+    R: RawType,
+{
+    /// Creates a `SeriesSparse` from a `Series` with `Option` values, storing
+    /// only the non-`None` values and preserving the `Index`.
+    pub fn from_series(series: Series<L, Option<R>>) -> Self {
+        let (index, values) = series.into_index_values();
+
+        let (positions, present) =
+            values
+            .into_iter()
+            .enumerate()
+            .filter_map(|(pos, value)| value.map(|v| (pos, v)))
+            .unzip()
+        ;
+
+        Self(index, positions, present, None)
+    }
+
+    /// Consumes this `SeriesSparse` and creates a `Series` with `Option`
+    /// values, with each missing value replaced by the fill value, if set.
+    pub fn into_series(self) -> Series<L, Option<R>> {
+        let (index, positions, present, fill) = (self.0, self.1, self.2, self.3);
+
+        let mut values = vec![fill; index.len()];
+
+        for (pos, value) in positions.into_iter().zip(present) {
+            values[pos] = Some(value);
+        }
+
+        Series::new_inner(index, values)
+    }
+
+    /// Consumes this `SeriesSparse` and creates a dense `Series`, with each
+    /// missing value replaced by the given fill value.
+    pub fn to_dense(self, fill: R) -> Series<L, R> {
+        let (index, positions, present) = (self.0, self.1, self.2);
+
+        let mut values = vec![fill; index.len()];
+
+        for (pos, value) in positions.into_iter().zip(present) {
+            values[pos] = value;
+        }
+
+        Series::new_inner(index, values)
+    }
+
+    /// Returns a read-only reference to the `Index` of this `SeriesSparse`.
+    pub fn index(&self) -> &Index<L> {
+        &self.0
+    }
+
+    /// Returns the number of label/value pairs, including missing values.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if this `SeriesSparse` contains no label/value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of values that are actually stored.
+    pub fn count_present(&self) -> usize {
+        self.2.len()
+    }
+
+    /// Returns the fraction of values that are actually stored, or `0.0` if
+    /// this `SeriesSparse` is empty.
+    pub fn density(&self) -> f64 {
+        if self.is_empty() { 0.0 } else { self.count_present() as f64 / self.len() as f64 }
+    }
+
+    /// Returns the value that missing values read as, if set.
+    pub fn fill_value(&self) -> Option<&R> {
+        self.3.as_ref()
+    }
+
+    /// Sets the value that missing values read as, or unsets it if `None`.
+    pub fn set_fill_value(&mut self, fill: Option<R>) {
+        self.3 = fill;
+    }
+
+    /// Consumes this `SeriesSparse` and returns it with the given fill value.
+    pub fn with_fill_value(mut self, fill: R) -> Self {
+        self.set_fill_value(Some(fill));
+        self
+    }
+
+    /// Given a position, returns a read-only reference to its value in the
+    /// `SeriesSparse`. Missing values return the fill value, if set, and
+    /// positions out of bounds return `None`.
+    pub fn iloc(&self, pos: usize) -> Option<&R> {
+        if pos >= self.len() { return None; }
+
+        match self.1.binary_search(&pos) {
+            Ok(i) => self.2.get(i),
+            Err(_) => self.fill_value(),
+        }
+    }
+
+    /// Given a label, returns a read-only reference to its value in the
+    /// `SeriesSparse`. Missing values return the fill value, if set, and
+    /// labels not in the `Index` return `None`.
+    pub fn loc<Q>(&self, label: &Q) -> Option<&R>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.0.index_of(label).and_then(|pos| self.iloc(pos))
+    }
+
+    /// Returns an iterator that yields all labels in this `SeriesSparse` in
+    /// order, along with their values, or the fill value if missing.
+    pub fn iter(&self) -> Iter<'_, L, R> {
+        Iter::new(self)
+    }
+
+    /// Retains only the label/value pairs specified by the predicate.
+    /// The predicate accepts references to a label and a value, which is the
+    /// fill value if missing.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&L, Option<&R>) -> bool,
+    {
+        let keep = self.iter().map(|(l, v)| pred(l, v)).collect::<Vec<_>>();
+
+        // Only do work if there are any pairs to drop.
+        if keep.iter().all(|&k| k) { return; }
+
+        // Each kept position shifts back by the number of dropped positions
+        // before it.
+        let mut new_pos = Vec::with_capacity(keep.len());
+        let mut n = 0usize;
+        for &k in &keep {
+            new_pos.push(n);
+            if k { n += 1; }
+        }
+
+        let mut p = 0usize;
+        self.0.retain(|_| { (keep[p], p += 1).0 });
+
+        let (positions, present) =
+            self.1
+            .drain(..)
+            .zip(self.2.drain(..))
+            .filter(|(pos, _)| keep[*pos])
+            .map(|(pos, v)| (new_pos[pos], v))
+            .unzip()
+        ;
+
+        self.1 = positions;
+        self.2 = present;
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: RawType,
+{
+    /// Consumes this `Series` and creates a `SeriesSparse` that only stores
+    /// the non-`None` values, preserving the `Index`.
+    pub fn to_sparse(self) -> SeriesSparse<L, R> {
+        SeriesSparse::from_series(self)
+    }
+}
+
+impl<L, R> From<Series<L, Option<R>>> for SeriesSparse<L, R>
+where
+    L: Label,
+    R: RawType,
+{
+    fn from(series: Series<L, Option<R>>) -> Self {
+        Self::from_series(series)
+    }
+}
+
+impl<L, R> From<SeriesSparse<L, R>> for Series<L, Option<R>>
+where
+    L: Label,
+    R: RawType,
+{
+    fn from(sparse: SeriesSparse<L, R>) -> Self {
+        sparse.into_series()
+    }
+}
+
+impl<'a, L, R> IntoIterator for &'a SeriesSparse<L, R>
+where
+    L: Label,
+    R: RawType,
+{
+    type Item = (&'a L, Option<&'a R>);
+    type IntoIter = Iter<'a, L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::testing::fixtures;
+
+    #[test]
+    fn to_sparse_to_dense() {
+        let sparse = fixtures::sparse_ints();
+
+        assert_eq!(sparse.0, Index::from_iter("abcde".chars()));
+        assert_eq!(sparse.1, vec![1, 4]);
+        assert_eq!(sparse.2, vec![10, 40]);
+
+        let (index, values) = sparse.to_dense(0).into_index_values();
+
+        assert_eq!(index, Index::from_iter("abcde".chars()));
+        assert_eq!(values, vec![0, 10, 0, 0, 40]);
+
+        let empty = Series::<char, Option<i32>>::new().to_sparse();
+
+        assert!(empty.1.is_empty());
+        assert!(empty.to_dense(7).is_empty());
+    }
+
+    #[test]
+    fn loc_iloc() {
+        let sparse = fixtures::sparse_ints();
+
+        assert_eq!(sparse.iloc(0), None);
+        assert_eq!(sparse.iloc(1), Some(&10));
+        assert_eq!(sparse.iloc(4), Some(&40));
+        assert_eq!(sparse.iloc(5), None);
+        assert_eq!(sparse.loc(&'e'), Some(&40));
+        assert_eq!(sparse.loc(&'c'), None);
+
+        let sparse = sparse.with_fill_value(-1);
+
+        assert_eq!(sparse.iloc(0), Some(&-1));
+        assert_eq!(sparse.iloc(1), Some(&10));
+        assert_eq!(sparse.iloc(5), None);
+        assert_eq!(sparse.loc(&'c'), Some(&-1));
+        assert_eq!(sparse.loc(&'z'), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut sparse = fixtures::sparse_ints();
+
+        let pairs = sparse.iter().collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            vec![(&'a', None), (&'b', Some(&10)), (&'c', None), (&'d', None), (&'e', Some(&40))],
+        );
+        assert_eq!(sparse.iter().len(), 5);
+
+        sparse.set_fill_value(Some(0));
+
+        let values = (&sparse).into_iter().map(|(_, v)| v).collect::<Vec<_>>();
+        assert_eq!(values, vec![Some(&0), Some(&10), Some(&0), Some(&0), Some(&40)]);
+    }
+
+    #[test]
+    fn density() {
+        let sparse = fixtures::sparse_ints();
+
+        assert_eq!(sparse.count_present(), 2);
+        assert_eq!(sparse.density(), 0.4);
+
+        let empty = Series::<char, Option<i32>>::new().to_sparse();
+        assert_eq!(empty.density(), 0.0);
+    }
+
+    #[test]
+    fn retain() {
+        let mut sparse = fixtures::sparse_ints();
+
+        sparse.retain(|l, v| *l != 'a' && v != Some(&10));
+
+        assert_eq!(sparse.0, Index::from_iter("cde".chars()));
+        assert_eq!(sparse.1, vec![2]);
+        assert_eq!(sparse.2, vec![40]);
+        assert_eq!(sparse.loc(&'e'), Some(&40));
+
+        let mut sparse = fixtures::sparse_ints().with_fill_value(0);
+
+        sparse.retain(|_, v| v != Some(&0));
+
+        assert_eq!(sparse.0, Index::from_iter("be".chars()));
+        assert_eq!(sparse.1, vec![0, 1]);
+        assert_eq!(sparse.2, vec![10, 40]);
+    }
+
+    #[test]
+    fn conversions() {
+        let s = Series::from_iter_checked(vec![('a', Some(1)), ('b', None), ('c', Some(3))]).unwrap();
+
+        let sparse = SeriesSparse::from(s);
+        let (index, values) = Series::from(sparse).into_index_values();
+
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![Some(1), None, Some(3)]);
+
+        let s = Series::from_iter_checked(vec![('a', Some(1)), ('b', None)]).unwrap();
+        let values = s.to_sparse().with_fill_value(9).into_series().into_values();
+
+        assert_eq!(values, vec![Some(1), Some(9)]);
+    }
+}
@@ -0,0 +1,748 @@
+//! Aggregations for use with `Series`.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::traits::KeyHash;
+use crate::traits::Label;
+use crate::traits::Numeric;
+use crate::traits::RawType;
+use crate::types::HashKey;
+
+use super::kernels;
+use super::MisalignedIndex;
+use super::Series;
+
+/// Specifies how to compute a quantile that lies between two values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linearly interpolate between the two values.
+    Linear,
+    /// Use the lower of the two values.
+    Lower,
+    /// Use the higher of the two values.
+    Higher,
+    /// Use the mean of the two values.
+    Midpoint,
+    /// Use the value whose position is nearest.
+    Nearest,
+}
+
+/// Returns `true` if a value is comparable with itself, i.e. is not `NaN`.
+fn is_comparable<N: PartialOrd>(n: &N) -> bool {
+    n.partial_cmp(n).is_some()
+}
+
+fn sum_iter<N, I>(iter: I) -> N
+where
+    N: Numeric,
+    I: IntoIterator<Item = N>,
+{
+    iter.into_iter().fold(N::zero(), |acc, n| acc + n)
+}
+
+fn product_iter<N, I>(iter: I) -> N
+where
+    N: Numeric,
+    I: IntoIterator<Item = N>,
+{
+    iter.into_iter().filter(is_comparable).fold(N::one(), |acc, n| acc * n)
+}
+
+fn mean_iter<N, I>(iter: I) -> Option<f64>
+where
+    N: Numeric,
+    I: IntoIterator<Item = N>,
+{
+    let (count, total) =
+        iter
+        .into_iter()
+        .filter(is_comparable)
+        .fold((0usize, 0.0f64), |(c, t), n| (c + 1, t + n.to_f64()))
+    ;
+
+    if count == 0 { None } else { Some(total / count as f64) }
+}
+
+fn var_iter<N, I>(iter: I, ddof: usize) -> Option<f64>
+where
+    N: Numeric,
+    I: IntoIterator<Item = N>,
+{
+    let values =
+        iter
+        .into_iter()
+        .map(Numeric::to_f64)
+        .filter(|v| !v.is_nan())
+        .collect::<Vec<_>>()
+    ;
+    let count = values.len();
+
+    if count <= ddof { return None; }
+
+    let mean = values.iter().sum::<f64>() / count as f64;
+    let sq_dev = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+
+    Some(sq_dev / (count - ddof) as f64)
+}
+
+fn quantile_iter<N, I>(iter: I, q: f64, interpolation: Interpolation) -> Option<f64>
+where
+    N: Numeric,
+    I: IntoIterator<Item = N>,
+{
+    if !(0.0..=1.0).contains(&q) { return None; }
+
+    let mut values =
+        iter
+        .into_iter()
+        .map(Numeric::to_f64)
+        .filter(|v| !v.is_nan())
+        .collect::<Vec<_>>()
+    ;
+
+    if values.is_empty() { return None; }
+
+    // All `NaN`s have been removed, so this comparison is total.
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let pos = q * (values.len() - 1) as f64;
+    let lo = values[pos.floor() as usize];
+    let hi = values[pos.ceil() as usize];
+
+    let quantile = match interpolation {
+        Interpolation::Linear => lo + (hi - lo) * pos.fract(),
+        Interpolation::Lower => lo,
+        Interpolation::Higher => hi,
+        Interpolation::Midpoint => (lo + hi) / 2.0,
+        Interpolation::Nearest => values[pos.round() as usize],
+    };
+
+    Some(quantile)
+}
+
+/// Computes a running accumulation over optional values. Values that are not
+/// comparable with themselves (e.g. `NaN`) are passed through to the output
+/// unchanged, and do not affect the accumulation. If `skip_na` is `true`,
+/// `None`s are treated the same way; otherwise, the first `None` ends the
+/// accumulation, and every result from then on is `None`.
+fn cum_iter<N, I, F>(iter: I, skip_na: bool, mut func: F) -> Vec<Option<N>>
+where
+    N: Numeric,
+    I: IntoIterator<Item = Option<N>>,
+    F: FnMut(N, N) -> N,
+{
+    let mut running: Option<N> = None;
+    let mut missing = false;
+
+    iter
+        .into_iter()
+        .map(|opt_n| match opt_n {
+            _ if missing => None,
+            Some(n) if n.partial_cmp(&n).is_some() => {
+                let r = match running { Some(r) => func(r, n), None => n };
+                running = Some(r);
+                Some(r)
+            },
+            None if !skip_na => {
+                missing = true;
+                None
+            },
+            other => other,
+        })
+        .collect()
+}
+
+/// Finds the most frequently occurring values, in first-seen order.
+fn mode_iter<'a, V, I>(iter: I) -> Vec<V>
+where
+    V: 'a + RawType + KeyHash,
+    I: IntoIterator<Item = &'a V>,
+{
+    let mut counts = HashMap::<HashKey<'_, V>, usize>::new();
+    let mut order = Vec::new();
+
+    for value in iter {
+        let count = counts.entry(HashKey(value)).or_insert(0);
+        if *count == 0 { order.push(value); }
+        *count += 1;
+    }
+
+    let max_count = counts.values().copied().max().unwrap_or(0);
+
+    order
+        .into_iter()
+        .filter(|&v| counts[&HashKey(v)] == max_count)
+        .cloned()
+        .collect()
+}
+
+/// Finds the most extreme value in the direction of the given `Ordering`.
+/// Values that are not comparable with themselves (e.g. `NaN`) are skipped.
+fn extreme_iter<N, I>(iter: I, target: Ordering) -> Option<N>
+where
+    N: Numeric,
+    I: IntoIterator<Item = N>,
+{
+    iter
+        .into_iter()
+        .filter(|n| n.partial_cmp(n).is_some())
+        .fold(None, |acc, n| match acc {
+            Some(a) if n.partial_cmp(&a) != Some(target) => Some(a),
+            _ => Some(n),
+        })
+}
+
+/// Finds the position of the first most extreme value in the direction of the
+/// given `Ordering`. Items that are `None` or not comparable with themselves
+/// (e.g. `NaN`) are skipped.
+fn extreme_pos_iter<'a, T, I>(iter: I, target: Ordering) -> Option<usize>
+where
+    T: PartialOrd + 'a,
+    I: IntoIterator<Item = Option<&'a T>>,
+{
+    iter
+        .into_iter()
+        .enumerate()
+        .filter_map(|(p, opt_t)| opt_t.map(|t| (p, t)))
+        .filter(|(_, t)| t.partial_cmp(t).is_some())
+        .fold(None, |acc: Option<(usize, &T)>, (p, t)| match acc {
+            Some(a) if t.partial_cmp(a.1) != Some(target) => Some(a),
+            _ => Some((p, t)),
+        })
+        .map(|(p, _)| p)
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: RawType + KeyHash,
+{
+    /// Returns the most frequently occurring value(s) in this `Series`. If
+    /// there are ties, the values are returned in first-seen order. Values are
+    /// compared as keys, so for floats, `0.0` and `-0.0` are counted together,
+    /// as are all `NaN`s.
+    pub fn mode(&self) -> Vec<V> {
+        mode_iter(&self.1)
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: RawType + KeyHash,
+{
+    /// Returns the most frequently occurring non-`None` value(s) in this
+    /// `Series`. If there are ties, the values are returned in first-seen
+    /// order.
+    pub fn mode(&self) -> Vec<R> {
+        mode_iter(self.1.iter().flatten())
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: RawType + PartialOrd,
+{
+    /// Returns the label of the largest value in this `Series`, skipping
+    /// `NaN`s. If there are ties, the label of the first occurrence is
+    /// returned.
+    pub fn idxmax(&self) -> Option<&L> {
+        extreme_pos_iter(self.1.iter().map(Some), Ordering::Greater).and_then(|p| self.0.iloc(p))
+    }
+
+    /// Returns the label of the smallest value in this `Series`, skipping
+    /// `NaN`s. If there are ties, the label of the first occurrence is
+    /// returned.
+    pub fn idxmin(&self) -> Option<&L> {
+        extreme_pos_iter(self.1.iter().map(Some), Ordering::Less).and_then(|p| self.0.iloc(p))
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: RawType + PartialOrd,
+{
+    /// Returns the label of the largest non-`None` value in this `Series`,
+    /// skipping `NaN`s. If there are ties, the label of the first occurrence
+    /// is returned.
+    pub fn idxmax(&self) -> Option<&L> {
+        extreme_pos_iter(self.1.iter().map(Option::as_ref), Ordering::Greater).and_then(|p| self.0.iloc(p))
+    }
+
+    /// Returns the label of the smallest non-`None` value in this `Series`,
+    /// skipping `NaN`s. If there are ties, the label of the first occurrence
+    /// is returned.
+    pub fn idxmin(&self) -> Option<&L> {
+        extreme_pos_iter(self.1.iter().map(Option::as_ref), Ordering::Less).and_then(|p| self.0.iloc(p))
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Numeric,
+{
+    /// Returns the sum of the values in this `Series`, skipping `NaN`s, or zero
+    /// if there are no values.
+    pub fn sum(&self) -> V {
+        kernels::sum(&self.1)
+    }
+
+    /// Returns the product of the values in this `Series`, skipping `NaN`s, or
+    /// one if there are no values.
+    pub fn product(&self) -> V {
+        product_iter(self.1.iter().copied())
+    }
+
+    /// Returns the arithmetic mean of the values in this `Series`, skipping
+    /// `NaN`s, or `None` if there are no values.
+    pub fn mean(&self) -> Option<f64> {
+        kernels::mean(&self.1)
+    }
+
+    /// Returns the smallest value in this `Series`, skipping `NaN`s, or `None`
+    /// if there are no values.
+    pub fn min(&self) -> Option<V> {
+        kernels::extreme(&self.1, Ordering::Less)
+    }
+
+    /// Returns the largest value in this `Series`, skipping `NaN`s, or `None`
+    /// if there are no values.
+    pub fn max(&self) -> Option<V> {
+        kernels::extreme(&self.1, Ordering::Greater)
+    }
+
+    fn cum_handler<F>(&self, func: F) -> Self
+    where
+        F: FnMut(V, V) -> V,
+    {
+        let values =
+            cum_iter(self.1.iter().copied().map(Some), true, func)
+            .into_iter()
+            .map(Option::unwrap)
+            .collect()
+        ;
+
+        Series::new_inner(self.0.clone(), values)
+    }
+
+    /// Returns a new `Series` with the same `Index`, containing the cumulative
+    /// sum of the values in this `Series`. `NaN`s are skipped.
+    pub fn cumsum(&self) -> Self {
+        self.cum_handler(|r, n| r + n)
+    }
+
+    /// Returns a new `Series` with the same `Index`, containing the cumulative
+    /// product of the values in this `Series`. `NaN`s are skipped.
+    pub fn cumprod(&self) -> Self {
+        self.cum_handler(|r, n| r * n)
+    }
+
+    /// Returns a new `Series` with the same `Index`, containing the cumulative
+    /// minimum of the values in this `Series`. `NaN`s are skipped.
+    pub fn cummin(&self) -> Self {
+        self.cum_handler(|r, n| if n < r { n } else { r })
+    }
+
+    /// Returns a new `Series` with the same `Index`, containing the cumulative
+    /// maximum of the values in this `Series`. `NaN`s are skipped.
+    pub fn cummax(&self) -> Self {
+        self.cum_handler(|r, n| if n > r { n } else { r })
+    }
+
+    /// Returns the variance of the values in this `Series`, skipping `NaN`s,
+    /// using `ddof` delta degrees of freedom. Returns `None` if there are not
+    /// more than `ddof` values.
+    pub fn var(&self, ddof: usize) -> Option<f64> {
+        var_iter(self.1.iter().copied(), ddof)
+    }
+
+    /// Returns the standard deviation of the values in this `Series`, skipping
+    /// `NaN`s, using `ddof` delta degrees of freedom. Returns `None` if there
+    /// are not more than `ddof` values.
+    pub fn std(&self, ddof: usize) -> Option<f64> {
+        self.var(ddof).map(f64::sqrt)
+    }
+
+    /// Returns the median of the values in this `Series`, skipping `NaN`s, or
+    /// `None` if there are no values.
+    pub fn median(&self) -> Option<f64> {
+        self.quantile(0.5, Interpolation::Linear)
+    }
+
+    /// Returns the `q`-th quantile of the values in this `Series`, skipping
+    /// `NaN`s. Returns `None` if there are no values or if `q` is not within
+    /// `[0, 1]`.
+    pub fn quantile(&self, q: f64, interpolation: Interpolation) -> Option<f64> {
+        quantile_iter(self.1.iter().copied(), q, interpolation)
+    }
+
+    /// Returns the sum of the products of the values in this `Series` and
+    /// another, matched by label. If the two `Series` do not have the same set
+    /// of labels, a `MisalignedIndex` error is returned.
+    pub fn dot(&self, other: &Self) -> Result<V, MisalignedIndex<L>> {
+        self.check_aligned(&other.0)?;
+        Ok(self.dot_inner(other))
+    }
+
+    /// Returns the sum of the products of the values in this `Series` and
+    /// another, matched by label. Only labels contained in both are used.
+    pub fn dot_inner(&self, other: &Self) -> V {
+        sum_iter(
+            self.iter()
+                .filter_map(|(l, &v)| other.loc(l).map(|&w| v * w))
+        )
+    }
+
+    /// Returns the mean of the values in this `Series`, weighted by the values
+    /// of another `Series` matched by label. Returns `None` if the weights sum
+    /// to zero. If the two `Series` do not have the same set of labels, a
+    /// `MisalignedIndex` error is returned.
+    pub fn weighted_mean<W>(&self, weights: &Series<L, W>) -> Result<Option<f64>, MisalignedIndex<L>>
+    where
+        W: Numeric,
+    {
+        self.check_aligned(&weights.0)?;
+
+        let (total, total_weight) =
+            self.iter()
+            .map(|(l, &v)| (v.to_f64(), weights.loc(l).unwrap().to_f64()))
+            .fold((0.0, 0.0), |(t, tw), (v, w)| (t + v * w, tw + w))
+        ;
+
+        Ok(if total_weight == 0.0 { None } else { Some(total / total_weight) })
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: Numeric,
+{
+    /// Returns the sum of the non-`None` values in this `Series`, skipping
+    /// `NaN`s, or zero if there are none.
+    pub fn sum(&self) -> R {
+        sum_iter(self.1.iter().flatten().copied().filter(is_comparable))
+    }
+
+    /// Returns the product of the non-`None` values in this `Series`, skipping
+    /// `NaN`s, or one if there are none.
+    pub fn product(&self) -> R {
+        product_iter(self.1.iter().flatten().copied())
+    }
+
+    /// Returns the arithmetic mean of the non-`None` values in this `Series`,
+    /// skipping `NaN`s, or `None` if there are none.
+    pub fn mean(&self) -> Option<f64> {
+        mean_iter(self.1.iter().flatten().copied())
+    }
+
+    /// Returns the smallest non-`None` value in this `Series`, skipping `NaN`s,
+    /// or `None` if there are no values.
+    pub fn min(&self) -> Option<R> {
+        extreme_iter(self.1.iter().flatten().copied(), Ordering::Less)
+    }
+
+    /// Returns the largest non-`None` value in this `Series`, skipping `NaN`s,
+    /// or `None` if there are no values.
+    pub fn max(&self) -> Option<R> {
+        extreme_iter(self.1.iter().flatten().copied(), Ordering::Greater)
+    }
+
+    fn cum_handler<F>(&self, skip_na: bool, func: F) -> Self
+    where
+        F: FnMut(R, R) -> R,
+    {
+        Series::new_inner(self.0.clone(), cum_iter(self.1.iter().copied(), skip_na, func))
+    }
+
+    /// Returns a new `Series` with the same `Index`, containing the cumulative
+    /// sum of the values in this `Series`. `NaN`s are skipped, and are kept
+    /// as-is in the result. If `skip_na` is `true`, `None`s are skipped in the
+    /// same way; otherwise, the first `None` propagates, making every result
+    /// from that position on `None`.
+    pub fn cumsum(&self, skip_na: bool) -> Self {
+        self.cum_handler(skip_na, |r, n| r + n)
+    }
+
+    /// Returns a new `Series` with the same `Index`, containing the cumulative
+    /// product of the values in this `Series`. `None`s are handled as in
+    /// `cumsum`.
+    pub fn cumprod(&self, skip_na: bool) -> Self {
+        self.cum_handler(skip_na, |r, n| r * n)
+    }
+
+    /// Returns a new `Series` with the same `Index`, containing the cumulative
+    /// minimum of the values in this `Series`. `None`s are handled as in
+    /// `cumsum`.
+    pub fn cummin(&self, skip_na: bool) -> Self {
+        self.cum_handler(skip_na, |r, n| if n < r { n } else { r })
+    }
+
+    /// Returns a new `Series` with the same `Index`, containing the cumulative
+    /// maximum of the values in this `Series`. `None`s are handled as in
+    /// `cumsum`.
+    pub fn cummax(&self, skip_na: bool) -> Self {
+        self.cum_handler(skip_na, |r, n| if n > r { n } else { r })
+    }
+
+    /// Returns the variance of the non-`None` values in this `Series`,
+    /// skipping `NaN`s, using `ddof` delta degrees of freedom. Returns `None`
+    /// if there are not more than `ddof` values.
+    pub fn var(&self, ddof: usize) -> Option<f64> {
+        var_iter(self.1.iter().flatten().copied(), ddof)
+    }
+
+    /// Returns the standard deviation of the non-`None` values in this
+    /// `Series`, skipping `NaN`s, using `ddof` delta degrees of freedom.
+    /// Returns `None` if there are not more than `ddof` values.
+    pub fn std(&self, ddof: usize) -> Option<f64> {
+        self.var(ddof).map(f64::sqrt)
+    }
+
+    /// Returns the median of the non-`None` values in this `Series`, skipping
+    /// `NaN`s, or `None` if there are no values.
+    pub fn median(&self) -> Option<f64> {
+        self.quantile(0.5, Interpolation::Linear)
+    }
+
+    /// Returns the `q`-th quantile of the non-`None` values in this `Series`,
+    /// skipping `NaN`s. Returns `None` if there are no values or if `q` is not
+    /// within `[0, 1]`.
+    pub fn quantile(&self, q: f64, interpolation: Interpolation) -> Option<f64> {
+        quantile_iter(self.1.iter().flatten().copied(), q, interpolation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+
+    #[test]
+    fn aggregations() {
+        let s = Series::from_iter_checked(vec![('a', 3), ('b', -1), ('c', 4), ('d', 2)]).unwrap();
+
+        assert_eq!(s.sum(), 8);
+        assert_eq!(s.product(), -24);
+        assert_eq!(s.mean(), Some(2.0));
+        assert_eq!(s.min(), Some(-1));
+        assert_eq!(s.max(), Some(4));
+
+        let s = Series::from_iter_checked(vec![('a', 1.5), ('b', f64::NAN), ('c', -0.5)]).unwrap();
+
+        assert_eq!(s.min(), Some(-0.5));
+        assert_eq!(s.max(), Some(1.5));
+
+        let s: Series<char, f64> = Series::new();
+
+        assert_eq!(s.sum(), 0.0);
+        assert_eq!(s.product(), 1.0);
+        assert_eq!(s.mean(), None);
+        assert_eq!(s.min(), None);
+        assert_eq!(s.max(), None);
+    }
+
+    #[test]
+    fn statistics() {
+        let s = Series::from_iter_checked(vec![
+            ('a', 2), ('b', 4), ('c', 4), ('d', 4), ('e', 5), ('f', 5), ('g', 7), ('h', 9),
+        ]).unwrap();
+
+        assert_eq!(s.var(0), Some(4.0));
+        assert_eq!(s.std(0), Some(2.0));
+        assert_eq!(s.var(1), Some(32.0 / 7.0));
+        assert_eq!(s.var(8), None);
+        assert_eq!(s.median(), Some(4.5));
+
+        let s = Series::from_iter_checked(vec![('a', 4.0), ('b', 1.0), ('c', 3.0), ('d', 2.0)]).unwrap();
+
+        assert_eq!(s.quantile(0.0, Interpolation::Linear), Some(1.0));
+        assert_eq!(s.quantile(1.0, Interpolation::Linear), Some(4.0));
+        assert_eq!(s.quantile(0.5, Interpolation::Linear), Some(2.5));
+        assert_eq!(s.quantile(0.5, Interpolation::Lower), Some(2.0));
+        assert_eq!(s.quantile(0.5, Interpolation::Higher), Some(3.0));
+        assert_eq!(s.quantile(0.5, Interpolation::Midpoint), Some(2.5));
+        assert_eq!(s.quantile(0.4, Interpolation::Nearest), Some(2.0));
+        assert_eq!(s.quantile(0.25, Interpolation::Linear), Some(1.75));
+        assert_eq!(s.quantile(1.5, Interpolation::Linear), None);
+        assert_eq!(s.quantile(-0.5, Interpolation::Linear), None);
+
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', f64::NAN), ('c', 3.0)]).unwrap();
+
+        assert_eq!(s.var(0), Some(1.0));
+        assert_eq!(s.std(1), Some(2.0f64.sqrt()));
+        assert_eq!(s.var(2), None);
+        assert_eq!(s.sum(), 4.0);
+        assert_eq!(s.mean(), Some(2.0));
+
+        let s: Series<char, f64> = Series::new();
+
+        assert_eq!(s.var(0), None);
+        assert_eq!(s.median(), None);
+    }
+
+    #[test]
+    fn statistics_skip_none() {
+        let s = Series::from_iter_checked(vec![
+            ('a', Some(1.0)), ('b', None), ('c', Some(3.0)), ('d', None), ('e', Some(2.0)),
+        ]).unwrap();
+
+        assert_eq!(s.var(0), Some(2.0 / 3.0));
+        assert_eq!(s.var(1), Some(1.0));
+        assert_eq!(s.std(1), Some(1.0));
+        assert_eq!(s.median(), Some(2.0));
+        assert_eq!(s.quantile(0.75, Interpolation::Linear), Some(2.5));
+    }
+
+    #[test]
+    fn cumulative() {
+        let s = Series::from_iter_checked(vec![('a', 3), ('b', 1), ('c', 4), ('d', 2)]).unwrap();
+
+        assert_eq!(s.cumsum().into_values(), vec![3, 4, 8, 10]);
+        assert_eq!(s.cumprod().into_values(), vec![3, 3, 12, 24]);
+        assert_eq!(s.cummin().into_values(), vec![3, 1, 1, 1]);
+        assert_eq!(s.cummax().into_values(), vec![3, 3, 4, 4]);
+        assert_eq!(s.cumsum().into_index(), Index::from_iter("abcd".chars()));
+
+        let s = Series::from_iter_checked(vec![('a', f64::NAN), ('b', 2.0), ('c', 1.0)]).unwrap();
+        let values = s.cummin().into_values();
+
+        assert!(values[0].is_nan());
+        assert_eq!(&values[1..], &[2.0, 1.0]);
+    }
+
+    #[test]
+    fn cumulative_none() {
+        let s = Series::from_iter_checked(vec![
+            ('a', Some(2)), ('b', Some(3)), ('c', None), ('d', Some(1)), ('e', Some(4)),
+        ]).unwrap();
+
+        assert_eq!(s.cumsum(false).into_values(), vec![Some(2), Some(5), None, None, None]);
+        assert_eq!(s.cumprod(false).into_values(), vec![Some(2), Some(6), None, None, None]);
+        assert_eq!(s.cummin(false).into_values(), vec![Some(2), Some(2), None, None, None]);
+        assert_eq!(s.cummax(false).into_values(), vec![Some(2), Some(3), None, None, None]);
+
+        let s = Series::from_iter_checked(vec![
+            ('a', None), ('b', Some(3)), ('c', None), ('d', Some(1)), ('e', Some(4)),
+        ]).unwrap();
+
+        assert_eq!(s.cumsum(false).into_values(), vec![None; 5]);
+        assert_eq!(s.cumsum(true).into_values(), vec![None, Some(3), None, Some(4), Some(8)]);
+        assert_eq!(s.cumprod(true).into_values(), vec![None, Some(3), None, Some(3), Some(12)]);
+        assert_eq!(s.cummin(true).into_values(), vec![None, Some(3), None, Some(1), Some(1)]);
+        assert_eq!(s.cummax(true).into_values(), vec![None, Some(3), None, Some(3), Some(4)]);
+    }
+
+    #[test]
+    fn mode() {
+        let s = Series::from_iter_checked(vec![
+            (0, 'b'), (1, 'a'), (2, 'c'), (3, 'a'), (4, 'b'), (5, 'd'),
+        ]).unwrap();
+
+        assert_eq!(s.mode(), vec!['b', 'a']);
+
+        let s = Series::from_iter_checked(vec![(0, 1.5), (1, 2.5), (2, 2.5)]).unwrap();
+
+        assert_eq!(s.mode(), vec![2.5]);
+
+        let s = Series::from_iter_checked(vec![(0, 0.0), (1, f64::NAN), (2, -0.0)]).unwrap();
+
+        assert_eq!(s.mode(), vec![0.0]);
+
+        let s = Series::from_iter_checked(vec![
+            (0, None), (1, Some('x')), (2, None), (3, Some('y')), (4, None), (5, Some('y')),
+        ]).unwrap();
+
+        assert_eq!(s.mode(), vec!['y']);
+
+        let s: Series<i32, char> = Series::new();
+
+        assert_eq!(s.mode(), vec![]);
+    }
+
+    #[test]
+    fn aggregations_skip_none() {
+        let s = Series::from_iter_checked(vec![
+            ('a', Some(3)),
+            ('b', None),
+            ('c', Some(-1)),
+            ('d', None),
+            ('e', Some(4)),
+        ]).unwrap();
+
+        assert_eq!(s.sum(), 6);
+        assert_eq!(s.product(), -12);
+        assert_eq!(s.mean(), Some(2.0));
+        assert_eq!(s.min(), Some(-1));
+        assert_eq!(s.max(), Some(4));
+
+        let s = Series::from_iter_checked(vec![('a', None::<i32>), ('b', None)]).unwrap();
+
+        assert_eq!(s.sum(), 0);
+        assert_eq!(s.product(), 1);
+        assert_eq!(s.mean(), None);
+        assert_eq!(s.min(), None);
+        assert_eq!(s.max(), None);
+    }
+
+    #[test]
+    fn idxmax_idxmin() {
+        let s = Series::from_iter_checked(vec![
+            ('a', 3.0), ('b', f64::NAN), ('c', 1.0), ('d', 3.0), ('e', 1.0),
+        ]).unwrap();
+
+        assert_eq!(s.idxmax(), Some(&'a'));
+        assert_eq!(s.idxmin(), Some(&'c'));
+
+        let s = Series::from_iter_checked(vec![
+            ('a', None), ('b', Some(5)), ('c', Some(7)), ('d', None), ('e', Some(2)),
+        ]).unwrap();
+
+        assert_eq!(s.idxmax(), Some(&'c'));
+        assert_eq!(s.idxmin(), Some(&'e'));
+
+        let s = Series::<char, Option<i32>>::from_iter_checked(vec![('a', None)]).unwrap();
+
+        assert_eq!(s.idxmax(), None);
+        assert_eq!(s.idxmin(), None);
+    }
+
+    #[test]
+    fn dot() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3)]).unwrap();
+        let t = Series::from_iter_checked(vec![('c', 4), ('a', 5), ('b', 6)]).unwrap();
+
+        assert_eq!(s.dot(&t).unwrap(), 5 + 12 + 12);
+
+        let t = Series::from_iter_checked(vec![('a', 5), ('b', 6), ('z', 7)]).unwrap();
+        let err = s.dot(&t).unwrap_err();
+
+        assert_eq!(err.missing, vec!['c']);
+        assert_eq!(err.extra, vec!['z']);
+        assert_eq!(s.dot_inner(&t), 5 + 12);
+
+        let t = Series::from_iter_checked(vec![('x', 1)]).unwrap();
+        assert_eq!(s.dot_inner(&t), 0);
+    }
+
+    #[test]
+    fn weighted_mean() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 4)]).unwrap();
+        let w = Series::from_iter_checked(vec![('c', 0.5), ('b', 0.25), ('a', 0.25)]).unwrap();
+
+        assert_eq!(s.weighted_mean(&w).unwrap(), Some(2.75));
+
+        let w = Series::from_iter_checked(vec![('a', 0), ('b', 0), ('c', 0)]).unwrap();
+        assert_eq!(s.weighted_mean(&w).unwrap(), None);
+
+        let w = Series::from_iter_checked(vec![('a', 1), ('b', 1)]).unwrap();
+        assert_eq!(s.weighted_mean(&w).unwrap_err().missing, vec!['c']);
+    }
+}
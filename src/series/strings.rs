@@ -0,0 +1,158 @@
+//! String methods for use with `Series`.
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use crate::traits::Label;
+use crate::traits::Storable;
+
+use super::Series;
+
+/// A namespace of string methods for a `Series` with `String` values, created
+/// by `Series::str`. Each method produces a new `Series` with the same `Index`.
+pub struct StrMethods<'a, L: Label> {
+    series: &'a Series<L, String>,
+}
+
+impl<'a, L> StrMethods<'a, L>
+where
+    L: Label,
+{
+    fn apply<F, C>(&self, func: F) -> Series<L, C>
+    where
+        F: FnMut(&String) -> C,
+        C: Storable,
+    {
+        self.series.apply(func)
+    }
+
+    /// Returns the number of characters in each value.
+    pub fn len(&self) -> Series<L, usize> {
+        self.apply(|s| s.chars().count())
+    }
+
+    /// Returns `true` for each value that is the empty string.
+    pub fn is_empty(&self) -> Series<L, bool> {
+        self.apply(String::is_empty)
+    }
+
+    /// Converts each value to lowercase.
+    pub fn to_lowercase(&self) -> Series<L, String> {
+        self.apply(|s| s.to_lowercase())
+    }
+
+    /// Converts each value to uppercase.
+    pub fn to_uppercase(&self) -> Series<L, String> {
+        self.apply(|s| s.to_uppercase())
+    }
+
+    /// Returns `true` for each value that contains the given pattern.
+    pub fn contains(&self, pat: &str) -> Series<L, bool> {
+        self.apply(|s| s.contains(pat))
+    }
+
+    /// Returns `true` for each value that starts with the given pattern.
+    pub fn starts_with(&self, pat: &str) -> Series<L, bool> {
+        self.apply(|s| s.starts_with(pat))
+    }
+
+    /// Returns `true` for each value that ends with the given pattern.
+    pub fn ends_with(&self, pat: &str) -> Series<L, bool> {
+        self.apply(|s| s.ends_with(pat))
+    }
+
+    /// Replaces all matches of a pattern in each value with another string.
+    pub fn replace(&self, from: &str, to: &str) -> Series<L, String> {
+        self.apply(|s| s.replace(from, to))
+    }
+
+    /// Splits each value by a separator into a list of substrings.
+    pub fn split(&self, sep: &str) -> Series<L, Vec<String>> {
+        self.apply(|s| s.split(sep).map(String::from).collect())
+    }
+
+    /// Removes leading and trailing whitespace from each value.
+    pub fn strip(&self) -> Series<L, String> {
+        self.apply(|s| s.trim().to_string())
+    }
+
+    /// Returns `true` for each value that matches the given regex.
+    #[cfg(feature = "regex")]
+    pub fn is_match(&self, re: &Regex) -> Series<L, bool> {
+        self.apply(|s| re.is_match(s))
+    }
+
+    /// Extracts the given capture group of the first match of a regex in each
+    /// value, or `None` if there is no match or the group did not participate.
+    #[cfg(feature = "regex")]
+    pub fn extract(&self, re: &Regex, group: usize) -> Series<L, Option<String>> {
+        self.apply(|s| {
+            re.captures(s)
+                .and_then(|caps| caps.get(group))
+                .map(|m| m.as_str().to_string())
+        })
+    }
+
+    /// Replaces all matches of a regex in each value with a replacement
+    /// string, which may refer to capture groups as in `Regex::replace_all`.
+    #[cfg(feature = "regex")]
+    pub fn replace_regex(&self, re: &Regex, rep: &str) -> Series<L, String> {
+        self.apply(|s| re.replace_all(s, rep).into_owned())
+    }
+}
+
+impl<L> Series<L, String>
+where
+    L: Label,
+{
+    /// Returns a namespace of string methods for the values in this `Series`.
+    pub fn str(&self) -> StrMethods<'_, L> {
+        StrMethods { series: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter::FromIterator;
+
+    use crate::index::Index;
+    use crate::testing::fixtures;
+
+    #[test]
+    fn str_methods() {
+        let s = fixtures::strings();
+
+        let (index, values) = s.str().len().into_index_values();
+        assert_eq!(index, Index::from_iter("abc".chars()));
+        assert_eq!(values, vec![13, 5, 0]);
+
+        assert_eq!(s.str().is_empty().into_values(), vec![false, false, true]);
+        assert_eq!(s.str().to_lowercase().into_values(), vec![" hello world ", "héllo", ""]);
+        assert_eq!(s.str().to_uppercase().into_values(), vec![" HELLO WORLD ", "HÉLLO", ""]);
+        assert_eq!(s.str().contains("llo").into_values(), vec![true, true, false]);
+        assert_eq!(s.str().starts_with("h").into_values(), vec![false, true, false]);
+        assert_eq!(s.str().ends_with(" ").into_values(), vec![true, false, false]);
+        assert_eq!(s.str().replace("l", "L").into_values(), vec![" HeLLo WorLd ", "héLLo", ""]);
+        assert_eq!(s.str().strip().into_values(), vec!["Hello World", "héllo", ""]);
+        assert_eq!(
+            s.str().split("l").into_values(),
+            vec![vec![" He", "", "o Wor", "d "], vec!["hé", "", "o"], vec![""]],
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn str_regex() {
+        use regex::Regex;
+
+        let s = fixtures::strings();
+        let re = Regex::new(r"(\w)llo").unwrap();
+
+        assert_eq!(s.str().is_match(&re).into_values(), vec![true, true, false]);
+        assert_eq!(
+            s.str().extract(&re, 1).into_values(),
+            vec![Some(String::from("e")), Some(String::from("é")), None],
+        );
+        assert_eq!(s.str().replace_regex(&re, "${1}LLO").into_values(), vec![" HeLLO World ", "héLLO", ""]);
+    }
+}
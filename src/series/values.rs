@@ -8,6 +8,10 @@ impl<V: Storable> ValueStore<V> {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 impl<R: RawType + Storable> ValueStore<Option<R>> {
@@ -27,7 +31,7 @@ impl<R: RawType + Storable> ValueStore<Option<R>> {
         ValueStore(
             self.0
             .into_iter()
-            .map(|v| v.unwrap_or_else(|| func()))
+            .map(|v| v.unwrap_or_else(&func))
             .collect()
         )
     }
@@ -36,7 +40,7 @@ impl<R: RawType + Storable> ValueStore<Option<R>> {
         ValueStore(
             self.0
             .into_iter()
-            .filter_map(|v| v)
+            .flatten()
             .collect()
         )
     }
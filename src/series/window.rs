@@ -0,0 +1,654 @@
+//! Window aggregations for use with `Series`.
+
+use crate::index::Index;
+use crate::traits::Label;
+use crate::traits::Numeric;
+use crate::traits::Storable;
+
+use super::Series;
+
+fn sum_window(window: &[f64]) -> f64 {
+    window.iter().sum()
+}
+
+fn mean_window(window: &[f64]) -> f64 {
+    sum_window(window) / window.len() as f64
+}
+
+fn min_window(window: &[f64]) -> f64 {
+    window.iter().copied().fold(f64::INFINITY, f64::min)
+}
+
+fn max_window(window: &[f64]) -> f64 {
+    window.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+}
+
+fn std_window(window: &[f64], ddof: usize) -> Option<f64> {
+    let count = window.len();
+
+    if count <= ddof { return None; }
+
+    let mean = mean_window(window);
+    let sq_dev = window.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+
+    Some((sq_dev / (count - ddof) as f64).sqrt())
+}
+
+/// Converts numeric values to `f64`s for use in window aggregations, treating
+/// `NaN`s as missing.
+fn to_window_values<N, I>(iter: I) -> Vec<Option<f64>>
+where
+    N: Numeric,
+    I: IntoIterator<Item = Option<N>>,
+{
+    iter
+        .into_iter()
+        .map(|opt_n| opt_n.map(Numeric::to_f64).filter(|f| !f.is_nan()))
+        .collect()
+}
+
+/// A rolling window adapter over a `Series`, created by `Series::rolling`.
+/// Each aggregation produces a new `Series` with the same `Index`, where each
+/// value is computed over the `window` values ending at that position.
+/// Missing values (`None`s and `NaN`s) are skipped, and positions whose window
+/// contains fewer than `min_periods` present values produce `None`.
+pub struct Rolling<'a, L: Label> {
+    index: &'a Index<L>,
+    values: Vec<Option<f64>>,
+    window: usize,
+    min_periods: usize,
+}
+
+impl<'a, L> Rolling<'a, L>
+where
+    L: Label,
+{
+    pub(crate) fn new(index: &'a Index<L>, values: Vec<Option<f64>>, window: usize) -> Self {
+        Self { index, values, window, min_periods: window }
+    }
+
+    /// Sets the minimum number of present values required in a window to
+    /// produce a value. Defaults to the window size.
+    pub fn min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    fn apply<F, T>(&self, mut func: F) -> Series<L, T>
+    where
+        F: FnMut(&[f64]) -> T,
+        T: Storable,
+    {
+        let mut buffer = Vec::with_capacity(self.window);
+
+        let values =
+            (0..self.values.len())
+            .map(|pos| {
+                let start = (pos + 1).saturating_sub(self.window);
+
+                buffer.clear();
+                buffer.extend(self.values[start..=pos].iter().flatten());
+
+                func(&buffer)
+            })
+            .collect()
+        ;
+
+        Series::new_inner(self.index.clone(), values)
+    }
+
+    fn apply_min_periods<F>(&self, mut func: F) -> Series<L, Option<f64>>
+    where
+        F: FnMut(&[f64]) -> Option<f64>,
+    {
+        let min_periods = self.min_periods;
+
+        self.apply(|w| {
+            if self.window == 0 || w.is_empty() || w.len() < min_periods { None }
+            else { func(w) }
+        })
+    }
+
+    /// Computes the sum of each window.
+    pub fn sum(&self) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|w| Some(sum_window(w)))
+    }
+
+    /// Computes the arithmetic mean of each window.
+    pub fn mean(&self) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|w| Some(mean_window(w)))
+    }
+
+    /// Computes the minimum of each window.
+    pub fn min(&self) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|w| Some(min_window(w)))
+    }
+
+    /// Computes the maximum of each window.
+    pub fn max(&self) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|w| Some(max_window(w)))
+    }
+
+    /// Computes the standard deviation of each window, using `ddof` delta
+    /// degrees of freedom.
+    pub fn std(&self, ddof: usize) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|w| std_window(w, ddof))
+    }
+
+    /// Counts the present values in each window. This ignores `min_periods`.
+    pub fn count(&self) -> Series<L, usize> {
+        self.apply(|w| w.len())
+    }
+}
+
+/// An expanding window adapter over a `Series`, created by
+/// `Series::expanding`. Each aggregation produces a new `Series` with the same
+/// `Index`, where each value is computed over all values up to and including
+/// that position, in a single pass. Missing values (`None`s and `NaN`s) are
+/// skipped, and positions with fewer than `min_periods` present values so far
+/// produce `None`.
+pub struct Expanding<'a, L: Label> {
+    index: &'a Index<L>,
+    values: Vec<Option<f64>>,
+    min_periods: usize,
+}
+
+/// Running statistics for an expanding window, updated using Welford's method.
+#[derive(Default)]
+struct RunningStats {
+    count: usize,
+    sum: f64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl RunningStats {
+    fn push(&mut self, x: f64) {
+        if self.count == 0 {
+            self.min = x;
+            self.max = x;
+        }
+        else {
+            self.min = self.min.min(x);
+            self.max = self.max.max(x);
+        }
+
+        self.count += 1;
+        self.sum += x;
+
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+}
+
+impl<'a, L> Expanding<'a, L>
+where
+    L: Label,
+{
+    pub(crate) fn new(index: &'a Index<L>, values: Vec<Option<f64>>, min_periods: usize) -> Self {
+        Self { index, values, min_periods }
+    }
+
+    fn apply<F, T>(&self, mut func: F) -> Series<L, T>
+    where
+        F: FnMut(&RunningStats) -> T,
+        T: Storable,
+    {
+        let mut stats = RunningStats::default();
+
+        let values =
+            self.values
+            .iter()
+            .map(|opt_x| {
+                if let Some(x) = opt_x { stats.push(*x); }
+                func(&stats)
+            })
+            .collect()
+        ;
+
+        Series::new_inner(self.index.clone(), values)
+    }
+
+    fn apply_min_periods<F>(&self, mut func: F) -> Series<L, Option<f64>>
+    where
+        F: FnMut(&RunningStats) -> Option<f64>,
+    {
+        let min_periods = self.min_periods.max(1);
+
+        self.apply(|st| if st.count < min_periods { None } else { func(st) })
+    }
+
+    /// Computes the cumulative sum at each position.
+    pub fn sum(&self) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|st| Some(st.sum))
+    }
+
+    /// Computes the cumulative arithmetic mean at each position.
+    pub fn mean(&self) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|st| Some(st.mean))
+    }
+
+    /// Computes the cumulative minimum at each position.
+    pub fn min(&self) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|st| Some(st.min))
+    }
+
+    /// Computes the cumulative maximum at each position.
+    pub fn max(&self) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|st| Some(st.max))
+    }
+
+    /// Computes the cumulative standard deviation at each position, using
+    /// `ddof` delta degrees of freedom.
+    pub fn std(&self, ddof: usize) -> Series<L, Option<f64>> {
+        self.apply_min_periods(|st| {
+            if st.count <= ddof { None }
+            else { Some((st.m2 / (st.count - ddof) as f64).sqrt()) }
+        })
+    }
+
+    /// Counts the present values up to each position. This ignores
+    /// `min_periods`.
+    pub fn count(&self) -> Series<L, usize> {
+        self.apply(|st| st.count)
+    }
+}
+
+/// Specifies the decay of an exponentially weighted window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Decay {
+    /// The smoothing factor `alpha`, where `0 < alpha <= 1`.
+    Alpha(f64),
+    /// The span `s`, where `s >= 1` and `alpha = 2 / (s + 1)`.
+    Span(f64),
+    /// The center of mass `c`, where `c >= 0` and `alpha = 1 / (1 + c)`.
+    CenterOfMass(f64),
+    /// The half-life `h`, where `h > 0` and `alpha = 1 - exp(ln(0.5) / h)`.
+    HalfLife(f64),
+}
+
+impl Decay {
+    /// Returns the smoothing factor `alpha` for this decay.
+    pub fn alpha(&self) -> f64 {
+        match *self {
+            Decay::Alpha(a) => a,
+            Decay::Span(s) => 2.0 / (s + 1.0),
+            Decay::CenterOfMass(c) => 1.0 / (1.0 + c),
+            Decay::HalfLife(h) => 1.0 - (0.5f64.ln() / h).exp(),
+        }
+    }
+}
+
+/// An exponentially weighted window adapter over a `Series`, created by
+/// `Series::ewm`. Each aggregation produces a new `Series` with the same
+/// `Index`. Missing values (`None`s and `NaN`s) are skipped, and positions
+/// with fewer than `min_periods` present values so far produce `None`.
+pub struct Ewm<'a, L: Label> {
+    index: &'a Index<L>,
+    values: Vec<Option<f64>>,
+    alpha: f64,
+    adjust: bool,
+    ignore_na: bool,
+    min_periods: usize,
+}
+
+impl<'a, L> Ewm<'a, L>
+where
+    L: Label,
+{
+    pub(crate) fn new(index: &'a Index<L>, values: Vec<Option<f64>>, decay: Decay) -> Self {
+        Self {
+            index,
+            values,
+            alpha: decay.alpha(),
+            adjust: true,
+            ignore_na: false,
+            min_periods: 1,
+        }
+    }
+
+    /// Sets whether to divide by the decaying adjustment factor in beginning
+    /// periods to account for imbalance in relative weightings. Defaults to
+    /// `true`.
+    pub fn adjust(mut self, adjust: bool) -> Self {
+        self.adjust = adjust;
+        self
+    }
+
+    /// Sets whether to ignore missing values when calculating weights. If
+    /// `false`, weights are based on absolute positions, so missing values
+    /// still contribute to the decay. Defaults to `false`.
+    pub fn ignore_na(mut self, ignore_na: bool) -> Self {
+        self.ignore_na = ignore_na;
+        self
+    }
+
+    /// Sets the minimum number of present values required to produce a value.
+    /// Defaults to 1.
+    pub fn min_periods(mut self, min_periods: usize) -> Self {
+        self.min_periods = min_periods;
+        self
+    }
+
+    /// Computes the exponentially weighted moving average at each position.
+    pub fn mean(&self) -> Series<L, Option<f64>> {
+        let old_wt_factor = 1.0 - self.alpha;
+        let new_wt = if self.adjust { 1.0 } else { self.alpha };
+        let min_periods = self.min_periods.max(1);
+
+        let mut weighted: Option<f64> = None;
+        let mut old_wt = 1.0;
+        let mut nobs = 0;
+
+        let values =
+            self.values
+            .iter()
+            .map(|&cur| {
+                nobs += cur.is_some() as usize;
+
+                match (weighted, cur) {
+                    (Some(w), cur) if cur.is_some() || !self.ignore_na => {
+                        old_wt *= old_wt_factor;
+
+                        if let Some(x) = cur {
+                            // Avoid numerical errors on constant series.
+                            if w != x {
+                                weighted = Some((old_wt * w + new_wt * x) / (old_wt + new_wt));
+                            }
+
+                            if self.adjust { old_wt += new_wt; } else { old_wt = 1.0; }
+                        }
+                    },
+                    (None, Some(x)) => { weighted = Some(x); },
+                    _ => {},
+                }
+
+                if nobs >= min_periods { weighted } else { None }
+            })
+            .collect()
+        ;
+
+        Series::new_inner(self.index.clone(), values)
+    }
+
+    /// Computes the exponentially weighted moving variance at each position.
+    /// If `bias` is `false`, the result is corrected for statistical bias.
+    pub fn var(&self, bias: bool) -> Series<L, Option<f64>> {
+        let old_wt_factor = 1.0 - self.alpha;
+        let new_wt = if self.adjust { 1.0 } else { self.alpha };
+        let min_periods = self.min_periods.max(1);
+
+        let mut mean: Option<f64> = None;
+        let mut cov = 0.0;
+        let mut sum_wt = 1.0;
+        let mut sum_wt2 = 1.0;
+        let mut old_wt = 1.0;
+        let mut nobs = 0;
+
+        let values =
+            self.values
+            .iter()
+            .enumerate()
+            .map(|(pos, &cur)| {
+                nobs += cur.is_some() as usize;
+
+                match (mean, cur) {
+                    (Some(m), cur) if pos > 0 && (cur.is_some() || !self.ignore_na) => {
+                        sum_wt *= old_wt_factor;
+                        sum_wt2 *= old_wt_factor * old_wt_factor;
+                        old_wt *= old_wt_factor;
+
+                        if let Some(x) = cur {
+                            // Avoid numerical errors on constant series.
+                            let new_m = if m != x { (old_wt * m + new_wt * x) / (old_wt + new_wt) } else { m };
+
+                            cov = (
+                                old_wt * (cov + (m - new_m) * (m - new_m))
+                                + new_wt * (x - new_m) * (x - new_m)
+                            ) / (old_wt + new_wt);
+
+                            mean = Some(new_m);
+                            sum_wt += new_wt;
+                            sum_wt2 += new_wt * new_wt;
+                            old_wt += new_wt;
+
+                            if !self.adjust {
+                                sum_wt /= old_wt;
+                                sum_wt2 /= old_wt * old_wt;
+                                old_wt = 1.0;
+                            }
+                        }
+                    },
+                    (None, Some(x)) => { mean = Some(x); },
+                    _ => {},
+                }
+
+                if nobs < min_periods { None }
+                else if bias { Some(cov) }
+                else {
+                    let numerator = sum_wt * sum_wt;
+                    let denominator = numerator - sum_wt2;
+
+                    if denominator > 0.0 { Some(numerator / denominator * cov) } else { None }
+                }
+            })
+            .collect()
+        ;
+
+        Series::new_inner(self.index.clone(), values)
+    }
+
+    /// Computes the exponentially weighted moving standard deviation at each
+    /// position. If `bias` is `false`, the variance is corrected for
+    /// statistical bias.
+    pub fn std(&self, bias: bool) -> Series<L, Option<f64>> {
+        self.var(bias).map(|v| v.map(f64::sqrt))
+    }
+}
+
+impl<L, V> Series<L, V>
+where
+    L: Label,
+    V: Numeric,
+{
+    /// Creates an exponentially weighted window adapter over this `Series`.
+    /// `NaN`s are treated as missing values.
+    pub fn ewm(&self, decay: Decay) -> Ewm<'_, L> {
+        Ewm::new(&self.0, to_window_values(self.1.iter().copied().map(Some)), decay)
+    }
+
+    /// Creates an expanding window adapter over this `Series`, requiring at
+    /// least `min_periods` values to produce a value. `NaN`s are treated as
+    /// missing values.
+    pub fn expanding(&self, min_periods: usize) -> Expanding<'_, L> {
+        Expanding::new(&self.0, to_window_values(self.1.iter().copied().map(Some)), min_periods)
+    }
+
+    /// Creates a rolling window adapter over this `Series` with the given
+    /// window size. `NaN`s are treated as missing values.
+    pub fn rolling(&self, window: usize) -> Rolling<'_, L> {
+        Rolling::new(&self.0, to_window_values(self.1.iter().copied().map(Some)), window)
+    }
+}
+
+impl<L, R> Series<L, Option<R>>
+where
+    L: Label,
+    R: Numeric,
+{
+    /// Creates an exponentially weighted window adapter over this `Series`.
+    /// `None`s and `NaN`s are treated as missing values.
+    pub fn ewm(&self, decay: Decay) -> Ewm<'_, L> {
+        Ewm::new(&self.0, to_window_values(self.1.iter().copied()), decay)
+    }
+
+    /// Creates an expanding window adapter over this `Series`, requiring at
+    /// least `min_periods` values to produce a value. `None`s and `NaN`s are
+    /// treated as missing values.
+    pub fn expanding(&self, min_periods: usize) -> Expanding<'_, L> {
+        Expanding::new(&self.0, to_window_values(self.1.iter().copied()), min_periods)
+    }
+
+    /// Creates a rolling window adapter over this `Series` with the given
+    /// window size. `None`s and `NaN`s are treated as missing values.
+    pub fn rolling(&self, window: usize) -> Rolling<'_, L> {
+        Rolling::new(&self.0, to_window_values(self.1.iter().copied()), window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolling() {
+        let s = Series::from_iter_checked(vec![
+            ('a', 1), ('b', 2), ('c', 3), ('d', 4), ('e', 5),
+        ]).unwrap();
+
+        let r = s.rolling(3);
+
+        assert_eq!(r.sum().into_values(), vec![None, None, Some(6.0), Some(9.0), Some(12.0)]);
+        assert_eq!(r.mean().into_values(), vec![None, None, Some(2.0), Some(3.0), Some(4.0)]);
+        assert_eq!(r.min().into_values(), vec![None, None, Some(1.0), Some(2.0), Some(3.0)]);
+        assert_eq!(r.max().into_values(), vec![None, None, Some(3.0), Some(4.0), Some(5.0)]);
+        assert_eq!(r.std(1).into_values(), vec![None, None, Some(1.0), Some(1.0), Some(1.0)]);
+        assert_eq!(r.count().into_values(), vec![1, 2, 3, 3, 3]);
+        assert_eq!(r.sum().into_index(), s.index().clone());
+
+        let r = s.rolling(3).min_periods(1);
+
+        assert_eq!(r.sum().into_values(), vec![Some(1.0), Some(3.0), Some(6.0), Some(9.0), Some(12.0)]);
+        assert_eq!(r.std(1).into_values(), vec![None, Some(0.5f64.sqrt()), Some(1.0), Some(1.0), Some(1.0)]);
+    }
+
+    #[test]
+    fn expanding() {
+        let s = Series::from_iter_checked(vec![('a', 1), ('b', 3), ('c', 2), ('d', 6)]).unwrap();
+
+        let e = s.expanding(1);
+
+        assert_eq!(e.sum().into_values(), vec![Some(1.0), Some(4.0), Some(6.0), Some(12.0)]);
+        assert_eq!(e.mean().into_values(), vec![Some(1.0), Some(2.0), Some(2.0), Some(3.0)]);
+        assert_eq!(e.min().into_values(), vec![Some(1.0), Some(1.0), Some(1.0), Some(1.0)]);
+        assert_eq!(e.max().into_values(), vec![Some(1.0), Some(3.0), Some(3.0), Some(6.0)]);
+        let std = e.std(1).into_values();
+        let expected = vec![None, Some(2.0f64.sqrt()), Some(1.0), Some((14.0f64 / 3.0).sqrt())];
+        for (p, e) in std.into_iter().zip(expected) {
+            assert_eq!(p.is_some(), e.is_some());
+            assert!((p.unwrap_or(0.0) - e.unwrap_or(0.0)).abs() < 1e-12);
+        }
+        assert_eq!(e.count().into_values(), vec![1, 2, 3, 4]);
+        assert_eq!(e.sum().into_index(), s.index().clone());
+
+        let e = s.expanding(3);
+
+        assert_eq!(e.sum().into_values(), vec![None, None, Some(6.0), Some(12.0)]);
+    }
+
+    #[test]
+    fn expanding_skip_missing() {
+        let s = Series::from_iter_checked(vec![
+            ('a', None), ('b', Some(2.0)), ('c', Some(f64::NAN)), ('d', Some(4.0)),
+        ]).unwrap();
+
+        let e = s.expanding(1);
+
+        assert_eq!(e.sum().into_values(), vec![None, Some(2.0), Some(2.0), Some(6.0)]);
+        assert_eq!(e.mean().into_values(), vec![None, Some(2.0), Some(2.0), Some(3.0)]);
+        assert_eq!(e.count().into_values(), vec![0, 1, 1, 2]);
+    }
+
+    fn assert_approx_eq(produced: Vec<Option<f64>>, expected: Vec<Option<f64>>) {
+        assert_eq!(produced.len(), expected.len());
+
+        for (p, e) in produced.into_iter().zip(expected) {
+            match (p, e) {
+                (Some(p), Some(e)) => assert!((p - e).abs() < 1e-9, "{} != {}", p, e),
+                (p, e) => assert_eq!(p, e),
+            }
+        }
+    }
+
+    #[test]
+    fn ewm_mean() {
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', 3.0)]).unwrap();
+
+        // Expected values match `pandas.Series.ewm(alpha=0.5).mean()`.
+        assert_approx_eq(
+            s.ewm(Decay::Alpha(0.5)).mean().into_values(),
+            vec![Some(1.0), Some(5.0 / 3.0), Some(17.0 / 7.0)],
+        );
+        assert_approx_eq(
+            s.ewm(Decay::Alpha(0.5)).adjust(false).mean().into_values(),
+            vec![Some(1.0), Some(1.5), Some(2.25)],
+        );
+        assert_approx_eq(
+            s.ewm(Decay::Span(3.0)).mean().into_values(),
+            s.ewm(Decay::Alpha(0.5)).mean().into_values(),
+        );
+        assert_approx_eq(
+            s.ewm(Decay::CenterOfMass(1.0)).mean().into_values(),
+            s.ewm(Decay::Alpha(0.5)).mean().into_values(),
+        );
+        assert_approx_eq(
+            s.ewm(Decay::HalfLife(1.0)).mean().into_values(),
+            s.ewm(Decay::Alpha(0.5)).mean().into_values(),
+        );
+
+        let s = Series::from_iter_checked(vec![('a', Some(1.0)), ('b', None), ('c', Some(3.0))]).unwrap();
+
+        // With `ignore_na=False`, the weight of the first value decays twice.
+        assert_approx_eq(
+            s.ewm(Decay::Alpha(0.5)).mean().into_values(),
+            vec![Some(1.0), Some(1.0), Some(2.6)],
+        );
+        assert_approx_eq(
+            s.ewm(Decay::Alpha(0.5)).ignore_na(true).mean().into_values(),
+            vec![Some(1.0), Some(1.0), Some(7.0 / 3.0)],
+        );
+        assert_approx_eq(
+            s.ewm(Decay::Alpha(0.5)).min_periods(2).mean().into_values(),
+            vec![None, None, Some(2.6)],
+        );
+    }
+
+    #[test]
+    fn ewm_var() {
+        let s = Series::from_iter_checked(vec![('a', 1.0), ('b', 2.0), ('c', 3.0)]).unwrap();
+
+        // Expected values match `pandas.Series.ewm(alpha=0.5).var(bias=...)`.
+        assert_approx_eq(
+            s.ewm(Decay::Alpha(0.5)).var(false).into_values(),
+            vec![None, Some(0.5), Some(13.0 / 14.0)],
+        );
+        assert_approx_eq(
+            s.ewm(Decay::Alpha(0.5)).var(true).into_values(),
+            vec![Some(0.0), Some(2.0 / 9.0), Some(26.0 / 49.0)],
+        );
+        assert_approx_eq(
+            s.ewm(Decay::Alpha(0.5)).std(false).into_values(),
+            vec![None, Some(0.5f64.sqrt()), Some((13.0f64 / 14.0).sqrt())],
+        );
+    }
+
+    #[test]
+    fn rolling_skip_missing() {
+        let s = Series::from_iter_checked(vec![
+            ('a', Some(1.0)), ('b', None), ('c', Some(3.0)), ('d', Some(f64::NAN)), ('e', Some(5.0)),
+        ]).unwrap();
+
+        let r = s.rolling(2);
+
+        assert_eq!(r.sum().into_values(), vec![None, None, None, None, None]);
+        assert_eq!(r.count().into_values(), vec![1, 1, 1, 1, 1]);
+
+        let r = s.rolling(3).min_periods(2);
+
+        assert_eq!(r.sum().into_values(), vec![None, None, Some(4.0), None, Some(8.0)]);
+        assert_eq!(r.mean().into_values(), vec![None, None, Some(2.0), None, Some(4.0)]);
+    }
+}
@@ -0,0 +1,112 @@
+use std::iter::FromIterator;
+
+use crate::frame::Frame;
+use crate::index::Index;
+use crate::series::Series;
+use crate::series::SeriesSparse;
+use crate::types::Column;
+
+pub fn ints() -> Series<char, i32> {
+    Series::from_iter_checked(vec![('a', 1), ('b', 2), ('c', 3), ('d', 4)]).unwrap()
+}
+
+pub fn sparse_ints() -> SeriesSparse<char, i32> {
+    Series::from_iter_checked(vec![
+        ('a', None), ('b', Some(10)), ('c', None), ('d', None), ('e', Some(40)),
+    ]).unwrap().to_sparse()
+}
+
+pub fn strings() -> Series<char, String> {
+    Series::from_iter_checked(vec![
+        ('a', String::from(" Hello World ")),
+        ('b', String::from("héllo")),
+        ('c', String::new()),
+    ]).unwrap()
+}
+
+pub fn even_labels() -> Frame<u32> {
+    let index = Index::from_iter(vec![2, 4, 6]);
+
+    Frame::from_columns(index.clone(), vec![
+        ("ints", Column::from(Series::from_values(index.clone(), vec![20i32, 40, 60]).unwrap())),
+        ("opts", Column::from(Series::from_values(index, vec![Some('b'), None, Some('f')]).unwrap())),
+    ]).unwrap()
+}
+
+pub fn city_sales() -> Frame<usize> {
+    let index = Index::from_iter(0..6);
+
+    Frame::from_columns(index.clone(), vec![
+        ("city", Column::from(Series::from_values(index.clone(), vec![
+            String::from("A"), String::from("B"), String::from("A"),
+            String::from("C"), String::from("B"), String::from("A"),
+        ]).unwrap())),
+        ("year", Column::from(Series::from_values(index.clone(), vec![1, 1, 2, 1, 1, 1]).unwrap())),
+        ("sales", Column::from(Series::from_values(index.clone(), vec![10, 20, 30, 40, 50, 60]).unwrap())),
+        ("price", Column::from(Series::from_values(index.clone(), vec![
+            Some(1.0), None, Some(2.0), Some(f64::NAN), Some(4.0), Some(3.0),
+        ]).unwrap())),
+        ("flag", Column::from(Series::from_values(index, vec![true, false, true, true, false, false]).unwrap())),
+    ]).unwrap()
+}
+
+pub fn store_sales() -> Frame<usize> {
+    let index = Index::from_iter(0..6);
+    let strs = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    Frame::from_columns(index.clone(), vec![
+        ("store", Column::from(Series::from_values(index.clone(), strs(&["A", "A", "B", "B", "C", "A"])).unwrap())),
+        ("year", Column::from(Series::from_values(index.clone(), vec![2020, 2021, 2020, 2021, 2021, 2021]).unwrap())),
+        ("total", Column::from(Series::from_values(index, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap())),
+    ]).unwrap()
+}
+
+pub fn people() -> Frame<char> {
+    let index = Index::from_iter("abcd".chars());
+
+    Frame::from_columns(index.clone(), vec![
+        ("id", Column::from(Series::from_values(index.clone(), vec![1, 2, 3, 2]).unwrap())),
+        ("score", Column::from(Series::from_values(index, vec![10, 20, 30, 40]).unwrap())),
+    ]).unwrap()
+}
+
+pub fn teams() -> Frame<char> {
+    let index = Index::from_iter("xyz".chars());
+
+    Frame::from_columns(index.clone(), vec![
+        ("id", Column::from(Series::from_values(index.clone(), vec![2, 4, 1]).unwrap())),
+        ("score", Column::from(Series::from_values(index, vec![Some(0.5), None, Some(1.5)]).unwrap())),
+    ]).unwrap()
+}
+
+pub fn id_score_flag() -> Frame<char> {
+    let index = Index::from_iter("abc".chars());
+
+    Frame::from_columns(index.clone(), vec![
+        ("id", Column::from(Series::from_values(index.clone(), vec![1u32, 2, 3]).unwrap())),
+        ("score", Column::from(Series::from_values(index.clone(), vec![Some(0.5f32), None, Some(1.5)]).unwrap())),
+        ("flag", Column::from(Series::from_values(index, vec![true, false, true]).unwrap())),
+    ]).unwrap()
+}
+
+pub fn id_score_name() -> Frame<char> {
+    let index = Index::from_iter("abcde".chars());
+
+    Frame::from_columns(index.clone(), vec![
+        ("id", Column::from(Series::from_values(index.clone(), vec![1i64, 2, 3, 4, 5]).unwrap())),
+        ("score", Column::from(Series::from_values(index.clone(), vec![Some(0.5), None, Some(1.5), None, Some(2.5)]).unwrap())),
+        ("name", Column::from(Series::from_values(index, "pqrst".chars().map(String::from).collect()).unwrap())),
+    ]).unwrap()
+}
+
+pub fn id_score_flag_name_initial() -> Frame<char> {
+    let index = Index::from_iter("abc".chars());
+
+    Frame::from_columns(index.clone(), vec![
+        ("id", Column::from(Series::from_values(index.clone(), vec![1u8, 2, 3]).unwrap())),
+        ("score", Column::from(Series::from_values(index.clone(), vec![Some(0.5), None, Some(1.5)]).unwrap())),
+        ("flag", Column::from(Series::from_values(index.clone(), vec![true, false, true]).unwrap())),
+        ("name", Column::from(Series::from_values(index.clone(), vec![String::from("p"), String::from("q\""), String::from("r")]).unwrap())),
+        ("initial", Column::from(Series::from_values(index, vec![Some('x'), None, Some('z')]).unwrap())),
+    ]).unwrap()
+}
@@ -9,6 +9,6 @@ pub struct IndexGen;
 
 impl IndexGen {
     pub fn index<L: Label + Arbitrary>() -> impl Strategy<Value = Index<L>> {
-        LabelGen::ordered().prop_map(|v| Index::from(v))
+        LabelGen::ordered().prop_map(Index::from)
     }
 }
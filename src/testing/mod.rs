@@ -1,2 +1,3 @@
 pub mod label;
 pub mod index;
+pub mod fixtures;
@@ -1,19 +1,219 @@
 
 use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Sub;
 
-pub trait RawType: Debug + Clone + Send + Sized {}
+use crate::series::Series;
+use crate::types::Column;
+use crate::types::DType;
+
+pub trait RawType: Debug + Clone + Send + Sized {
+    /// Returns the `DType` that represents this type.
+    fn dtype() -> DType;
+
+    /// Formats this value for display, e.g. when printing a `Series`.
+    fn fmt_value(&self, f: &mut Formatter) -> FmtResult;
+
+    /// Returns the number of bytes this value owns on the heap, which is zero
+    /// for most types.
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
 
 /// The main trait the defines what types are able to be stored in a `Series`.
-pub trait Storable: Debug + Clone + Send + Sized {}
+pub trait Storable: Debug + Clone + Send + Sized {
+    /// Returns the number of bytes this value owns on the heap.
+    fn heap_size(&self) -> usize;
+
+    /// Returns a name for this type, based on the `DType` of its raw values.
+    fn dtype_name() -> String;
+
+    /// Formats this value for display, e.g. when printing a `Series`.
+    fn fmt_value(&self, f: &mut Formatter) -> FmtResult;
+}
 
 // All `RawType`s can be `Storable`s.
-impl <R: RawType> Storable for R {}
+impl <R: RawType> Storable for R {
+    fn heap_size(&self) -> usize {
+        RawType::heap_size(self)
+    }
+
+    fn dtype_name() -> String {
+        format!("{:?}", R::dtype())
+    }
+
+    fn fmt_value(&self, f: &mut Formatter) -> FmtResult {
+        RawType::fmt_value(self, f)
+    }
+}
 
 // In addition, all `Option<RawType>`s are `Storable`s.
-impl <R: RawType> Storable for Option<R> {}
+impl <R: RawType> Storable for Option<R> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, RawType::heap_size)
+    }
+
+    fn dtype_name() -> String {
+        format!("Option<{:?}>", R::dtype())
+    }
+
+    fn fmt_value(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Some(r) => r.fmt_value(f),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+// Lists of `RawType`s are also `Storable`s, e.g. for split or nested values.
+impl <R: RawType> Storable for Vec<R> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * std::mem::size_of::<R>() + self.iter().map(RawType::heap_size).sum::<usize>()
+    }
+
+    fn dtype_name() -> String {
+        format!("List<{:?}>", R::dtype())
+    }
+
+    fn fmt_value(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "[")?;
+        for (i, r) in self.iter().enumerate() {
+            if i > 0 { write!(f, ", ")?; }
+            r.fmt_value(f)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Trait for values that can be hashed and compared as keys, e.g. for grouping
+/// or finding duplicates. Unlike `Hash` and `Eq`, this is also implemented for
+/// floats, which are compared by their bit patterns after mapping `-0.0` to
+/// `0.0` and every `NaN` to a single `NaN`, so that e.g. all `NaN` keys form a
+/// single group.
+pub trait KeyHash {
+    /// Feeds this value into a hasher.
+    fn key_hash<H: Hasher>(&self, state: &mut H);
+
+    /// Compares this value with another. Values that are equal must have the
+    /// same hash.
+    fn key_eq(&self, other: &Self) -> bool;
+}
 
 /// Trait that defines what is needed for a label in an `Index`.
 pub trait Label: RawType + PartialEq + Eq + Hash + PartialOrd + Ord {}
 
 impl<T: RawType + PartialEq + Eq + Hash + PartialOrd + Ord> Label for T {}
+
+/// Trait for value types that have a `Column` variant, allowing statically
+/// typed access to the columns of a `Frame`.
+pub trait ColumnType: Storable {
+    /// Returns a reference to the wrapped `Series` if it has values of this
+    /// type.
+    fn from_column<L: Label>(column: &Column<L>) -> Option<&Series<L, Self>>;
+
+    /// Returns a mutable reference to the wrapped `Series` if it has values of
+    /// this type.
+    fn from_column_mut<L: Label>(column: &mut Column<L>) -> Option<&mut Series<L, Self>>;
+
+    /// Wraps a `Series` with values of this type in a `Column`.
+    fn into_column<L: Label>(series: Series<L, Self>) -> Column<L>;
+}
+
+/// Trait that defines what is needed for numeric operations on a `Series`.
+pub trait Numeric:
+    RawType
+    + Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The additive identity.
+    fn zero() -> Self;
+
+    /// The multiplicative identity.
+    fn one() -> Self;
+
+    /// Converts this value to an `f64`, possibly losing precision.
+    fn to_f64(self) -> f64;
+}
+
+/// Trait for numeric types that have a sign, and thus an absolute value.
+pub trait Signed: Numeric {
+    /// Computes the absolute value.
+    fn abs(self) -> Self;
+}
+
+/// Trait for integral numeric types.
+pub trait Integer: Numeric {
+    /// Raises this value to an integer power.
+    fn pow(self, n: u32) -> Self;
+
+    /// Adds, returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtracts, returning `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplies, returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Adds, wrapping around at the bounds of the type.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Subtracts, wrapping around at the bounds of the type.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Multiplies, wrapping around at the bounds of the type.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Adds, saturating at the bounds of the type.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Subtracts, saturating at the bounds of the type.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Multiplies, saturating at the bounds of the type.
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+/// Trait for floating-point numeric types.
+pub trait Float: Signed {
+    /// Converts an `f64` to this type, possibly losing precision.
+    fn from_f64(v: f64) -> Self;
+
+    /// Rounds to the nearest integer, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+
+    /// Raises this value to an integer power.
+    fn powi(self, n: i32) -> Self;
+
+    /// Raises this value to a floating-point power.
+    fn powf(self, n: Self) -> Self;
+
+    /// Computes the natural logarithm.
+    fn ln(self) -> Self;
+
+    /// Computes `e` raised to the power of this value.
+    fn exp(self) -> Self;
+
+    /// Computes the square root, or `NaN` if negative.
+    fn sqrt(self) -> Self;
+}
+
+/// Trait for converting a value of one raw type into another. Conversions
+/// that fail or would lose information return `None`. Which pairs of types
+/// can be converted is described by `DType::can_cast_to`.
+pub trait Cast<T: RawType>: RawType {
+    /// Converts this value, or returns `None` if it cannot be represented
+    /// exactly as the target type.
+    fn cast(&self) -> Option<T>;
+}
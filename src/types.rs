@@ -9,41 +9,475 @@ pub use chrono::naive::{
     NaiveDateTime as DateTime,
 };
 
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use crate::index::Index;
+use crate::series::Series;
+use crate::traits::Cast;
+use crate::traits::ColumnType;
+use crate::traits::Float;
+use crate::traits::Integer;
+use crate::traits::KeyHash;
+use crate::traits::Label;
+use crate::traits::Numeric;
+use crate::traits::Signed;
 use crate::traits::RawType;
+use crate::traits::Storable;
 
 /// Helper macro to create the plumbing for each type supported in `rustable`.
 macro_rules! define_types {
-    ( $( ($type:ty, $name:ident $( , $cfg_flag:meta )?), )+ ) => {
+    ( $( ($type:ty, $name:ident $( , $cfg_flag:meta )? $( ; heap_size = $heap_size:expr )?), )+ ) => {
         paste::item! {
-            // /// Represents all data types supported by `rustable`.
-            // #[derive(Debug, PartialEq, Eq, Copy, Clone)]
-            // pub enum DType {
-            //     $(
-            //         $(#[$cfg_flag])? $name,
-            //         // $(#[$cfg_flag])? [<Opt $name>],
-            //     )*
-            // }
+            /// Represents all data types supported by `rustable`.
+            #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+            pub enum DType {
+                $(
+                    $(#[$cfg_flag])? $name,
+                    // $(#[$cfg_flag])? [<Opt $name>],
+                )*
+            }
 
             /// Provides references to elements within a potentially
             /// heterogeneous row of data.
-            #[derive(PartialEq)]
+            #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
             pub enum Datum<'a> {
                 $(
                     $(#[$cfg_flag])? $name(&'a $type),
-                    // $(#[$cfg_flag])? [<Opt $name>](&'a Option<$type>),
+                    $(#[$cfg_flag])? [<Opt $name>](&'a Option<$type>),
                 )*
             }
 
+            /// Formats the referenced value as when displaying a `Series`.
+            impl<'a> Display for Datum<'a> {
+                fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(v) => Storable::fmt_value(*v, f),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](v) => Storable::fmt_value(*v, f),
+                        )*
+                    }
+                }
+            }
+
+            impl<'a> Datum<'a> {
+                /// Returns `true` if this references a missing `Option` value.
+                pub fn is_none(&self) -> bool {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(_) => false,
+                            $(#[$cfg_flag])? Self::[<Opt $name>](v) => v.is_none(),
+                        )*
+                    }
+                }
+
+                /// Feeds this value into a hasher for use as a grouping key. A
+                /// value hashes the same as the same value wrapped in `Some`.
+                pub(crate) fn key_hash<H: Hasher>(&self, state: &mut H) {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])?
+                            Self::$name(v) => {
+                                std::mem::discriminant(&DType::$name).hash(state);
+                                true.hash(state);
+                                v.key_hash(state);
+                            },
+                            $(#[$cfg_flag])?
+                            Self::[<Opt $name>](v) => {
+                                std::mem::discriminant(&DType::$name).hash(state);
+                                v.key_hash(state);
+                            },
+                        )*
+                    }
+                }
+
+                /// Compares this value with another for use as a grouping key.
+                /// Values of different base types are never equal, but a value
+                /// equals the same value wrapped in `Some`.
+                pub(crate) fn key_eq(&self, other: &Self) -> bool {
+                    match (self, other) {
+                        $(
+                            $(#[$cfg_flag])? (Self::$name(a), Self::$name(b)) => a.key_eq(b),
+                            $(#[$cfg_flag])? (Self::[<Opt $name>](a), Self::[<Opt $name>](b)) => a.key_eq(b),
+                            $(#[$cfg_flag])?
+                            (Self::$name(a), Self::[<Opt $name>](Some(b)))
+                            | (Self::[<Opt $name>](Some(b)), Self::$name(a)) => a.key_eq(b),
+                        )*
+                        _ => false,
+                    }
+                }
+            }
+
             /// An enum representation of a `Series`, typically only seen when
             /// trying to get a reference to a column from a `Frame` without
             /// knowing its type beforehand.
-            pub enum Column {
+            #[derive(Debug, Clone)]
+            pub enum Column<L: Label> {
+                $(
+                    $(#[$cfg_flag])? $name(Series<L, $type>),
+                    $(#[$cfg_flag])? [<Opt $name>](Series<L, Option<$type>>),
+                )*
+            }
+
+            impl<L: Label> Column<L> {
+                /// Returns the data type of the values of the wrapped `Series`.
+                /// Columns of `Option` values report the type they wrap.
+                pub fn dtype(&self) -> DType {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(_) => DType::$name,
+                            $(#[$cfg_flag])? Self::[<Opt $name>](_) => DType::$name,
+                        )*
+                    }
+                }
+
+                /// Returns `true` if the wrapped `Series` has `Option` values.
+                pub fn is_optional(&self) -> bool {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(_) => false,
+                            $(#[$cfg_flag])? Self::[<Opt $name>](_) => true,
+                        )*
+                    }
+                }
+
+                /// Returns the name of the storage type of the values of the
+                /// wrapped `Series`, e.g. `"I32"` or `"Option<F64>"`.
+                pub fn dtype_name(&self) -> String {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(_) => <$type as Storable>::dtype_name(),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](_) => <Option<$type> as Storable>::dtype_name(),
+                        )*
+                    }
+                }
+
+                /// Returns a read-only reference to the `Index` of the wrapped
+                /// `Series`.
+                pub fn index(&self) -> &Index<L> {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(series) => series.index(),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](series) => series.index(),
+                        )*
+                    }
+                }
+
+                /// Sets the name of the wrapped `Series`.
+                pub(crate) fn set_name(&mut self, name: &str) {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(series) => series.set_name(Some(name.to_string())),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](series) => series.set_name(Some(name.to_string())),
+                        )*
+                    }
+                }
+
+                /// Replaces the `Index` and name of the wrapped `Series`. The
+                /// caller must ensure that the new `Index` has the same length.
+                pub(crate) fn relabeled<K: Label>(self, index: Index<K>, name: &str) -> Column<K> {
+                    let name = Some(name.to_string());
+
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(series) => Column::$name(Series(index, series.1, name)),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](series) => Column::[<Opt $name>](Series(index, series.1, name)),
+                        )*
+                    }
+                }
+
+                /// Returns a new `Column` containing the label/value pairs at
+                /// the given positions, in that order. Returns `None` if any
+                /// position is out of bounds or repeated.
+                pub fn take(&self, positions: &[usize]) -> Option<Self> {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(series) => series.take(positions).map(Self::$name),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](series) => series.take(positions).map(Self::[<Opt $name>]),
+                        )*
+                    }
+                }
+
+                /// Clones the values at the given positions, which may repeat,
+                /// into a new `Column` with the given `Index`. If `optional` is
+                /// `true`, the new `Column` has `Option` values and missing
+                /// positions become `None`; otherwise, all positions must be
+                /// present.
+                pub(crate) fn gather<K: Label>(&self, positions: &[Option<usize>], index: Index<K>, optional: bool) -> Column<K> {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])?
+                            Self::$name(series) => {
+                                let vs = &series.1;
+
+                                if optional {
+                                    let values = positions.iter().map(|p| p.map(|p| vs[p].clone())).collect();
+                                    Column::[<Opt $name>](Series(index, values, series.2.clone()))
+                                } else {
+                                    let values = positions.iter().map(|p| vs[p.unwrap()].clone()).collect();
+                                    Column::$name(Series(index, values, series.2.clone()))
+                                }
+                            },
+                            $(#[$cfg_flag])?
+                            Self::[<Opt $name>](series) => {
+                                let vs = &series.1;
+                                let values = positions.iter().map(|p| p.and_then(|p| vs[p].clone())).collect();
 
+                                Column::[<Opt $name>](Series(index, values, series.2.clone()))
+                            },
+                        )*
+                    }
+                }
+
+                /// For each pair of positions, clones the value at the left
+                /// position from this `Column` if present, or else the value at
+                /// the right position from the other `Column`, into a new
+                /// `Column` with the given `Index`. Each pair must have at least
+                /// one position. The new `Column` has `Option` values if either
+                /// `Column` does. Returns `None` if the `Column`s have different
+                /// base types.
+                pub(crate) fn coalesce_gather<K: Label>(
+                    &self,
+                    other: &Self,
+                    positions: &[(Option<usize>, Option<usize>)],
+                    index: Index<K>,
+                ) -> Option<Column<K>>
+                {
+                    match (self, other) {
+                        $(
+                            $(#[$cfg_flag])?
+                            (Self::$name(a), Self::$name(b)) => {
+                                let values =
+                                    positions
+                                    .iter()
+                                    .map(|&(l, r)| l.map_or_else(|| b.1[r.unwrap()].clone(), |l| a.1[l].clone()))
+                                    .collect()
+                                ;
+
+                                Some(Column::$name(Series(index, values, a.2.clone())))
+                            },
+                            $(#[$cfg_flag])?
+                            (Self::[<Opt $name>](a), Self::[<Opt $name>](b)) => {
+                                let values =
+                                    positions
+                                    .iter()
+                                    .map(|&(l, r)| l.map_or_else(|| b.1[r.unwrap()].clone(), |l| a.1[l].clone()))
+                                    .collect()
+                                ;
+
+                                Some(Column::[<Opt $name>](Series(index, values, a.2.clone())))
+                            },
+                            $(#[$cfg_flag])?
+                            (Self::$name(a), Self::[<Opt $name>](b)) => {
+                                let values =
+                                    positions
+                                    .iter()
+                                    .map(|&(l, r)| l.map_or_else(|| b.1[r.unwrap()].clone(), |l| Some(a.1[l].clone())))
+                                    .collect()
+                                ;
+
+                                Some(Column::[<Opt $name>](Series(index, values, a.2.clone())))
+                            },
+                            $(#[$cfg_flag])?
+                            (Self::[<Opt $name>](a), Self::$name(b)) => {
+                                let values =
+                                    positions
+                                    .iter()
+                                    .map(|&(l, r)| l.map_or_else(|| Some(b.1[r.unwrap()].clone()), |l| a.1[l].clone()))
+                                    .collect()
+                                ;
+
+                                Some(Column::[<Opt $name>](Series(index, values, a.2.clone())))
+                            },
+                        )*
+                        _ => None,
+                    }
+                }
+
+                /// Stacks the values of the given parts end to end into a new
+                /// `Column` with the given `Index` and name. Each part is either
+                /// a `Column`, or `None` and a number of missing values. The new
+                /// `Column` has `Option` values if any part is optional or
+                /// missing. Returns `None` if there are no `Column`s, or if the
+                /// `Column`s have different base types.
+                pub(crate) fn concat<K: Label>(parts: &[(Option<&Self>, usize)], index: Index<K>, name: &str) -> Option<Column<K>> {
+                    let first = parts.iter().find_map(|&(column, _)| column)?;
+                    let optional = parts.iter().any(|&(column, _)| column.map_or(true, Self::is_optional));
+                    let name = Some(name.to_string());
+
+                    match first {
+                        $(
+                            $(#[$cfg_flag])?
+                            Self::$name(_) | Self::[<Opt $name>](_) => {
+                                let mut values = Vec::with_capacity(index.len());
+
+                                for &(column, len) in parts {
+                                    match column {
+                                        None => values.extend(std::iter::repeat(None).take(len)),
+                                        Some(Self::$name(series)) => values.extend(series.1.iter().cloned().map(Some)),
+                                        Some(Self::[<Opt $name>](series)) => values.extend(series.1.iter().cloned()),
+                                        Some(_) => return None,
+                                    }
+                                }
+
+                                if optional {
+                                    Some(Column::[<Opt $name>](Series(index, values, name)))
+                                } else {
+                                    let values = values.into_iter().map(Option::unwrap).collect();
+                                    Some(Column::$name(Series(index, values, name)))
+                                }
+                            },
+                        )*
+                    }
+                }
+
+                /// Returns the number of values in the wrapped `Series`.
+                pub fn len(&self) -> usize {
+                    self.index().len()
+                }
+
+                /// Returns `true` if the wrapped `Series` contains no values.
+                pub fn is_empty(&self) -> bool {
+                    self.index().is_empty()
+                }
+
+                /// Returns an estimate of the number of bytes used to store the
+                /// `Index` and values of the wrapped `Series`. If `deep` is
+                /// `true`, the heap contents of the labels and values are also
+                /// included.
+                pub fn memory_usage(&self, deep: bool) -> usize {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(series) => series.memory_usage(deep),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](series) => series.memory_usage(deep),
+                        )*
+                    }
+                }
+
+                /// Returns an estimate of the number of bytes used to store the
+                /// values of the wrapped `Series`, excluding its `Index`.
+                pub(crate) fn values_memory_usage(&self, deep: bool) -> usize {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(series) => series.values_memory_usage(deep),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](series) => series.values_memory_usage(deep),
+                        )*
+                    }
+                }
+
+                /// Returns a `Datum` referencing the value at the given
+                /// position, or `None` if the position is out of bounds.
+                pub fn datum(&self, pos: usize) -> Option<Datum<'_>> {
+                    match self {
+                        $(
+                            $(#[$cfg_flag])? Self::$name(series) => series.iloc(pos).map(Datum::$name),
+                            $(#[$cfg_flag])? Self::[<Opt $name>](series) => series.iloc(pos).map(Datum::[<Opt $name>]),
+                        )*
+                    }
+                }
+
+                $(
+                    #[doc = concat!("Returns a reference to the wrapped `Series` if it has `", stringify!($type), "` values.")]
+                    $(#[$cfg_flag])?
+                    pub fn [<as_ $name:lower>](&self) -> Option<&Series<L, $type>> {
+                        match self {
+                            Self::$name(series) => Some(series),
+                            _ => None,
+                        }
+                    }
+
+                    #[doc = concat!("Returns a reference to the wrapped `Series` if it has `Option<", stringify!($type), ">` values.")]
+                    $(#[$cfg_flag])?
+                    pub fn [<as_opt_ $name:lower>](&self) -> Option<&Series<L, Option<$type>>> {
+                        match self {
+                            Self::[<Opt $name>](series) => Some(series),
+                            _ => None,
+                        }
+                    }
+                )*
             }
 
             $(
                 $(#[$cfg_flag])?
-                impl RawType for $type {}
+                impl ColumnType for $type {
+                    fn from_column<L: Label>(column: &Column<L>) -> Option<&Series<L, Self>> {
+                        match column {
+                            Column::$name(series) => Some(series),
+                            _ => None,
+                        }
+                    }
+
+                    fn from_column_mut<L: Label>(column: &mut Column<L>) -> Option<&mut Series<L, Self>> {
+                        match column {
+                            Column::$name(series) => Some(series),
+                            _ => None,
+                        }
+                    }
+
+                    fn into_column<L: Label>(series: Series<L, Self>) -> Column<L> {
+                        Column::$name(series)
+                    }
+                }
+
+                $(#[$cfg_flag])?
+                impl ColumnType for Option<$type> {
+                    fn from_column<L: Label>(column: &Column<L>) -> Option<&Series<L, Self>> {
+                        match column {
+                            Column::[<Opt $name>](series) => Some(series),
+                            _ => None,
+                        }
+                    }
+
+                    fn from_column_mut<L: Label>(column: &mut Column<L>) -> Option<&mut Series<L, Self>> {
+                        match column {
+                            Column::[<Opt $name>](series) => Some(series),
+                            _ => None,
+                        }
+                    }
+
+                    fn into_column<L: Label>(series: Series<L, Self>) -> Column<L> {
+                        Column::[<Opt $name>](series)
+                    }
+                }
+            )*
+
+            $(
+                $(#[$cfg_flag])?
+                impl<L: Label> From<Series<L, $type>> for Column<L> {
+                    fn from(series: Series<L, $type>) -> Self {
+                        Self::$name(series)
+                    }
+                }
+
+                $(#[$cfg_flag])?
+                impl<L: Label> From<Series<L, Option<$type>>> for Column<L> {
+                    fn from(series: Series<L, Option<$type>>) -> Self {
+                        Self::[<Opt $name>](series)
+                    }
+                }
+            )*
+
+            $(
+                $(#[$cfg_flag])?
+                impl RawType for $type {
+                    fn dtype() -> DType {
+                        DType::$name
+                    }
+
+                    fn fmt_value(&self, f: &mut Formatter) -> FmtResult {
+                        Display::fmt(self, f)
+                    }
+
+                    $(
+                        fn heap_size(&self) -> usize {
+                            $heap_size(self)
+                        }
+                    )?
+                }
 
                 // $(#[$cfg_flag])?
                 // impl Storable for Option<$type> {
@@ -77,7 +511,7 @@ define_types!(
     (char, Char),
     (bool, Bool),
 
-    (String, Str),
+    (String, Str; heap_size = String::capacity),
 
     (Decimal, Decimal, cfg(feature = "decimal")),
 
@@ -85,3 +519,478 @@ define_types!(
     (Time, Time, cfg(feature = "date-time")),
     (DateTime, DateTime, cfg(feature = "date-time")),
 );
+
+impl<L: Label> Column<L> {
+    /// Creates a `Column` from values that may be missing. The `Column` has
+    /// `Option` values if `optional` is `true` or any value is `None`.
+    #[cfg(any(feature = "arrow", feature = "csv", feature = "excel", feature = "serde_json", feature = "sql"))]
+    pub(crate) fn from_options<T>(index: Index<L>, values: Vec<Option<T>>, optional: bool) -> Self
+    where
+        T: ColumnType,
+        Option<T>: ColumnType,
+    {
+        if optional || values.iter().any(Option::is_none) {
+            Option::<T>::into_column(Series::from_values(index, values).unwrap())
+        } else {
+            T::into_column(Series::from_values(index, values.into_iter().flatten().collect()).unwrap())
+        }
+    }
+}
+
+impl<T: KeyHash> KeyHash for Option<T> {
+    fn key_hash<H: Hasher>(&self, state: &mut H) {
+        self.is_some().hash(state);
+        if let Some(v) = self { v.key_hash(state); }
+    }
+
+    fn key_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.key_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A reference to a value that is hashed and compared with `KeyHash`, for use
+/// as a key in hash maps and sets.
+pub(crate) struct HashKey<'a, T: KeyHash>(pub(crate) &'a T);
+
+impl<'a, T: KeyHash> Hash for HashKey<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.key_hash(state)
+    }
+}
+
+impl<'a, T: KeyHash> PartialEq for HashKey<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.key_eq(other.0)
+    }
+}
+
+impl<'a, T: KeyHash> Eq for HashKey<'a, T> {}
+
+/// Helper macro to implement `KeyHash` for types that implement `Hash` and
+/// `Eq`, or for floats via their canonical bit patterns.
+macro_rules! impl_key_hash {
+    ( @bits $value:expr, $type:ty ) => {
+        if $value.is_nan() { <$type>::NAN.to_bits() }
+        else if *$value == 0.0 { (0.0 as $type).to_bits() }
+        else { $value.to_bits() }
+    };
+    ( @float $( $type:ty ),+ ) => {
+        $(
+            impl KeyHash for $type {
+                fn key_hash<H: Hasher>(&self, state: &mut H) {
+                    impl_key_hash!(@bits self, $type).hash(state)
+                }
+
+                fn key_eq(&self, other: &Self) -> bool {
+                    impl_key_hash!(@bits self, $type) == impl_key_hash!(@bits other, $type)
+                }
+            }
+        )*
+    };
+    ( $( ($type:ty $( , $cfg_flag:meta )?), )+ ) => {
+        $(
+            $(#[$cfg_flag])?
+            impl KeyHash for $type {
+                fn key_hash<H: Hasher>(&self, state: &mut H) { self.hash(state) }
+                fn key_eq(&self, other: &Self) -> bool { self == other }
+            }
+        )*
+    };
+}
+
+impl_key_hash!(
+    (i8),
+    (i16),
+    (i32),
+    (i64),
+    (isize),
+    (i128, cfg(feature = "128")),
+
+    (u8),
+    (u16),
+    (u32),
+    (u64),
+    (usize),
+    (u128, cfg(feature = "128")),
+
+    (char),
+    (bool),
+
+    (String),
+
+    (Decimal, cfg(feature = "decimal")),
+
+    (Date, cfg(feature = "date-time")),
+    (Time, cfg(feature = "date-time")),
+    (DateTime, cfg(feature = "date-time")),
+);
+
+impl_key_hash!(@float f32, f64);
+
+/// Helper macro to implement `Numeric` for primitive number types.
+macro_rules! impl_numeric {
+    ( $( ($type:ty, $zero:expr, $one:expr $( , $cfg_flag:meta )?), )+ ) => {
+        $(
+            $(#[$cfg_flag])?
+            impl Numeric for $type {
+                fn zero() -> Self { $zero }
+                fn one() -> Self { $one }
+                fn to_f64(self) -> f64 { self as f64 }
+            }
+        )*
+    };
+}
+
+impl_numeric!(
+    (i8, 0, 1),
+    (i16, 0, 1),
+    (i32, 0, 1),
+    (i64, 0, 1),
+    (isize, 0, 1),
+    (i128, 0, 1, cfg(feature = "128")),
+
+    (u8, 0, 1),
+    (u16, 0, 1),
+    (u32, 0, 1),
+    (u64, 0, 1),
+    (usize, 0, 1),
+    (u128, 0, 1, cfg(feature = "128")),
+
+    (f32, 0.0, 1.0),
+    (f64, 0.0, 1.0),
+);
+
+/// Helper macro to implement `Signed` for primitive number types.
+macro_rules! impl_signed {
+    ( $( ($type:ty $( , $cfg_flag:meta )?), )+ ) => {
+        $(
+            $(#[$cfg_flag])?
+            impl Signed for $type {
+                fn abs(self) -> Self { <$type>::abs(self) }
+            }
+        )*
+    };
+}
+
+impl_signed!(
+    (i8),
+    (i16),
+    (i32),
+    (i64),
+    (isize),
+    (i128, cfg(feature = "128")),
+
+    (f32),
+    (f64),
+);
+
+/// Helper macro to implement `Integer` for primitive integer types.
+macro_rules! impl_integer {
+    ( $( ($type:ty $( , $cfg_flag:meta )?), )+ ) => {
+        $(
+            $(#[$cfg_flag])?
+            impl Integer for $type {
+                fn pow(self, n: u32) -> Self { <$type>::pow(self, n) }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> { <$type>::checked_add(self, rhs) }
+                fn checked_sub(self, rhs: Self) -> Option<Self> { <$type>::checked_sub(self, rhs) }
+                fn checked_mul(self, rhs: Self) -> Option<Self> { <$type>::checked_mul(self, rhs) }
+
+                fn wrapping_add(self, rhs: Self) -> Self { <$type>::wrapping_add(self, rhs) }
+                fn wrapping_sub(self, rhs: Self) -> Self { <$type>::wrapping_sub(self, rhs) }
+                fn wrapping_mul(self, rhs: Self) -> Self { <$type>::wrapping_mul(self, rhs) }
+
+                fn saturating_add(self, rhs: Self) -> Self { <$type>::saturating_add(self, rhs) }
+                fn saturating_sub(self, rhs: Self) -> Self { <$type>::saturating_sub(self, rhs) }
+                fn saturating_mul(self, rhs: Self) -> Self { <$type>::saturating_mul(self, rhs) }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    (i8),
+    (i16),
+    (i32),
+    (i64),
+    (isize),
+    (i128, cfg(feature = "128")),
+
+    (u8),
+    (u16),
+    (u32),
+    (u64),
+    (usize),
+    (u128, cfg(feature = "128")),
+);
+
+/// Helper macro to implement `Float` for primitive floating-point types.
+macro_rules! impl_float {
+    ( $( $type:ty ),+ ) => {
+        $(
+            impl Float for $type {
+                fn from_f64(v: f64) -> Self { v as $type }
+                fn round(self) -> Self { <$type>::round(self) }
+                fn powi(self, n: i32) -> Self { <$type>::powi(self, n) }
+                fn powf(self, n: Self) -> Self { <$type>::powf(self, n) }
+                fn ln(self) -> Self { <$type>::ln(self) }
+                fn exp(self) -> Self { <$type>::exp(self) }
+                fn sqrt(self) -> Self { <$type>::sqrt(self) }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl DType {
+    /// Returns `true` if this is an integral numeric type.
+    pub fn is_integer(self) -> bool {
+        match self {
+            DType::I8 | DType::I16 | DType::I32 | DType::I64 | DType::ISize => true,
+            DType::U8 | DType::U16 | DType::U32 | DType::U64 | DType::USize => true,
+            #[cfg(feature = "128")]
+            DType::I128 | DType::U128 => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this is a floating-point numeric type.
+    pub fn is_float(self) -> bool {
+        matches!(self, DType::F32 | DType::F64)
+    }
+
+    /// Returns `true` if this is an integral or floating-point numeric type.
+    pub fn is_numeric(self) -> bool {
+        self.is_integer() || self.is_float()
+    }
+
+    /// Returns `true` if values of this type can be cast to values of another
+    /// type using `Cast`. The casting rules are:
+    ///
+    /// * Any type can be cast to itself.
+    /// * Any type can be cast to `Str`, by formatting.
+    /// * `Str` can be cast to any type, by parsing.
+    /// * Numeric types can be cast to each other, if the value fits exactly.
+    /// * `Bool` can be cast to any numeric type, as `0` or `1`.
+    pub fn can_cast_to(self, to: DType) -> bool {
+        self == to
+        || to == DType::Str
+        || self == DType::Str
+        || (self.is_numeric() && to.is_numeric())
+        || (self == DType::Bool && to.is_numeric())
+    }
+}
+
+/// Checks if an integer with the given magnitude can be exactly represented by
+/// a float with the given number of mantissa digits.
+fn fits_mantissa(magnitude: u128, mantissa_digits: u32) -> bool {
+    magnitude == 0
+    || 128 - magnitude.leading_zeros() - magnitude.trailing_zeros() <= mantissa_digits
+}
+
+/// Converts a float to an `i128` or `u128` intermediate, if it is integral and
+/// within range of either.
+fn float_to_wide_int(v: f64) -> Option<Result<i128, u128>> {
+    if v.fract() != 0.0 || v < -(2.0f64.powi(127)) || v >= 2.0f64.powi(128) { None }
+    else if v < 0.0 { Some(Ok(v as i128)) }
+    else { Some(Err(v as u128)) }
+}
+
+/// Helper macro to implement `Cast` between integer types.
+macro_rules! impl_cast_int_to_int {
+    ( $from:ty => $( $to:ty ),+ ) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(&self) -> Option<$to> {
+                    <$to>::try_from(*self).ok()
+                }
+            }
+        )+
+    };
+}
+
+/// Helper macro to implement `Cast` from integer types to float types.
+macro_rules! impl_cast_int_to_float {
+    ( $from:ty, $to_wide:ident => $( $to:ty ),+ ) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(&self) -> Option<$to> {
+                    if fits_mantissa(impl_cast_int_to_float!(@magnitude *self, $to_wide), <$to>::MANTISSA_DIGITS) {
+                        Some(*self as $to)
+                    }
+                    else { None }
+                }
+            }
+        )+
+    };
+    (@magnitude $v:expr, signed) => { ($v as i128).unsigned_abs() };
+    (@magnitude $v:expr, unsigned) => { $v as u128 };
+}
+
+/// Helper macro to implement `Cast` from float types to integer types.
+macro_rules! impl_cast_float_to_int {
+    ( $from:ty => $( $to:ty ),+ ) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(&self) -> Option<$to> {
+                    match float_to_wide_int(*self as f64)? {
+                        Ok(i) => <$to>::try_from(i).ok(),
+                        Err(u) => <$to>::try_from(u).ok(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+/// Helper macro to implement `Cast` from `bool` to numeric types.
+macro_rules! impl_cast_bool {
+    ( $( $to:ty ),+ ) => {
+        $(
+            impl Cast<$to> for bool {
+                fn cast(&self) -> Option<$to> {
+                    Cast::<$to>::cast(&(*self as u8))
+                }
+            }
+        )+
+    };
+}
+
+/// Helper macro to implement `Cast` to and from `String`, as well as the
+/// identity `Cast` for non-numeric types.
+macro_rules! impl_cast_str {
+    ( $( ($type:ty, $identity:tt $( , $cfg_flag:meta )?), )+ ) => {
+        $(
+            $(#[$cfg_flag])?
+            impl Cast<String> for $type {
+                fn cast(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+
+            $(#[$cfg_flag])?
+            impl Cast<$type> for String {
+                fn cast(&self) -> Option<$type> {
+                    self.parse().ok()
+                }
+            }
+
+            impl_cast_str!(@identity $type, $identity $( , $cfg_flag )?);
+        )+
+    };
+    (@identity $type:ty, identity $( , $cfg_flag:meta )?) => {
+        $(#[$cfg_flag])?
+        impl Cast<$type> for $type {
+            fn cast(&self) -> Option<$type> {
+                Some(self.clone())
+            }
+        }
+    };
+    (@identity $type:ty, numeric $( , $cfg_flag:meta )?) => {};
+}
+
+/// Helper macro to expand a list of numeric types for the `Cast` macros.
+macro_rules! with_numeric_types {
+    ( $mac:ident ! ( $( $prefix:tt )* ) ) => {
+        $mac!($( $prefix )* i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+        #[cfg(feature = "128")]
+        $mac!($( $prefix )* i128, u128);
+    };
+}
+
+macro_rules! impl_cast_from_int {
+    ( $( ($from:ty, $to_wide:ident $( , $cfg_flag:meta )?), )+ ) => {
+        $(
+            $(#[$cfg_flag])?
+            const _: () = {
+                with_numeric_types!(impl_cast_int_to_int!($from =>));
+                impl_cast_int_to_float!($from, $to_wide => f32, f64);
+                impl_cast_float_to_int!(f32 => $from);
+                impl_cast_float_to_int!(f64 => $from);
+            };
+        )+
+    };
+}
+
+impl_cast_from_int!(
+    (i8, signed),
+    (i16, signed),
+    (i32, signed),
+    (i64, signed),
+    (isize, signed),
+    (i128, signed, cfg(feature = "128")),
+
+    (u8, unsigned),
+    (u16, unsigned),
+    (u32, unsigned),
+    (u64, unsigned),
+    (usize, unsigned),
+    (u128, unsigned, cfg(feature = "128")),
+);
+
+impl Cast<f32> for f32 {
+    fn cast(&self) -> Option<f32> {
+        Some(*self)
+    }
+}
+
+impl Cast<f64> for f64 {
+    fn cast(&self) -> Option<f64> {
+        Some(*self)
+    }
+}
+
+impl Cast<f64> for f32 {
+    fn cast(&self) -> Option<f64> {
+        Some(*self as f64)
+    }
+}
+
+impl Cast<f32> for f64 {
+    fn cast(&self) -> Option<f32> {
+        let n = *self as f32;
+        if n as f64 == *self || self.is_nan() { Some(n) } else { None }
+    }
+}
+
+with_numeric_types!(impl_cast_bool!());
+impl_cast_bool!(f32, f64);
+
+impl_cast_str!(
+    (i8, numeric),
+    (i16, numeric),
+    (i32, numeric),
+    (i64, numeric),
+    (isize, numeric),
+    (i128, numeric, cfg(feature = "128")),
+
+    (u8, numeric),
+    (u16, numeric),
+    (u32, numeric),
+    (u64, numeric),
+    (usize, numeric),
+    (u128, numeric, cfg(feature = "128")),
+
+    (f32, numeric),
+    (f64, numeric),
+
+    (char, identity),
+    (bool, identity),
+
+    (Decimal, identity, cfg(feature = "decimal")),
+
+    (Date, identity, cfg(feature = "date-time")),
+    (Time, identity, cfg(feature = "date-time")),
+    (DateTime, identity, cfg(feature = "date-time")),
+);
+
+impl Cast<String> for String {
+    fn cast(&self) -> Option<String> {
+        Some(self.clone())
+    }
+}